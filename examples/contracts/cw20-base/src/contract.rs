@@ -1,7 +1,7 @@
 use crate::error::ContractError;
 use crate::responses::{BalanceResponse, Cw20Coin, Cw20ReceiveMsg, TokenInfoResponse};
 use crate::validation::{validate_accounts, validate_msg, verify_logo};
-use cw2::{ensure_from_older_version, set_contract_version};
+use cw2::get_contract_version;
 use cw20_allowances::responses::AllowanceResponse;
 use cw20_marketing::responses::{LogoInfo, MarketingInfoResponse};
 use cw20_marketing::Logo;
@@ -82,6 +82,7 @@ pub struct Cw20Base<E, Q> {
 #[sv::messages(cw20_marketing as Marketing)]
 #[sv::messages(cw20_minting as Minting)]
 #[sv::custom(msg=E, query=Q)]
+#[sv::version(name = CONTRACT_NAME, version = CONTRACT_VERSION)]
 impl<E, Q> Cw20Base<E, Q>
 where
     E: CustomMsg + 'static,
@@ -160,7 +161,6 @@ where
             mint,
             marketing,
         } = data;
-        set_contract_version(ctx.deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
         // check valid token info
         validate_msg(&name, &symbol, decimals)?;
@@ -339,8 +339,12 @@ where
 
     #[sv::msg(migrate)]
     fn migrate(&self, ctx: MigrateCtx<Q>) -> Result<Response<E>, ContractError> {
-        let original_version =
-            ensure_from_older_version(ctx.deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        // Contract name and version are validated before the migration and the stored
+        // version is updated after it succeeds.
+        let original_version = get_contract_version(ctx.deps.storage)?
+            .version
+            .parse::<semver::Version>()
+            .map_err(|err| StdError::generic_err(format!("Semver: {err}")))?;
 
         if original_version < "0.14.0".parse::<semver::Version>().unwrap() {
             // Build reverse map of allowances per spender
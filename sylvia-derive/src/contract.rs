@@ -2,10 +2,12 @@ use communication::api::Api;
 use communication::enum_msg::EnumMessage;
 use communication::executor::Executor;
 use communication::querier::Querier;
+use communication::reply::Reply;
 use communication::struct_msg::StructMessage;
 use communication::wrapper_msg::GlueMessage;
 use mt::MtHelpers;
 use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
 use quote::quote;
 use syn::{GenericParam, ItemImpl};
 
@@ -13,7 +15,7 @@ use crate::parser::attributes::msg::MsgType;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{
     assert_new_method_defined, ContractErrorAttr, Custom, OverrideEntryPoint,
-    ParsedSylviaAttributes,
+    ParsedSylviaAttributes, VersionAttr,
 };
 use crate::types::interfaces::Interfaces;
 use crate::types::msg_variant::MsgVariants;
@@ -37,6 +39,9 @@ mod mt;
 ///     - [Querier](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - [Executor](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - Api trait implementation
+///     - Reply ids and `dispatch_reply` function
+///     - MultiTest proxy methods calling the IBC handlers, if any are defined
+///     - `contract_info` query if the contract version is defined
 pub struct ContractInput<'a> {
     item: &'a ItemImpl,
     generics: Vec<&'a GenericParam>,
@@ -44,11 +49,15 @@ pub struct ContractInput<'a> {
     custom: Custom,
     override_entry_points: Vec<OverrideEntryPoint>,
     interfaces: Interfaces,
+    version: Option<VersionAttr>,
+    actions: bool,
+    typed_query_errors: bool,
 }
 
 impl<'a> ContractInput<'a> {
     pub fn new(item: &'a ItemImpl) -> Self {
         assert_new_method_defined(item);
+        assert_single_ibc_handlers(item);
 
         let generics = item.generics.params.iter().collect();
         let parsed_attrs = ParsedSylviaAttributes::new(item.attrs.iter());
@@ -56,6 +65,9 @@ impl<'a> ContractInput<'a> {
         let custom = parsed_attrs.custom_attr.unwrap_or_default();
         let override_entry_points = parsed_attrs.override_entry_point_attrs;
         let interfaces = Interfaces::new(item);
+        let version = parsed_attrs.version_attr;
+        let actions = parsed_attrs.actions;
+        let typed_query_errors = parsed_attrs.typed_query_errors;
 
         Self {
             item,
@@ -64,6 +76,9 @@ impl<'a> ContractInput<'a> {
             custom,
             override_entry_points,
             interfaces,
+            version,
+            actions,
+            typed_query_errors,
         }
     }
 
@@ -73,12 +88,17 @@ impl<'a> ContractInput<'a> {
             item,
             generics,
             custom,
+            typed_query_errors,
             ..
         } = self;
         let multitest_helpers = self.emit_multitest_helpers();
 
         let executor_variants = MsgVariants::new(item.as_variants(), MsgType::Exec, &[], &None);
         let querier_variants = MsgVariants::new(item.as_variants(), MsgType::Query, &[], &None);
+        let query_handlers = match generics.is_empty() {
+            true => querier_variants.emit_query_handlers(),
+            false => quote! {},
+        };
         let executor = Executor::new(
             item.generics.clone(),
             *item.self_ty.clone(),
@@ -89,10 +109,12 @@ impl<'a> ContractInput<'a> {
             item.generics.clone(),
             *item.self_ty.clone(),
             querier_variants,
+            *typed_query_errors,
         )
         .emit();
         let messages = self.emit_messages();
-        let contract_api = Api::new(item, generics, custom).emit();
+        let contract_api = Api::new(item, generics, custom, &self.interfaces).emit();
+        let reply = Reply::new(item, generics, &self.error, custom).emit();
 
         quote! {
             pub mod sv {
@@ -100,12 +122,16 @@ impl<'a> ContractInput<'a> {
 
                 #messages
 
+                #reply
+
                 #multitest_helpers
 
                 #querier
 
                 #executor
 
+                #query_handlers
+
                 #contract_api
             }
         }
@@ -141,8 +167,16 @@ impl<'a> ContractInput<'a> {
     }
 
     fn emit_struct_msg(&self, msg_ty: MsgType) -> TokenStream {
-        StructMessage::new(self.item, msg_ty, &self.generics, &self.error, &self.custom)
-            .map_or(quote! {}, |msg| msg.emit())
+        StructMessage::new(
+            self.item,
+            msg_ty,
+            &self.generics,
+            &self.error,
+            &self.custom,
+            self.version.as_ref(),
+            self.actions,
+        )
+        .map_or(quote! {}, |msg| msg.emit())
     }
 
     fn emit_enum_msg(&self, msg_ty: MsgType) -> TokenStream {
@@ -156,6 +190,8 @@ impl<'a> ContractInput<'a> {
             &self.error,
             &self.custom,
             &self.interfaces,
+            self.version.as_ref(),
+            self.actions,
         )
         .emit()
     }
@@ -169,10 +205,34 @@ impl<'a> ContractInput<'a> {
             item,
             custom,
             override_entry_points,
+            interfaces,
+            version,
             ..
         } = self;
 
         let generic_params = &self.generics;
-        MtHelpers::new(item, generic_params, custom, override_entry_points.clone()).emit()
+        MtHelpers::new(
+            item,
+            generic_params,
+            custom,
+            override_entry_points.clone(),
+            interfaces,
+            version.is_some(),
+        )
+        .emit()
+    }
+}
+
+/// Every IBC entry point can be handled by at most one method.
+fn assert_single_ibc_handlers(item: &ItemImpl) {
+    for msg_ty in MsgType::ibc_msg_types() {
+        let variants = MsgVariants::<GenericParam>::new(item.as_variants(), msg_ty, &[], &None);
+        let mut variants = variants.variants();
+        if let (Some(first), Some(second)) = (variants.next(), variants.next()) {
+            emit_error!(
+                second.function_name().span(), "More than one `{}` handler.", msg_ty.emit_ep_name();
+                note = first.function_name().span() => "Previous handler defined here."
+            );
+        }
     }
 }
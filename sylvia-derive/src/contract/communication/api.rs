@@ -1,6 +1,7 @@
 use crate::crate_module;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{Custom, MsgType};
+use crate::types::interfaces::Interfaces;
 use crate::types::msg_variant::MsgVariants;
 use crate::utils::emit_bracketed_generics;
 use proc_macro2::TokenStream;
@@ -16,10 +17,16 @@ pub struct Api<'a> {
     sudo_variants: MsgVariants<'a, GenericParam>,
     generics: &'a [&'a GenericParam],
     custom: &'a Custom,
+    interfaces: &'a Interfaces,
 }
 
 impl<'a> Api<'a> {
-    pub fn new(source: &'a ItemImpl, generics: &'a [&'a GenericParam], custom: &'a Custom) -> Self {
+    pub fn new(
+        source: &'a ItemImpl,
+        generics: &'a [&'a GenericParam],
+        custom: &'a Custom,
+        interfaces: &'a Interfaces,
+    ) -> Self {
         let exec_variants = MsgVariants::new(
             source.as_variants(),
             MsgType::Exec,
@@ -64,6 +71,7 @@ impl<'a> Api<'a> {
             sudo_variants,
             generics,
             custom,
+            interfaces,
         }
     }
 
@@ -78,7 +86,7 @@ impl<'a> Api<'a> {
             sudo_variants,
             generics,
             custom,
-            ..
+            interfaces,
         } = self;
 
         let where_clause = &source.generics.where_clause;
@@ -103,6 +111,7 @@ impl<'a> Api<'a> {
         };
         let custom_msg = custom.msg_or_default();
         let custom_query = custom.query_or_default();
+        let namespaces = interfaces.emit_namespaces();
 
         quote! {
             impl #bracket_generics #sylvia ::types::ContractApi for #contract_name #where_clause {
@@ -118,6 +127,7 @@ impl<'a> Api<'a> {
                 type Querier<'querier> = #sylvia ::types::BoundQuerier<'querier, #custom_query, Self >;
                 type CustomMsg = #custom_msg;
                 type CustomQuery = #custom_query;
+                #namespaces
             }
         }
     }
@@ -18,6 +18,7 @@ pub struct EnumMessage<'a> {
     custom: &'a Custom,
     where_clause: &'a Option<WhereClause>,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    arbitrary: bool,
 }
 
 impl<'a> EnumMessage<'a> {
@@ -30,7 +31,9 @@ impl<'a> EnumMessage<'a> {
     ) -> Self {
         let where_clause = &source.generics.where_clause;
         let variants = MsgVariants::new(source.as_variants(), msg_ty, generics, where_clause);
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let arbitrary = cfg!(feature = "arbitrary") && parsed_attrs.arbitrary.is_some();
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
@@ -44,6 +47,7 @@ impl<'a> EnumMessage<'a> {
             custom,
             where_clause,
             msg_attrs_to_forward,
+            arbitrary,
         }
     }
 
@@ -68,10 +72,14 @@ impl<'a> EnumMessage<'a> {
         let used_generics = variants.used_generics();
         let bracketed_used_generics = emit_bracketed_generics(used_generics);
 
-        let mut variant_names = variants.as_names_snake_cased();
-        variant_names.sort();
+        let variant_names = variants.as_msg_names();
         let variants_cnt = variant_names.len();
         let variants_constructors = variants.emit_constructors();
+        let msg_name_method = variants.emit_msg_name_method();
+        let arbitrary_impl = match self.arbitrary {
+            true => variants.emit_arbitrary_impl(&enum_name),
+            false => quote! {},
+        };
         let variants = variants.emit();
 
         let ctx_type = msg_ty.emit_ctx_type(&custom.query_or_default());
@@ -110,8 +118,12 @@ impl<'a> EnumMessage<'a> {
                 }
 
                 #(#variants_constructors)*
+
+                #msg_name_method
             }
 
+            #arbitrary_impl
+
             pub const fn #messages_fn_name () -> [&'static str; #variants_cnt] {
                 [#(#variant_names,)*]
             }
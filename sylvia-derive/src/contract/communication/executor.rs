@@ -1,4 +1,3 @@
-use convert_case::Case;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{GenericParam, Generics, Type};
@@ -8,7 +7,6 @@ use crate::parser::attributes::msg::MsgType;
 use crate::types::associated_types::EmitAssociated;
 use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::SvCasing;
 
 /// Emits [execute helper](https://cosmwasm-docs.vercel.app/sylvia/macros/generated-types/communication#executor-helpers).
 ///
@@ -85,13 +83,12 @@ trait EmitExecutorMethod {
 
 impl EmitExecutorMethod for MsgVariant<'_> {
     fn emit_executor_impl(&self, api_path: &TokenStream) -> TokenStream {
-        let name = self.name();
         let fields = self.fields();
         let sylvia = crate_module();
 
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
         let fields_names = fields.iter().map(MsgField::name);
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
 
         quote! {
             fn #variant_name(self, #(#parameters),*) -> Result<#sylvia ::types::ExecutorBuilder< #sylvia ::types::ReadyExecutorBuilderState >, #sylvia ::cw_std::StdError> {
@@ -105,14 +102,13 @@ impl EmitExecutorMethod for MsgVariant<'_> {
     }
 
     fn emit_executor_method_declaration(&self) -> TokenStream {
-        let name = self.name();
         let sylvia = crate_module();
 
         let parameters = self
             .fields()
             .iter()
             .map(|field| field.emit_method_field_folded());
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
 
         quote! {
             fn #variant_name(self, #(#parameters),*) -> Result< #sylvia ::types::ExecutorBuilder<#sylvia ::types::ReadyExecutorBuilderState>, #sylvia ::cw_std::StdError>;
@@ -2,5 +2,6 @@ pub mod api;
 pub mod enum_msg;
 pub mod executor;
 pub mod querier;
+pub mod reply;
 pub mod struct_msg;
 pub mod wrapper_msg;
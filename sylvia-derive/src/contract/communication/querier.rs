@@ -1,4 +1,3 @@
-use convert_case::Case;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{GenericParam, Generics, Type};
@@ -8,7 +7,7 @@ use crate::parser::attributes::msg::MsgType;
 use crate::types::associated_types::EmitAssociated;
 use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::SvCasing;
+use crate::utils::emit_query_error_type;
 
 /// Emits [query helper](https://cosmwasm-docs.vercel.app/sylvia/macros/generated-types/communication#query-helpers).
 ///
@@ -18,14 +17,21 @@ pub struct Querier<'a> {
     generics: Generics,
     self_ty: Type,
     variants: MsgVariants<'a, GenericParam>,
+    typed_errors: bool,
 }
 
 impl<'a> Querier<'a> {
-    pub fn new(generics: Generics, self_ty: Type, variants: MsgVariants<'a, GenericParam>) -> Self {
+    pub fn new(
+        generics: Generics,
+        self_ty: Type,
+        variants: MsgVariants<'a, GenericParam>,
+        typed_errors: bool,
+    ) -> Self {
         Self {
             generics,
             self_ty,
             variants,
+            typed_errors,
         }
     }
 
@@ -35,7 +41,7 @@ impl<'a> Querier<'a> {
             generics,
             self_ty,
             variants,
-            ..
+            typed_errors,
         } = self;
 
         let where_clause = &generics.where_clause;
@@ -47,11 +53,11 @@ impl<'a> Querier<'a> {
 
         let querier_methods_impl = variants
             .variants()
-            .map(|variant| variant.emit_querier_impl(&api_path));
+            .map(|variant| variant.emit_querier_impl(&api_path, *typed_errors));
 
         let querier_methods_declaration = variants
             .variants()
-            .map(|variant| variant.emit_querier_method_declaration());
+            .map(|variant| variant.emit_querier_method_declaration(*typed_errors));
 
         let types_declaration = where_clause
             .as_ref()
@@ -84,42 +90,45 @@ impl<'a> Querier<'a> {
 }
 
 trait EmitQuerierMethod {
-    fn emit_querier_impl(&self, api_path: &TokenStream) -> TokenStream;
-    fn emit_querier_method_declaration(&self) -> TokenStream;
+    fn emit_querier_impl(&self, api_path: &TokenStream, typed_errors: bool) -> TokenStream;
+    fn emit_querier_method_declaration(&self, typed_errors: bool) -> TokenStream;
 }
 
 impl EmitQuerierMethod for MsgVariant<'_> {
-    fn emit_querier_impl(&self, api_path: &TokenStream) -> TokenStream {
-        let sylvia = crate_module();
-        let name = self.name();
+    fn emit_querier_impl(&self, api_path: &TokenStream, typed_errors: bool) -> TokenStream {
         let fields = self.fields();
         let return_type = self.return_type();
 
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
         let fields_names = fields.iter().map(MsgField::name);
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
+
+        let error_type = emit_query_error_type(typed_errors);
+        let query_call = match typed_errors {
+            true => quote! { self.query_typed(&query) },
+            false => quote! { self.querier().query_wasm_smart(self.contract(), &query) },
+        };
 
         quote! {
-            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError> {
+            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #error_type> {
                 let query = #api_path :: #variant_name (#(#fields_names),*);
-                self.querier().query_wasm_smart(self.contract(), &query)
+                #query_call
             }
         }
     }
 
-    fn emit_querier_method_declaration(&self) -> TokenStream {
-        let sylvia = crate_module();
-        let name = self.name();
+    fn emit_querier_method_declaration(&self, typed_errors: bool) -> TokenStream {
         let return_type = self.return_type();
+        let error_type = emit_query_error_type(typed_errors);
 
         let parameters = self
             .fields()
             .iter()
             .map(|field| field.emit_method_field_folded());
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
 
         quote! {
-            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError>;
+            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #error_type>;
         }
     }
 }
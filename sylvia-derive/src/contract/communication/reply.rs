@@ -0,0 +1,519 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote};
+use syn::{GenericParam, Ident, ItemImpl, LitInt};
+
+use crate::crate_module;
+use crate::parser::attributes::msg::ReplyOn;
+use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::{ContractErrorAttr, Custom, MsgAttr, MsgType};
+use crate::types::msg_field::MsgField;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
+use crate::utils::emit_bracketed_generics;
+
+/// Reply handlers bound to a single reply id.
+struct ReplyId<'a> {
+    handler_id: &'a Ident,
+    explicit_id: Option<&'a LitInt>,
+    handlers: Vec<(ReplyOn, &'a MsgVariant<'a>)>,
+}
+
+impl<'a> ReplyId<'a> {
+    fn emit_const_name(&self) -> Ident {
+        let handler_id = self.handler_id.to_string().to_case(Case::UpperSnake);
+        format_ident!("{}_REPLY_ID", handler_id, span = self.handler_id.span())
+    }
+
+    /// Value of the reply id - the one set with `id = ...`, or the hash of the reply id name,
+    /// so it doesn't change when the handlers are reordered.
+    fn value(&self) -> u64 {
+        match self.explicit_id {
+            Some(id) => id.base10_parse().unwrap_or_else(|err| {
+                emit_error!(id.span(), "Invalid reply id: {}.", err);
+                0
+            }),
+            None => fnv1a_hash(&self.handler_id.to_string()),
+        }
+    }
+
+    fn handler(&self, reply_on: ReplyOn) -> Option<&'a MsgVariant<'a>> {
+        self.handlers
+            .iter()
+            .find(|(on, _)| *on == reply_on)
+            .map(|(_, handler)| *handler)
+    }
+
+    fn uses_payload(&self) -> bool {
+        self.handlers.iter().any(|(_, handler)| {
+            handler
+                .fields()
+                .iter()
+                .any(|field| field.payload().is_some())
+        })
+    }
+
+    /// Emits match arm calling the handlers bound to this reply id.
+    fn emit_match_arm(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let const_name = self.emit_const_name();
+        let payload = if self.uses_payload() {
+            quote! { payload, }
+        } else {
+            quote! {}
+        };
+
+        if let Some(handler) = self.handler(ReplyOn::Always) {
+            let result = match takes_result(handler) {
+                true => quote! { result, },
+                false => quote! {},
+            };
+            let call = emit_handler_call(self.handler_id, handler, ReplyOn::Always);
+            return quote! {
+                #const_name => {
+                    let #sylvia ::cw_std::Reply { #payload #result .. } = msg;
+                    #call
+                }
+            };
+        }
+
+        let success = match self.handler(ReplyOn::Success) {
+            Some(handler) => {
+                let uses_data = handler.fields().iter().any(|field| field.data().is_some());
+                let call = emit_handler_call(self.handler_id, handler, ReplyOn::Success);
+                if uses_data {
+                    quote! {
+                        #sylvia ::cw_std::SubMsgResult::Ok(sub_msg_resp) => {
+                            #[allow(deprecated)]
+                            let data = sub_msg_resp.data;
+                            #call
+                        }
+                    }
+                } else {
+                    quote! { #sylvia ::cw_std::SubMsgResult::Ok(_) => #call }
+                }
+            }
+            None => quote! {
+                #sylvia ::cw_std::SubMsgResult::Ok(_) => Ok(#sylvia ::cw_std::Response::new())
+            },
+        };
+
+        let error = match self.handler(ReplyOn::Error) {
+            Some(handler) => {
+                let error = match takes_result(handler) {
+                    true => quote! { error },
+                    false => quote! { _ },
+                };
+                let call = emit_handler_call(self.handler_id, handler, ReplyOn::Error);
+                quote! { #sylvia ::cw_std::SubMsgResult::Err(#error) => #call }
+            }
+            None => quote! {
+                #sylvia ::cw_std::SubMsgResult::Err(error) => Err(#sylvia ::cw_std::StdError::generic_err(error)).map_err(Into::into)
+            },
+        };
+
+        quote! {
+            #const_name => {
+                let #sylvia ::cw_std::Reply { #payload result, .. } = msg;
+                match result {
+                    #success,
+                    #error,
+                }
+            }
+        }
+    }
+
+    /// Emits match arm returning the name of the handler called for the reply.
+    fn emit_handler_name_arm(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let const_name = self.emit_const_name();
+
+        if let Some(handler) = self.handler(ReplyOn::Always) {
+            let name = handler.function_name().to_string();
+            return quote! { #const_name => Some(#name) };
+        }
+
+        let name = |reply_on| match self.handler(reply_on) {
+            Some(handler) => {
+                let name = handler.function_name().to_string();
+                quote! { Some(#name) }
+            }
+            None => quote! { None },
+        };
+        let success = name(ReplyOn::Success);
+        let error = name(ReplyOn::Error);
+
+        quote! {
+            #const_name => match &msg.result {
+                #sylvia ::cw_std::SubMsgResult::Ok(_) => #success,
+                #sylvia ::cw_std::SubMsgResult::Err(_) => #error,
+            }
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash of the reply id name.
+fn fnv1a_hash(name: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    name.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Checks if the handler expects the submessage result or the error message as an argument.
+fn takes_result(handler: &MsgVariant) -> bool {
+    handler
+        .fields()
+        .iter()
+        .any(|field| field.data().is_none() && field.payload().is_none())
+}
+
+/// Emits call of the reply handler bound to `reply_id` with arguments decoded from the `Reply`.
+///
+/// Expects `deps`, `env`, `contract` and depending on `reply_on` and handler arguments
+/// `data`, `payload`, `error` and `result` to be in scope.
+fn emit_handler_call(reply_id: &Ident, handler: &MsgVariant, reply_on: ReplyOn) -> TokenStream {
+    let sylvia = crate_module();
+    let empty_payload = format!(
+        "Failed to decode payload of the reply `{}`: payload is empty.",
+        reply_id
+    );
+    let function_name = handler.function_name();
+    let missing_data = quote! {
+        || #sylvia ::cw_std::StdError::generic_err("Missing reply data field.")
+    };
+
+    let args = handler.fields().iter().map(|field| {
+        let ty = field.ty();
+        match (field.data(), field.payload()) {
+            (Some(data), _) if data.instantiate && data.opt => quote! {
+                data.map(|data| #sylvia ::cw_utils::parse_instantiate_response_data(&data))
+                    .transpose()
+                    .map_err(|err| #sylvia ::cw_std::StdError::generic_err(err.to_string()))?
+            },
+            (Some(data), _) if data.instantiate => quote! {
+                #sylvia ::cw_utils::parse_instantiate_response_data(&data.ok_or_else(#missing_data)?)
+                    .map_err(|err| #sylvia ::cw_std::StdError::generic_err(err.to_string()))?
+            },
+            (Some(data), _) if data.raw && data.opt => quote! { data },
+            (Some(data), _) if data.raw => quote! { data.ok_or_else(#missing_data)? },
+            (Some(data), _) if data.opt => quote! {
+                data.map(|data| #sylvia ::cw_std::from_json(&data)).transpose()?
+            },
+            (Some(_), _) => quote! {
+                #sylvia ::cw_std::from_json(&data.ok_or_else(#missing_data)?)?
+            },
+            (None, Some(payload)) if payload.raw => quote! { payload },
+            (None, Some(_)) => quote! {
+                {
+                    #sylvia ::cw_std::ensure!(
+                        !payload.is_empty(),
+                        #sylvia ::cw_std::StdError::parse_err(
+                            ::core::any::type_name::<#ty>(),
+                            #empty_payload,
+                        )
+                    );
+                    #sylvia ::cw_std::from_json(&payload)?
+                }
+            },
+            (None, None) if reply_on == ReplyOn::Error => quote! { error },
+            (None, None) => quote! { result },
+        }
+    });
+
+    quote! {
+        contract. #function_name (Into::into((deps, env)), #(#args),* ).map_err(Into::into)
+    }
+}
+
+/// Validates arguments of the typed reply handler.
+fn validate_handler(handler: &MsgVariant, reply_on: ReplyOn) {
+    let fields = handler.fields();
+    let count = |predicate: fn(&&MsgField) -> bool| fields.iter().filter(predicate).count();
+
+    if count(|field| field.data().is_some()) > 1 {
+        emit_error!(
+            handler.function_name().span(), "Multiple `sv::data` arguments in reply handler.";
+            note = "Only one argument can be marked with `#[sv::data]` attribute."
+        );
+    }
+    if count(|field| field.payload().is_some()) > 1 {
+        emit_error!(
+            handler.function_name().span(), "Multiple `sv::payload` arguments in reply handler.";
+            note = "Only one argument can be marked with `#[sv::payload]` attribute."
+        );
+    }
+
+    for field in fields {
+        match (reply_on, field.data(), field.payload()) {
+            (_, Some(_), Some(_)) => emit_error!(
+                field.name().span(), "Argument marked with both `sv::data` and `sv::payload`.";
+                note = "Reply handler argument can be either the data or the payload."
+            ),
+            (ReplyOn::Error | ReplyOn::Always, Some(_), _) => emit_error!(
+                field.name().span(), "The `sv::data` attribute is supported only for `success` reply handlers.";
+                note = "Use `#[sv::msg(reply, reply_on=success)]` to decode the data field."
+            ),
+            (ReplyOn::Success, None, None) => emit_error!(
+                field.name().span(), "Missing `sv::data` or `sv::payload` attribute.";
+                note = "Arguments of the `success` reply handler have to be marked with `#[sv::data]` or `#[sv::payload]`."
+            ),
+            (_, Some(data), _) if data.instantiate && !cfg!(feature = "cosmwasm_1_2") => {
+                emit_error!(
+                    field.name().span(), "The `instantiate` data parameter requires `cosmwasm_1_2` feature.";
+                    note = "Enable `cosmwasm_1_2` feature of the `sylvia` crate."
+                )
+            }
+            _ => (),
+        }
+    }
+
+    if count(|field| field.data().is_none() && field.payload().is_none()) > 1 {
+        emit_error!(
+            handler.function_name().span(), "Too many arguments in reply handler.";
+            note = "Only one argument of the `error` and `always` reply handlers can be left without `#[sv::payload]` attribute."
+        );
+    }
+}
+
+/// Emits reply ids and `dispatch_reply` function routing the `Reply` to the
+/// handlers marked with `#[sv::msg(reply, ...)]` attribute.
+pub struct Reply<'a> {
+    source: &'a ItemImpl,
+    generics: &'a [&'a GenericParam],
+    error: &'a ContractErrorAttr,
+    custom: &'a Custom,
+    variants: MsgVariants<'a, GenericParam>,
+}
+
+impl<'a> Reply<'a> {
+    pub fn new(
+        source: &'a ItemImpl,
+        generics: &'a [&'a GenericParam],
+        error: &'a ContractErrorAttr,
+        custom: &'a Custom,
+    ) -> Self {
+        let variants = MsgVariants::new(
+            source.as_variants(),
+            MsgType::Reply,
+            generics,
+            &source.generics.where_clause,
+        );
+
+        Self {
+            source,
+            generics,
+            error,
+            custom,
+            variants,
+        }
+    }
+
+    pub fn emit(&self) -> TokenStream {
+        let Self {
+            source,
+            generics,
+            error,
+            custom,
+            variants,
+        } = self;
+        let sylvia = crate_module();
+
+        if variants.variants().count() == 0 {
+            return quote! {};
+        }
+
+        let (reply_ids, fallback) = self.reply_ids();
+
+        let ids = reply_ids.iter().map(|reply_id| {
+            let const_name = reply_id.emit_const_name();
+            let id = reply_id.value();
+            quote! { pub const #const_name : u64 = #id ; }
+        });
+
+        let fallback_call = match fallback {
+            Some(fallback) => {
+                let function_name = fallback.function_name();
+                quote! {
+                    contract. #function_name (Into::into((deps, env)), msg).map_err(Into::into)
+                }
+            }
+            None => quote! {
+                Err(#sylvia ::cw_std::StdError::generic_err(format!("Unknown reply id: {}.", msg.id))).map_err(Into::into)
+            },
+        };
+
+        let body = if reply_ids.is_empty() {
+            fallback_call
+        } else {
+            let arms = reply_ids.iter().map(ReplyId::emit_match_arm);
+            quote! {
+                match msg.id {
+                    #(#arms)*
+                    _ => #fallback_call,
+                }
+            }
+        };
+
+        let handler_names = self.emit_handler_names(&reply_ids, fallback);
+
+        let contract_type = &source.self_ty;
+        let where_clause = &source.generics.where_clause;
+        let bracketed_generics = emit_bracketed_generics(generics);
+        let custom_query = custom.query_or_default();
+        let ret_type = MsgType::Reply.emit_result_type(&custom.msg_or_default(), &error.error);
+
+        quote! {
+            #(#ids)*
+
+            pub fn dispatch_reply #bracketed_generics (
+                deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                env: #sylvia ::cw_std::Env,
+                msg: #sylvia ::cw_std::Reply,
+                contract: &#contract_type,
+            ) -> #ret_type #where_clause {
+                #body
+            }
+
+            #handler_names
+        }
+    }
+
+    /// Emits names of the reply handlers and the `reply_handler_name` function finding
+    /// the handler called for the `Reply`, used by the MultiTest coverage.
+    fn emit_handler_names(
+        &self,
+        reply_ids: &[ReplyId],
+        fallback: Option<&MsgVariant>,
+    ) -> TokenStream {
+        let sylvia = crate_module();
+
+        let names: Vec<_> = self
+            .variants
+            .variants()
+            .map(|variant| variant.function_name().to_string())
+            .collect();
+        let names_cnt = names.len();
+
+        let handler_name_fn = if cfg!(feature = "mt") {
+            let fallback = match fallback {
+                Some(fallback) => {
+                    let name = fallback.function_name().to_string();
+                    quote! { Some(#name) }
+                }
+                None => quote! { None },
+            };
+            let arms = reply_ids.iter().map(ReplyId::emit_handler_name_arm);
+
+            quote! {
+                pub fn reply_handler_name(msg: &#sylvia ::cw_std::Reply) -> Option<&'static str> {
+                    match msg.id {
+                        #(#arms,)*
+                        _ => #fallback,
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            pub const fn reply_messages() -> [&'static str; #names_cnt] {
+                [#(#names,)*]
+            }
+
+            #handler_name_fn
+        }
+    }
+
+    /// Groups typed reply handlers by the reply id and returns them in order of definition
+    /// along with the optional catch-all handler.
+    fn reply_ids(&self) -> (Vec<ReplyId<'_>>, Option<&MsgVariant<'_>>) {
+        let mut reply_ids: Vec<ReplyId> = vec![];
+        let mut fallback: Option<&MsgVariant> = None;
+
+        for variant in self.variants.variants() {
+            let MsgAttr::Reply {
+                handlers,
+                reply_on,
+                id,
+            } = variant.msg_attr()
+            else {
+                continue;
+            };
+
+            if handlers.is_empty() && reply_on.is_none() {
+                match fallback {
+                    Some(previous) => emit_error!(
+                        variant.function_name().span(), "More than one catch-all reply handler.";
+                        note = previous.function_name().span() => "Previous catch-all reply handler defined here.";
+                        note = "Bind reply handlers to reply ids with `#[sv::msg(reply, handlers=[...])]`."
+                    ),
+                    None => fallback = Some(variant),
+                }
+                continue;
+            }
+
+            let reply_on = reply_on.unwrap_or_default();
+            validate_handler(variant, reply_on);
+
+            let handler_ids = match handlers.is_empty() {
+                true => vec![variant.function_name()],
+                false => handlers.iter().collect(),
+            };
+
+            for handler_id in handler_ids {
+                match reply_ids
+                    .iter_mut()
+                    .find(|reply_id| reply_id.handler_id == handler_id)
+                {
+                    Some(reply_id) => {
+                        if let Some((_, previous)) = reply_id
+                            .handlers
+                            .iter()
+                            .find(|(on, _)| on.excludes(&reply_on))
+                        {
+                            emit_error!(
+                                handler_id.span(), "Duplicated reply handler for `{}`.", handler_id;
+                                note = previous.function_name().span() => "Previous handler for this reply id defined here.";
+                                note = "Single reply id can be handled either by one `always` handler, or by one `success` and one `error` handler."
+                            );
+                            continue;
+                        }
+                        if let (Some(previous), Some(id)) = (reply_id.explicit_id, id) {
+                            if previous.base10_digits() != id.base10_digits() {
+                                emit_error!(
+                                    id.span(), "Conflicting ids of the reply id `{}`.", handler_id;
+                                    note = previous.span() => "Previous id of this reply id defined here."
+                                );
+                            }
+                        }
+                        reply_id.explicit_id = reply_id.explicit_id.or(id.as_ref());
+                        reply_id.handlers.push((reply_on, variant));
+                    }
+                    None => reply_ids.push(ReplyId {
+                        handler_id,
+                        explicit_id: id.as_ref(),
+                        handlers: vec![(reply_on, variant)],
+                    }),
+                }
+            }
+        }
+
+        for (index, reply_id) in reply_ids.iter().enumerate() {
+            if let Some(previous) = reply_ids[..index]
+                .iter()
+                .find(|previous| previous.value() == reply_id.value())
+            {
+                emit_error!(
+                    reply_id.handler_id.span(), "Reply ids `{}` and `{}` have the same value {}.", previous.handler_id, reply_id.handler_id, reply_id.value();
+                    note = "Set a different value of one of them with `#[sv::msg(reply, id = ...)]`."
+                );
+            }
+        }
+
+        (reply_ids, fallback)
+    }
+}
@@ -1,14 +1,15 @@
+use crate::crate_module;
 use crate::parser::attributes::MsgAttrForwarding;
 use crate::parser::variant_descs::AsVariantDescs;
-use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes};
+use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes, VersionAttr};
 use crate::types::msg_field::MsgField;
-use crate::types::msg_variant::MsgVariants;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
 use crate::utils::{as_where_clause, emit_bracketed_generics, filter_wheres};
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{GenericParam, ItemImpl, Type};
+use syn::{GenericParam, ItemImpl, Type, WherePredicate};
 
 /// Representation of single struct message
 pub struct StructMessage<'a> {
@@ -19,6 +20,9 @@ pub struct StructMessage<'a> {
     error: &'a ContractErrorAttr,
     custom: &'a Custom,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    version: Option<&'a VersionAttr>,
+    actions: bool,
+    arbitrary: bool,
 }
 
 impl<'a> StructMessage<'a> {
@@ -28,6 +32,8 @@ impl<'a> StructMessage<'a> {
         generics: &'a [&'a GenericParam],
         error: &'a ContractErrorAttr,
         custom: &'a Custom,
+        version: Option<&'a VersionAttr>,
+        actions: bool,
     ) -> Option<StructMessage<'a>> {
         let contract_type = &source.self_ty;
 
@@ -55,7 +61,9 @@ impl<'a> StructMessage<'a> {
             return None;
         }
 
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let arbitrary = cfg!(feature = "arbitrary") && parsed_attrs.arbitrary.is_some();
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
@@ -69,6 +77,9 @@ impl<'a> StructMessage<'a> {
             error,
             custom,
             msg_attrs_to_forward,
+            version,
+            actions,
+            arbitrary,
         })
     }
 
@@ -81,6 +92,7 @@ impl<'a> StructMessage<'a> {
             error,
             custom,
             msg_attrs_to_forward,
+            ..
         } = self;
 
         let Some(variant) = variants.get_only_variant() else {
@@ -105,11 +117,24 @@ impl<'a> StructMessage<'a> {
 
         let ctx_type = variant.msg_type().emit_ctx_type(&custom.query_or_default());
         let fields_names: Vec<_> = variant.fields().iter().map(MsgField::name).collect();
+        let funds_check = variant.emit_funds_check();
+        let guards = variant.emit_guards();
+        let args = variant.emit_call_args(&fields_names);
+        let call = quote! { contract.#function_name(Into::into(ctx), #(#args,)*) };
+        let call = match self.actions {
+            true => {
+                let action = function_name.to_string();
+                quote! { #call.map(|resp| resp.add_attribute("action", #action)) }
+            }
+            false => call,
+        };
+        let method_call = self.emit_method_call(*variant.msg_type(), guards, call);
         let parameters = variant.fields().iter().map(MsgField::emit_method_field);
         let fields = variant.fields().iter().map(MsgField::emit_pub);
 
         let msg_attrs_to_forward = msg_attrs_to_forward.iter().map(|attr| &attr.attrs);
         let derive_call = variant.msg_type().emit_derive_call();
+        let arbitrary_impl = self.emit_arbitrary_impl(variant, &wheres);
 
         quote! {
             #[allow(clippy::derive_partial_eq_without_eq)]
@@ -128,9 +153,81 @@ impl<'a> StructMessage<'a> {
                 pub fn dispatch #bracketed_unused_generics (self, contract: &#contract_type, ctx: #ctx_type) -> #ret_type #full_where
                 {
                     let Self { #(#fields_names,)* } = self;
-                    contract.#function_name(Into::into(ctx), #(#fields_names,)*).map_err(Into::into)
+                    #funds_check
+                    #method_call
+                }
+            }
+
+            #arbitrary_impl
+        }
+    }
+
+    /// Emits the `arbitrary::Arbitrary` implementation of the message
+    /// if requested with `#[sv::arbitrary]`.
+    fn emit_arbitrary_impl(&self, variant: &MsgVariant, wheres: &[&WherePredicate]) -> TokenStream {
+        let sylvia = crate_module();
+
+        if !self.arbitrary {
+            return quote! {};
+        }
+
+        let used_generics = self.variants.used_generics();
+        let bracketed_used_generics = emit_bracketed_generics(used_generics);
+        let name = variant.msg_type().emit_msg_name();
+        let fields = variant.fields().iter().map(MsgField::emit_arbitrary);
+
+        quote! {
+            impl<'sv_arb, #(#used_generics,)* > #sylvia ::arbitrary::Arbitrary<'sv_arb> for #name #bracketed_used_generics
+            where
+                #(#wheres,)*
+                #(#used_generics: #sylvia ::arbitrary::Arbitrary<'sv_arb>,)*
+            {
+                fn arbitrary(u: &mut #sylvia ::arbitrary::Unstructured<'sv_arb>) -> #sylvia ::arbitrary::Result<Self> {
+                    Ok(Self { #(#fields,)* })
+                }
+            }
+        }
+    }
+
+    /// Emits the guarded call of the message handler.
+    ///
+    /// If the contract version is defined, the `cw2` contract info is stored on instantiation.
+    /// On migration the stored contract info is validated before the handler is called and
+    /// updated after it succeeds, so the handler can still read the version it migrates from.
+    fn emit_method_call(
+        &self,
+        msg_ty: MsgType,
+        guards: TokenStream,
+        call: TokenStream,
+    ) -> TokenStream {
+        let sylvia = crate_module();
+
+        match (msg_ty, self.version) {
+            (MsgType::Instantiate, Some(version)) => {
+                let set_version = version.emit_set_version(quote! { ctx.0.storage });
+                quote! {
+                    #set_version
+                    #guards
+                    #call.map_err(Into::into)
+                }
+            }
+            (MsgType::Migrate, Some(version)) => {
+                let ensure_upgrade = version.emit_ensure_upgrade(quote! { sv_deps.storage });
+                let set_version = version.emit_set_version(quote! { sv_deps.storage });
+                quote! {
+                    let (mut sv_deps, sv_env) = ctx;
+                    #ensure_upgrade
+                    let ctx = (#sylvia ::cw_std::DepsMut::branch(&mut sv_deps), sv_env);
+                    #guards
+                    let response = #call?;
+                    #set_version
+                    Ok(response)
                 }
             }
+            _ => quote! {
+                #guards
+                #call.map_err(Into::into)
+            },
         }
     }
 }
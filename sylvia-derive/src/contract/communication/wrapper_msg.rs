@@ -1,10 +1,10 @@
 use crate::crate_module;
 use crate::fold::StripGenerics;
-use crate::parser::{ContractErrorAttr, Custom, MsgType};
+use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes, VersionAttr};
 use crate::types::interfaces::Interfaces;
 use crate::utils::emit_bracketed_generics;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::fold::Fold;
 use syn::spanned::Spanned;
 use syn::{Ident, ItemImpl, Type};
@@ -22,6 +22,9 @@ pub struct GlueMessage<'a> {
     error: &'a ContractErrorAttr,
     custom: &'a Custom,
     interfaces: &'a Interfaces,
+    version: Option<&'a VersionAttr>,
+    actions: bool,
+    arbitrary: bool,
 }
 
 impl<'a> GlueMessage<'a> {
@@ -31,7 +34,14 @@ impl<'a> GlueMessage<'a> {
         error: &'a ContractErrorAttr,
         custom: &'a Custom,
         interfaces: &'a Interfaces,
+        version: Option<&'a VersionAttr>,
+        actions: bool,
     ) -> Self {
+        let arbitrary = cfg!(feature = "arbitrary")
+            && ParsedSylviaAttributes::new(source.attrs.iter())
+                .arbitrary
+                .is_some();
+
         GlueMessage {
             source,
             contract: &source.self_ty,
@@ -39,6 +49,9 @@ impl<'a> GlueMessage<'a> {
             error,
             custom,
             interfaces,
+            version,
+            actions,
+            arbitrary,
         }
     }
 
@@ -62,38 +75,58 @@ impl<'a> GlueMessage<'a> {
         let enum_accessor = msg_ty.as_accessor_name();
         let contract_name = StripGenerics.fold_type((*contract).clone());
 
-        let variants = interfaces.emit_glue_message_variants(msg_ty, contract);
-        let types = interfaces.emit_glue_message_types(msg_ty, contract);
+        let mut variants = interfaces.emit_glue_message_variants(msg_ty, contract);
+        let mut schemas = interfaces.emit_glue_message_schemas(msg_ty, contract);
 
         let ep_name = msg_ty.emit_ep_name();
         let messages_fn_name = Ident::new(&format!("{}_messages", ep_name), contract.span());
-        let contract_variant = quote! { #contract_name ( <#contract as #sylvia ::types::ContractApi> :: #enum_accessor ) };
+        let contract_variant = quote! { #[serde(untagged)] #contract_name ( <#contract as #sylvia ::types::ContractApi> :: #enum_accessor ) };
         let mut messages_call = interfaces.emit_messages_call(msg_ty);
         messages_call.push(quote! { &#messages_fn_name() });
+        let mut messages_names = interfaces.emit_collision_names();
+        messages_names.push(contract_name.to_token_stream().to_string());
 
-        let variants_cnt = messages_call.len();
-
-        let dispatch_arms = interfaces.emit_dispatch_arms(msg_ty);
+        // Responses of the `exec` and `sudo` messages are tagged with the `action` attribute
+        // if the contract is marked with `#[sv::actions]`.
+        let actions = self.actions && matches!(msg_ty, MsgType::Exec | MsgType::Sudo);
+        let mut dispatch_arms = interfaces.emit_dispatch_arms(msg_ty, actions);
+        let mut variants_deserialization = interfaces.emit_variants_deserialization();
+        variants_deserialization.push(quote! { msg.deserialize().map(Self:: #contract_name) });
+        let mut response_schemas_calls = interfaces.emit_response_schemas_calls(msg_ty, contract);
 
-        let dispatch_arm =
-            quote! {#contract_enum_name :: #contract_name (msg) => msg.dispatch(contract, ctx)};
+        // Contracts with defined version expose the `cw2` contract info with a `contract_info` query.
+        if let (MsgType::Query, Some(_)) = (msg_ty, self.version) {
+            let contract_info = quote! { #sylvia ::version::ContractInfoQueryMsg };
+            variants.push(quote! { #[serde(untagged)] ContractInfo(#contract_info) });
+            schemas.push(quote! { gen.subschema_for::<#contract_info>() });
+            messages_call.push(quote! { &#sylvia ::version::query_messages() });
+            messages_names.push("sv::version".to_owned());
+            dispatch_arms.push(quote! {
+                #contract_enum_name :: ContractInfo(msg) => msg.dispatch(ctx.0).map_err(Into::into)
+            });
+            variants_deserialization.push(quote! { msg.deserialize().map(Self::ContractInfo) });
+            response_schemas_calls
+                .push(quote! { <#contract_info as #sylvia ::cw_schema::QueryResponses>::response_schemas_impl() });
+        }
 
-        let interfaces_deserialization_attempts = interfaces.emit_deserialization_attempts(msg_ty);
+        let variants_cnt = messages_call.len();
+        let variants_indexes = 0..variants_cnt;
 
-        let contract_deserialization_attempt = quote! {
-            let msgs = &#messages_fn_name();
-            if msgs.into_iter().any(|msg| msg == &recv_msg_name) {
-                match val.deserialize_into() {
-                    Ok(msg) => return Ok(Self:: #contract_name (msg)),
-                    Err(err) => return Err(D::Error::custom(err)).map(Self:: #contract_name )
-                };
+        let dispatch_arm = match actions {
+            true => quote! {
+                #contract_enum_name :: #contract_name (msg) => {
+                    let action = msg.sv_msg_name();
+                    msg.dispatch(contract, ctx).map(|resp| resp.add_attribute("action", action))
+                }
+            },
+            false => {
+                quote! {#contract_enum_name :: #contract_name (msg) => msg.dispatch(contract, ctx)}
             }
         };
 
         let ctx_type = msg_ty.emit_ctx_type(&custom.query_or_default());
         let ret_type = msg_ty.emit_result_type(&custom.msg_or_default(), &error.error);
 
-        let mut response_schemas_calls = interfaces.emit_response_schemas_calls(msg_ty, contract);
         response_schemas_calls
             .push(quote! {<#contract as #sylvia ::types::ContractApi> :: #enum_accessor ::response_schemas_impl()});
 
@@ -114,6 +147,7 @@ impl<'a> GlueMessage<'a> {
             }
         };
 
+        let arbitrary_impl = self.emit_arbitrary_impl();
         let modules_names = interfaces.variants_modules();
         let variants_names = interfaces.variants_names();
         let serde = quote! { #sylvia:: serde }.to_string();
@@ -121,7 +155,7 @@ impl<'a> GlueMessage<'a> {
         quote! {
             #[allow(clippy::derive_partial_eq_without_eq)]
             #[derive(#sylvia ::serde::Serialize, Clone, Debug, PartialEq)]
-            #[serde(rename_all="snake_case", untagged)]
+            #[serde(rename_all="snake_case")]
             #[serde(crate = #serde )]
             pub enum #contract_enum_name #bracketed_wrapper_generics #full_where_clause {
                 #(#variants,)*
@@ -155,7 +189,7 @@ impl<'a> GlueMessage<'a> {
                                 any_of: Some(
                                     <[_]>::into_vec(
                                         Box::new([
-                                            #(gen.subschema_for::<#types>(),)*
+                                            #(#schemas,)*
                                             gen.subschema_for::< <#contract as #sylvia ::types::ContractApi> :: #enum_accessor >(),
                                         ]),
                                     ),
@@ -176,7 +210,8 @@ impl<'a> GlueMessage<'a> {
                 ) -> #ret_type #full_where_clause {
                     const _: () = {
                         let msgs: [&[&str]; #variants_cnt] = [#(#messages_call),*];
-                        #sylvia ::utils::assert_no_intersection(msgs);
+                        let names: [&str; #variants_cnt] = [#(#messages_names),*];
+                        #sylvia ::utils::assert_no_intersection_named(msgs, names);
                     };
 
                     match self {
@@ -188,41 +223,39 @@ impl<'a> GlueMessage<'a> {
 
             #response_schemas
 
-            impl<'sv_de, #(#generics,)* > #sylvia ::serde::Deserialize<'sv_de> for #contract_enum_name #bracketed_wrapper_generics #full_where_clause {
-                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-                    where D: #sylvia ::serde::Deserializer<'sv_de>,
-                {
-                    use #sylvia ::serde::de::Error;
+            impl<'sv_de, #(#generics,)* > #sylvia ::glue::GlueMessage<'sv_de> for #contract_enum_name #bracketed_wrapper_generics #full_where_clause {
+                fn messages() -> &'static [&'static [&'static str]] {
+                    const MESSAGES: [&[&str]; #variants_cnt] = [#(#messages_call),*];
+                    &MESSAGES
+                }
 
-                    let val = #sylvia ::serde_value::Value::deserialize(deserializer)?;
-                    let map = match &val {
-                        #sylvia ::serde_value::Value::Map(map) => map,
-                        _ => return Err(D::Error::custom("Wrong message format!"))
-                    };
-                    if map.len() != 1 {
-                        return Err(D::Error::custom(format!("Expected exactly one message. Received {}", map.len())))
-                    }
+                fn variants() -> &'static [&'static str] {
+                    &[#(#messages_names),*]
+                }
 
-                    // Due to earlier size check of map this unwrap is safe
-                    let recv_msg_name = map.into_iter().next().unwrap();
+                fn lookup() -> &'static [(&'static str, usize)] {
+                    const MESSAGES: [&[&str]; #variants_cnt] = [#(#messages_call),*];
+                    const LOOKUP: [(&str, usize); #sylvia ::glue::messages_count(&MESSAGES)] =
+                        #sylvia ::glue::sorted_lookup(&MESSAGES);
+                    &LOOKUP
+                }
 
-                    if let #sylvia ::serde_value::Value::String(recv_msg_name) = &recv_msg_name .0 {
-                        #(#interfaces_deserialization_attempts)*
-                        #contract_deserialization_attempt
+                fn deserialize_variant<D: #sylvia ::serde::Deserializer<'sv_de>>(
+                    index: usize,
+                    msg: #sylvia ::glue::ReceivedMsg<D>,
+                ) -> Result<Self, D::Error> {
+                    match index {
+                        #(#variants_indexes => #variants_deserialization,)*
+                        _ => unreachable!("Lookup contains only indexes of the variants"),
                     }
+                }
+            }
 
-                    let msgs: [&[&str]; #variants_cnt] = [#(#messages_call),*];
-                    let mut err_msg = msgs.into_iter().flatten().fold(
-                        // It might be better to forward the error or serialization, but we just
-                        // deserialized it from JSON, not reason to expect failure here.
-                        format!(
-                            "Unsupported message received: {}. Messages supported by this contract: ",
-                            #sylvia ::serde_json::to_string(&val).unwrap_or_else(|_| String::new())
-                        ),
-                        |mut acc, message| acc + message + ", ",
-                    );
-                    err_msg.truncate(err_msg.len() - 2);
-                    Err(D::Error::custom(err_msg))
+            impl<'sv_de, #(#generics,)* > #sylvia ::serde::Deserialize<'sv_de> for #contract_enum_name #bracketed_wrapper_generics #full_where_clause {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where D: #sylvia ::serde::Deserializer<'sv_de>,
+                {
+                    #sylvia ::glue::deserialize(deserializer)
                 }
             }
 
@@ -241,6 +274,74 @@ impl<'a> GlueMessage<'a> {
                 }
             }
             )*
+
+            #arbitrary_impl
+        }
+    }
+
+    /// Emits the `arbitrary::Arbitrary` implementation of the glue message, choosing one of
+    /// the interfaces or the contract, if requested with `#[sv::arbitrary]`.
+    fn emit_arbitrary_impl(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            source,
+            contract,
+            msg_ty,
+            interfaces,
+            version,
+            ..
+        } = self;
+
+        if !self.arbitrary {
+            return quote! {};
+        }
+
+        let generics: Vec<_> = source.generics.params.iter().collect();
+        let bracketed_wrapper_generics = emit_bracketed_generics(&generics);
+        let contract_enum_name = msg_ty.emit_msg_wrapper_name();
+        let enum_accessor = msg_ty.as_accessor_name();
+        let contract_name = StripGenerics.fold_type((*contract).clone());
+
+        let mut variants_names: Vec<_> = interfaces
+            .variants_names()
+            .map(|variant| quote! { #variant })
+            .collect();
+        if let (MsgType::Query, Some(_)) = (msg_ty, version) {
+            variants_names.push(quote! { ContractInfo });
+        }
+        variants_names.push(quote! { #contract_name });
+        let variants_cnt = variants_names.len();
+        let variants_indexes = 0..variants_cnt;
+
+        let where_predicates = source
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.predicates);
+        // Messages of the generic contracts depend on the generics, so they are bound to
+        // implement `Arbitrary`.
+        let variants_bounds = interfaces
+            .variants_modules()
+            .map(|module| quote! { <#contract as #module ::sv::InterfaceMessagesApi>:: #enum_accessor })
+            .chain(std::iter::once(
+                quote! { <#contract as #sylvia ::types::ContractApi>:: #enum_accessor },
+            ))
+            .filter(|_| !generics.is_empty())
+            .map(|ty| quote! { #ty: #sylvia ::arbitrary::Arbitrary<'sv_arb> });
+
+        quote! {
+            impl<'sv_arb, #(#generics,)* > #sylvia ::arbitrary::Arbitrary<'sv_arb> for #contract_enum_name #bracketed_wrapper_generics
+            where
+                #(#where_predicates,)*
+                #(#variants_bounds,)*
+            {
+                fn arbitrary(u: &mut #sylvia ::arbitrary::Unstructured<'sv_arb>) -> #sylvia ::arbitrary::Result<Self> {
+                    Ok(match u.choose_index(#variants_cnt)? {
+                        #(#variants_indexes => Self:: #variants_names (u.arbitrary()?),)*
+                        _ => unreachable!(),
+                    })
+                }
+            }
         }
     }
 }
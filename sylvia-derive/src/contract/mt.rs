@@ -1,16 +1,18 @@
-use convert_case::Case;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::{parse_quote, GenericParam, ItemImpl, Type};
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote};
+use syn::{parse_quote, GenericParam, ImplItem, ItemImpl, Type};
 
 use crate::crate_module;
 use crate::parser::attributes::msg::MsgType;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{
     Custom, FilteredOverrideEntryPoints, OverrideEntryPoint, ParsedSylviaAttributes,
+    SylviaAttribute,
 };
+use crate::types::interfaces::Interfaces;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::{emit_bracketed_generics, SvCasing};
+use crate::utils::emit_bracketed_generics;
 
 fn get_ident_from_type(contract_name: &Type) -> &Ident {
     let Type::Path(type_path) = contract_name else {
@@ -39,6 +41,10 @@ pub struct MtHelpers<'a> {
     migrate_variants: MsgVariants<'a, GenericParam>,
     reply_variants: MsgVariants<'a, GenericParam>,
     sudo_variants: MsgVariants<'a, GenericParam>,
+    invariants: Vec<&'a Ident>,
+    interfaces: &'a Interfaces,
+    has_version: bool,
+    arbitrary: bool,
 }
 
 impl<'a> MtHelpers<'a> {
@@ -47,6 +53,8 @@ impl<'a> MtHelpers<'a> {
         generic_params: &'a [&'a GenericParam],
         custom: &'a Custom,
         override_entry_points: Vec<OverrideEntryPoint>,
+        interfaces: &'a Interfaces,
+        has_version: bool,
     ) -> Self {
         let where_clause = &source.generics.where_clause;
         let instantiate_variant = MsgVariants::new(
@@ -86,13 +94,13 @@ impl<'a> MtHelpers<'a> {
             where_clause,
         );
 
-        let error_type = ParsedSylviaAttributes::new(source.attrs.iter())
-            .error_attrs
-            .unwrap_or_default()
-            .error;
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let arbitrary = cfg!(feature = "arbitrary") && parsed_attrs.arbitrary.is_some();
+        let error_type = parsed_attrs.error_attrs.unwrap_or_default().error;
         let error_type = parse_quote! { #error_type };
 
         let contract_name = &source.self_ty;
+        let invariants = collect_invariants(source);
 
         Self {
             error_type,
@@ -108,6 +116,10 @@ impl<'a> MtHelpers<'a> {
             sudo_variants,
             migrate_variants,
             reply_variants,
+            invariants,
+            interfaces,
+            has_version,
+            arbitrary,
         }
     }
 
@@ -138,6 +150,7 @@ impl<'a> MtHelpers<'a> {
                 DistrT,
                 IbcT,
                 GovT,
+                StargateT,
             >
         };
         let api = quote! { < #contract_name as #sylvia ::types::ContractApi> };
@@ -176,6 +189,9 @@ impl<'a> MtHelpers<'a> {
             .variants()
             .map(|variant| variant.emit_mt_method_declaration(&custom_msg, error_type, &api));
 
+        let (ibc_methods_declarations, ibc_methods): (Vec<_>, Vec<_>) =
+            self.emit_ibc_methods().into_iter().unzip();
+
         let where_predicates = where_clause
             .as_ref()
             .map(|where_clause| &where_clause.predicates);
@@ -196,9 +212,10 @@ impl<'a> MtHelpers<'a> {
                     #( #migrate_methods_declarations )*
                     #( #query_methods_declarations )*
                     #( #sudo_methods_declarations )*
+                    #( #ibc_methods_declarations )*
                 }
 
-                impl<'app, #(#generic_params,)* BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT >
+                impl<'app, #(#generic_params,)* BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT >
                     #trait_name <'app, #(#generic_params,)* #mt_app >
                         for #sylvia ::multitest::Proxy <'app, #mt_app, #contract_name >
                     where
@@ -213,6 +230,7 @@ impl<'a> MtHelpers<'a> {
                         DistrT: #sylvia ::cw_multi_test::Distribution,
                         IbcT: #sylvia ::cw_multi_test::Ibc,
                         GovT: #sylvia ::cw_multi_test::Gov,
+                        StargateT: #sylvia ::cw_multi_test::Stargate,
                         #mt_app : Executor< #custom_msg >,
                         #where_predicates
                 {
@@ -220,6 +238,7 @@ impl<'a> MtHelpers<'a> {
                     #( #migrate_methods )*
                     #( #query_methods )*
                     #( #sudo_methods )*
+                    #( #ibc_methods )*
                 }
 
                 #impl_contract
@@ -293,9 +312,13 @@ impl<'a> MtHelpers<'a> {
                 DistrT,
                 IbcT,
                 GovT,
+                StargateT,
             >
         };
 
+        let add_invariants = self.emit_add_invariants();
+        let contract_messages = self.emit_contract_messages();
+
         let code_info = if cfg!(feature = "cosmwasm_1_2") {
             quote! {
                 pub fn code_info(&self) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::CodeInfoResponse> {
@@ -306,6 +329,17 @@ impl<'a> MtHelpers<'a> {
             quote! {}
         };
 
+        let fuzz = if self.arbitrary {
+            quote! {
+                /// Creates the driver sending random sequences of the messages to the contracts of this code.
+                pub fn fuzz(&self, senders: &[ #sylvia ::cw_std::Addr]) -> #sylvia ::multitest::fuzz::Fuzz<'app, #contract, #mt_app > {
+                    #sylvia ::multitest::fuzz::Fuzz::new(self.app, self.code_id, senders)
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         quote! {
             pub struct CodeId<'app, Contract, MtApp> {
                 code_id: u64,
@@ -314,7 +348,7 @@ impl<'a> MtHelpers<'a> {
 
             }
 
-            impl<'app, #(#generic_params,)* BankT, ApiT, StorageT, CustomT, StakingT, DistrT, IbcT, GovT > CodeId<'app, #contract, #mt_app >
+            impl<'app, #(#generic_params,)* BankT, ApiT, StorageT, CustomT, StakingT, DistrT, IbcT, GovT, StargateT > CodeId<'app, #contract, #mt_app >
                 where
                     BankT: #sylvia ::cw_multi_test::Bank,
                     ApiT: #sylvia ::cw_std::Api,
@@ -324,12 +358,18 @@ impl<'a> MtHelpers<'a> {
                     DistrT: #sylvia ::cw_multi_test::Distribution,
                     IbcT: #sylvia ::cw_multi_test::Ibc,
                     GovT: #sylvia ::cw_multi_test::Gov,
+                    StargateT: #sylvia ::cw_multi_test::Stargate,
                     #where_predicates
             {
                 pub fn store_code(app: &'app #sylvia ::multitest::App< #mt_app >) -> Self {
-                    let code_id = app
-                        .app_mut()
-                        .store_code(Box::new( #contract_ident:: < #(#generic_params_lifetimes_replaced),* > ::new() ));
+                    let contract = #contract_ident:: < #(#generic_params_lifetimes_replaced),* > ::new();
+                    let code_id = match app.coverage_recorder(#contract_messages) {
+                        Some(recorder) => app
+                            .app_mut()
+                            .store_code(Box::new( #sylvia ::multitest::coverage::CoveredContract::new(contract, recorder) )),
+                        None => app.app_mut().store_code(Box::new(contract)),
+                    };
+                    #add_invariants
                     Self { code_id, app, _phantom: std::marker::PhantomData::default() }
                 }
 
@@ -339,6 +379,8 @@ impl<'a> MtHelpers<'a> {
 
                 #code_info
 
+                #fuzz
+
                 pub fn instantiate(
                     &self, #(#fields,)*
                 ) -> InstantiateProxy<'_, 'app, #(#generic_params,)* #mt_app > {
@@ -356,6 +398,129 @@ impl<'a> MtHelpers<'a> {
         }
     }
 
+    /// Emits the messages declared by the contract and its interfaces, recorded by the
+    /// [App](sylvia::multitest::App) coverage.
+    fn emit_contract_messages(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            contract_name,
+            interfaces,
+            has_version,
+            migrate_variants,
+            reply_variants,
+            ..
+        } = self;
+
+        let contract = get_ident_from_type(contract_name).to_string();
+        let declared = |msg_ty: MsgType| {
+            let messages_fn_name = format_ident!("{}_messages", msg_ty.emit_ep_name());
+            let mut declared = interfaces.emit_declared_messages(&msg_ty);
+            if let (MsgType::Query, true) = (msg_ty, *has_version) {
+                declared.push(quote! {
+                    #sylvia ::multitest::coverage::DeclaredMessages {
+                        source: "sv::version",
+                        namespace: None,
+                        messages: &#sylvia ::version::query_messages(),
+                    }
+                });
+            }
+            declared.push(quote! {
+                #sylvia ::multitest::coverage::DeclaredMessages {
+                    source: #contract,
+                    namespace: None,
+                    messages: &super:: #messages_fn_name (),
+                }
+            });
+            declared
+        };
+        let exec = declared(MsgType::Exec);
+        let query = declared(MsgType::Query);
+        let sudo = declared(MsgType::Sudo);
+
+        let migrate = match migrate_variants.get_only_variant() {
+            Some(variant) => {
+                let name = variant.function_name().to_string();
+                quote! { &[#name] }
+            }
+            None => quote! { &[] },
+        };
+        let (reply, reply_handler) = match reply_variants.variants().count() {
+            0 => (quote! { &[] }, quote! { |_| None }),
+            _ => (
+                quote! { &super::reply_messages() },
+                quote! { super::reply_handler_name },
+            ),
+        };
+
+        quote! {
+            {
+                const EXEC: &[#sylvia ::multitest::coverage::DeclaredMessages] = &[#(#exec,)*];
+                const QUERY: &[#sylvia ::multitest::coverage::DeclaredMessages] = &[#(#query,)*];
+                const SUDO: &[#sylvia ::multitest::coverage::DeclaredMessages] = &[#(#sudo,)*];
+                const REPLY: &[&str] = #reply;
+                #sylvia ::multitest::coverage::ContractMessages {
+                    contract: #contract,
+                    exec: EXEC,
+                    query: QUERY,
+                    sudo: SUDO,
+                    migrate: #migrate,
+                    reply: REPLY,
+                    reply_handler: #reply_handler,
+                }
+            }
+        }
+    }
+
+    /// Emits the registration of the `#[sv::invariant]` methods in the [App](sylvia::multitest::App).
+    ///
+    /// The invariants are called on the contracts of the stored code, with the storage of the
+    /// contract and the current block.
+    fn emit_add_invariants(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            contract_name,
+            custom,
+            invariants,
+            ..
+        } = self;
+
+        if invariants.is_empty() {
+            return quote! {};
+        }
+
+        let custom_query = custom.query_or_default();
+        let invariants_names = invariants.iter().map(|invariant| invariant.to_string());
+
+        quote! {
+            app.add_invariants(move |app, contract_addr| {
+                match app.wrap().query_wasm_contract_info(contract_addr.to_string()) {
+                    Ok(info) if info.code_id == code_id => (),
+                    _ => return Ok(()),
+                }
+                let storage = app.contract_storage(contract_addr);
+                let deps = #sylvia ::cw_std::Deps {
+                    storage: &*storage,
+                    api: app.api(),
+                    querier: #sylvia ::cw_std::QuerierWrapper::< #custom_query >::new(app),
+                };
+                let mut env = #sylvia ::cw_std::testing::mock_env();
+                env.block = app.block_info();
+                env.contract.address = contract_addr.clone();
+
+                let contract = #contract_name ::new();
+                #(
+                    contract
+                        . #invariants ((deps, env.clone()).into())
+                        .map_err(|err| #sylvia ::multitest::InvariantViolation {
+                            invariant: #invariants_names,
+                            error: err.to_string(),
+                        })?;
+                )*
+                Ok(())
+            });
+        }
+    }
+
     fn emit_instantiate_proxy(&self, contract: &Type) -> TokenStream {
         let sylvia = crate_module();
         let Self {
@@ -377,6 +542,7 @@ impl<'a> MtHelpers<'a> {
         let custom_msg = self.custom.msg_or_default();
 
         let instantiate2_body = self.emit_instantiate2_body();
+        let instantiate_msg = self.emit_instantiate_wasm_msg();
 
         quote! {
             pub struct InstantiateProxy<'proxy, 'app, #(#generic_params,)* MtApp> {
@@ -413,9 +579,24 @@ impl<'a> MtHelpers<'a> {
 
                 #[track_caller]
                 pub fn call(self, sender: &#sylvia ::cw_std::Addr ) -> Result<#sylvia ::multitest::Proxy<'app, MtApp, #contract_name >, #error_type> {
+                    let (proxy, msg) = self.send(sender).map_err(|err| err.into_contract_error())?;
+                    proxy.app.check_invariants(&proxy.contract_addr, &msg);
+                    Ok(proxy)
+                }
+
+                #[track_caller]
+                pub fn try_call(self, sender: &#sylvia ::cw_std::Addr ) -> Result<#sylvia ::multitest::Proxy<'app, MtApp, #contract_name >, #sylvia ::multitest::MtError< #error_type >> {
+                    let (proxy, _) = self.send(sender)?;
+                    proxy.app
+                        .try_check_invariants(&proxy.contract_addr)
+                        .map_err(#sylvia ::multitest::MtError::InvariantViolated)?;
+                    Ok(proxy)
+                }
+
+                fn send(self, sender: &#sylvia ::cw_std::Addr ) -> Result<(#sylvia ::multitest::Proxy<'app, MtApp, #contract_name >, InstantiateMsg #bracketed_used_generics), #sylvia ::multitest::MtError< #error_type >> {
                     let Self {code_id, funds, label, admin, salt, msg} = self;
 
-                    match salt {
+                    let proxy = match salt {
                         Some(salt) => {
                             #instantiate2_body
                         },
@@ -429,14 +610,87 @@ impl<'a> MtHelpers<'a> {
                                 label,
                                 admin,
                             )
-                            .map_err(|err| err.downcast().unwrap())
+                            .map_err(|err| #sylvia ::multitest::MtError::from_anyhow(err, None))
                             .map(|addr| #sylvia ::multitest::Proxy {
                                 contract_addr: addr,
                                 app: code_id.app,
                                 _phantom: std::marker::PhantomData::default(),
                             }),
-                    }
+                    }?;
+                    Ok((proxy, msg))
                 }
+
+                #[track_caller]
+                pub fn dry_run(self, sender: &#sylvia ::cw_std::Addr ) -> Result<#sylvia ::cw_multi_test::AppResponse, #error_type>
+                    where MtApp: #sylvia ::multitest::AppState
+                {
+                    let Self {code_id, funds, label, admin, salt, msg} = self;
+                    let msg = #sylvia ::cw_std::to_json_binary(&msg)
+                        .map_err(Into::< #error_type >::into)?;
+                    #instantiate_msg
+
+                    code_id.app.dry_run(|app| {
+                        app.app_mut()
+                            .execute(sender.clone(), msg.into())
+                            .map_err(|err| #sylvia ::multitest::MtError::from_anyhow(err, None).into_contract_error())
+                    })
+                }
+            }
+
+            impl<'proxy, 'app, #(#generic_params,)* MtApp> #sylvia ::multitest::TransactionMsg< #custom_msg > for InstantiateProxy<'proxy, 'app, #(#generic_params,)* MtApp>
+                where
+                    MtApp: Executor< #custom_msg >,
+                    #where_predicates
+            {
+                fn into_msg(self) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::CosmosMsg< #custom_msg >> {
+                    let Self {code_id, funds, label, admin, salt, msg} = self;
+                    let msg = #sylvia ::cw_std::to_json_binary(&msg)?;
+                    #instantiate_msg
+
+                    Ok(msg.into())
+                }
+            }
+        }
+    }
+
+    fn emit_instantiate_wasm_msg(&self) -> TokenStream {
+        let sylvia = crate_module();
+
+        if cfg!(feature = "cosmwasm_1_2") {
+            quote! {
+                let msg = match salt {
+                    Some(salt) => #sylvia ::cw_std::WasmMsg::Instantiate2 {
+                        admin,
+                        code_id: code_id.code_id,
+                        msg,
+                        funds: funds.to_owned(),
+                        label: label.to_owned(),
+                        salt: salt.into(),
+                    },
+                    None => #sylvia ::cw_std::WasmMsg::Instantiate {
+                        admin,
+                        code_id: code_id.code_id,
+                        msg,
+                        funds: funds.to_owned(),
+                        label: label.to_owned(),
+                    },
+                };
+            }
+        } else {
+            quote! {
+                if salt.is_some() {
+                    let err = #sylvia ::cw_std::StdError::generic_err(
+                        "`with_salt` was called, but it requires `cosmwasm_1_2` feature enabled. Consider removing `with_salt` or adding the `cosmwasm_1_2` feature."
+                    );
+                    return Err(Into::into(err));
+                }
+                let msg = #sylvia ::cw_std::WasmMsg::Instantiate {
+                    admin,
+                    code_id: code_id.code_id,
+                    msg,
+                    funds: funds.to_owned(),
+                    label: label.to_owned(),
+                };
             }
         }
     }
@@ -462,10 +716,10 @@ impl<'a> MtHelpers<'a> {
                 let app_response = (*code_id.app)
                     .app_mut()
                     .execute(sender.clone(), msg.into())
-                    .map_err(|err| err.downcast::< #error_type >().unwrap())?;
+                    .map_err(|err| #sylvia ::multitest::MtError::from_anyhow(err, None))?;
 
                 #sylvia:: cw_utils::parse_instantiate_response_data(app_response.data.unwrap().as_slice())
-                    .map_err(|err| Into::into( #sylvia ::cw_std::StdError::generic_err(err.to_string())))
+                    .map_err(|err| #sylvia ::multitest::MtError::Contract(Into::into( #sylvia ::cw_std::StdError::generic_err(err.to_string()))))
                     .map(|data| #sylvia ::multitest::Proxy {
                         contract_addr: #sylvia ::cw_std::Addr::unchecked(data.contract_address),
                         app: code_id.app,
@@ -477,7 +731,7 @@ impl<'a> MtHelpers<'a> {
                 let err = #sylvia ::cw_std::StdError::generic_err(
                     "`with_salt` was called, but it requires `cosmwasm_1_2` feature enabled. Consider removing `with_salt` or adding the `cosmwasm_1_2` feature."
                 );
-                Err(Into::into(err))
+                Err(#sylvia ::multitest::MtError::Contract(Into::into(err)))
             }
         }
     }
@@ -528,22 +782,27 @@ impl<'a> MtHelpers<'a> {
 
         let reply_body = match override_entry_points.get_entry_point(MsgType::Reply) {
             Some(entry_point) => entry_point.emit_multitest_dispatch(),
-            None => reply_variants
-                .get_only_variant()
-                .as_ref()
-                .map(|reply| {
-                    let reply_name = reply.name().to_case(Case::Snake);
-                    quote! {
-                        self. #reply_name ((deps, env).into(), msg).map_err(Into::into)
-                    }
-                })
-                .unwrap_or_else(|| {
-                    quote! {
-                        #sylvia ::anyhow::bail!("reply not implemented for contract")
-                    }
-                }),
+            None if reply_variants.get_only_variant().is_some() => quote! {
+                dispatch_reply(deps, env, msg, self).map_err(Into::into)
+            },
+            None => quote! { #sylvia ::anyhow::bail!("reply not implemented for contract") },
         };
 
+        let [instantiate_body, exec_body, sudo_body, reply_body, migrate_body] = [
+            instantiate_body,
+            exec_body,
+            sudo_body,
+            reply_body,
+            migrate_body,
+        ]
+        .map(|body| {
+            quote! {
+                let failed_contract = #sylvia ::multitest::FailedContract::new(&env);
+                (move || -> #sylvia ::anyhow::Result<_> { #body })()
+                    .map_err(|err| err.context(failed_contract))
+            }
+        });
+
         let custom_msg = custom.msg_or_default();
         let custom_query = custom.query_or_default();
 
@@ -607,6 +866,105 @@ impl<'a> MtHelpers<'a> {
             }
         }
     }
+
+    /// Emits the proxy methods calling the IBC handlers of the contract, as declaration and
+    /// definition pairs.
+    fn emit_ibc_methods(&self) -> Vec<(TokenStream, TokenStream)> {
+        let Self {
+            source,
+            contract_name,
+            custom,
+            ..
+        } = self;
+        let sylvia = crate_module();
+
+        if !cfg!(feature = "stargate") {
+            return vec![];
+        }
+
+        let custom_msg = custom.msg_or_default();
+        let custom_query = custom.query_or_default();
+
+        MsgType::ibc_msg_types()
+            .into_iter()
+            .filter_map(|msg_ty| {
+                MsgVariants::<GenericParam>::new(source.as_variants(), msg_ty, &[], &None)
+                    .get_only_variant()
+                    .map(|variant| {
+                        let ep_name = msg_ty.emit_ep_name();
+                        let msg_name = msg_ty.emit_msg_name();
+                        let function_name = variant.function_name();
+                        let result = match msg_ty {
+                            MsgType::IbcChannelOpen => {
+                                quote! { #sylvia ::cw_std::IbcChannelOpenResponse }
+                            }
+                            MsgType::IbcPacketReceive => {
+                                quote! { #sylvia ::cw_std::IbcReceiveResponse<#custom_msg> }
+                            }
+                            _ => quote! { #sylvia ::cw_std::IbcBasicResponse<#custom_msg> },
+                        };
+                        let signature = quote! {
+                            fn #ep_name (&self, msg: #sylvia ::cw_std:: #msg_name) -> #sylvia ::anyhow::Result< #result >
+                        };
+
+                        let declaration = quote! { #signature; };
+                        let definition = quote! {
+                            #signature {
+                                self.app.call_entry_point(
+                                    &self.contract_addr,
+                                    |deps: #sylvia ::cw_std::DepsMut< #custom_query >, env| {
+                                        < #contract_name >::new(). #function_name ((deps, env).into(), msg).map_err(Into::into)
+                                    },
+                                )
+                            }
+                        };
+                        (declaration, definition)
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Collects the methods marked with `#[sv::invariant]`.
+fn collect_invariants(source: &ItemImpl) -> Vec<&Ident> {
+    let invariants: Vec<_> = source
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method)
+                if method.attrs.iter().any(|attr| {
+                    matches!(SylviaAttribute::new(attr), Some(SylviaAttribute::Invariant))
+                }) =>
+            {
+                Some(method)
+            }
+            _ => None,
+        })
+        .filter(|method| {
+            let attrs = ParsedSylviaAttributes::new(method.attrs.iter());
+            if attrs.msg_attr.is_some() {
+                emit_error!(
+                    method.sig.ident.span(), "The attribute `sv::invariant` can't be used on the message handler";
+                    note = "Declare the invariant as a separate method taking the `QueryCtx`"
+                );
+                return false;
+            }
+            true
+        })
+        .map(|method| &method.sig.ident)
+        .collect();
+
+    if let Some(invariant) = invariants.first() {
+        if !source.generics.params.is_empty() {
+            emit_error!(
+                invariant.span(),
+                "The attribute `sv::invariant` is not supported on generic contracts"
+            );
+            return vec![];
+        }
+    }
+
+    invariants
 }
 
 fn emit_default_dispatch(msg_ty: &MsgType, contract_name: &Type) -> TokenStream {
@@ -650,7 +1008,6 @@ impl EmitMethods for MsgVariant<'_> {
     ) -> TokenStream {
         let sylvia = crate_module();
 
-        let name = self.name();
         let return_type = self.return_type();
 
         let params: Vec<_> = self
@@ -660,7 +1017,7 @@ impl EmitMethods for MsgVariant<'_> {
             .collect();
         let arguments = self.as_fields_names();
         let type_name = self.msg_type().as_accessor_name();
-        let name = name.to_case(Case::Snake);
+        let name = self.method_name();
 
         match self.msg_type() {
             MsgType::Exec => quote! {
@@ -684,13 +1041,16 @@ impl EmitMethods for MsgVariant<'_> {
                 }
             }
             MsgType::Sudo => quote! {
+                #[track_caller]
                 fn #name (&self, #(#params,)* ) -> Result< #sylvia ::cw_multi_test::AppResponse, #error_type> {
                     let msg = #api :: #type_name :: #name ( #(#arguments),* );
 
-                    (*self.app)
+                    let resp = (*self.app)
                         .app_mut()
                         .wasm_sudo(self.contract_addr.clone(), &msg)
-                        .map_err(|err| err.downcast().unwrap())
+                        .map_err(|err| #sylvia ::multitest::MtError::< #error_type >::from_anyhow(err, Some(&self.contract_addr)).into_contract_error())?;
+                    self.app.check_invariants(&self.contract_addr, &msg);
+                    Ok(resp)
                 }
             },
             MsgType::Migrate => quote! {
@@ -713,7 +1073,6 @@ impl EmitMethods for MsgVariant<'_> {
     ) -> TokenStream {
         let sylvia = crate_module();
 
-        let name = self.name();
         let return_type = self.return_type();
 
         let params: Vec<_> = self
@@ -722,7 +1081,7 @@ impl EmitMethods for MsgVariant<'_> {
             .map(|field| field.emit_method_field_folded())
             .collect();
         let type_name = self.msg_type().as_accessor_name();
-        let name = name.to_case(Case::Snake);
+        let name = self.method_name();
 
         match self.msg_type() {
             MsgType::Exec => quote! {
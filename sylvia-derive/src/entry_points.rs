@@ -18,6 +18,7 @@ use crate::types::msg_variant::MsgVariants;
 /// Generates `entry_points` module containing:
 ///     - instantiate, execute, query and sudo entry points by default
 ///     - migrate and reply entry points if respective messages are defined
+///     - IBC entry points if respective messages are defined
 pub struct EntryPointInput<'a> {
     item: &'a ItemImpl,
     args: EntryPointArgs,
@@ -59,11 +60,13 @@ impl<'a> EntryPointInput<'a> {
 ///
 /// By default generates entry points for `instantiate`, `execute`, `query` and `sudo` messages.
 /// Generates `reply` and `migrate` entry points if respective messages are defined.
+/// Generates IBC entry points if respective messages are defined and `stargate` feature is enabled.
 pub struct EntryPoints<'a> {
     source: &'a ItemImpl,
     name: Type,
     error: Type,
-    reply: Option<Ident>,
+    has_reply: bool,
+    ibc_handlers: Vec<(MsgType, Ident)>,
     override_entry_points: Vec<OverrideEntryPoint>,
     generics: Vec<&'a GenericParam>,
     where_clause: &'a Option<WhereClause>,
@@ -81,17 +84,26 @@ impl<'a> EntryPoints<'a> {
         let generics: Vec<_> = source.generics.params.iter().collect();
         let where_clause = &source.generics.where_clause;
 
-        let reply =
+        let has_reply =
             MsgVariants::<GenericParam>::new(source.as_variants(), MsgType::Reply, &[], &None)
-                .variants()
-                .map(|variant| variant.function_name().clone())
-                .next();
+                .get_only_variant()
+                .is_some();
+
+        let ibc_handlers = MsgType::ibc_msg_types()
+            .into_iter()
+            .filter_map(|msg_ty| {
+                MsgVariants::<GenericParam>::new(source.as_variants(), msg_ty, &[], &None)
+                    .get_only_variant()
+                    .map(|variant| (msg_ty, variant.function_name().clone()))
+            })
+            .collect();
 
         Self {
             source,
             name,
             error,
-            reply,
+            has_reply,
+            ibc_handlers,
             override_entry_points,
             generics,
             where_clause,
@@ -102,7 +114,8 @@ impl<'a> EntryPoints<'a> {
     pub fn emit(&self) -> TokenStream {
         let Self {
             source,
-            reply,
+            has_reply,
+            ibc_handlers,
             override_entry_points,
             generics,
             where_clause,
@@ -146,13 +159,17 @@ impl<'a> EntryPoints<'a> {
             .get_entry_point(MsgType::Reply)
             .map(|_| quote! {})
             .unwrap_or_else(|| {
-                if reply.is_some() {
+                if *has_reply {
                     self.emit_default_entry_point(MsgType::Reply)
                 } else {
                     quote! {}
                 }
             });
 
+        let ibc_entry_points = ibc_handlers
+            .iter()
+            .map(|(msg_ty, _)| self.emit_default_entry_point(*msg_ty));
+
         quote! {
             pub mod entry_points {
                 use super::*;
@@ -162,6 +179,8 @@ impl<'a> EntryPoints<'a> {
                 #migrate
 
                 #reply_ep
+
+                #(#ibc_entry_points)*
             }
         }
     }
@@ -171,7 +190,7 @@ impl<'a> EntryPoints<'a> {
             name,
             error,
             attrs,
-            reply,
+            ibc_handlers,
             ..
         } = self;
         let sylvia = crate_module();
@@ -198,11 +217,21 @@ impl<'a> EntryPoints<'a> {
         let associated_name = msg_ty.as_accessor_wrapper_name();
         let msg = match msg_ty {
             MsgType::Reply => quote! { msg: #sylvia ::cw_std::Reply },
+            msg_ty if msg_ty.is_ibc() => {
+                let msg_name = msg_ty.emit_msg_name();
+                quote! { msg: #sylvia ::cw_std:: #msg_name }
+            }
             _ => quote! { msg: < #contract as #sylvia ::types::ContractApi> :: #associated_name },
         };
-        let dispatch = match msg_ty {
-            MsgType::Reply => quote! {
-                #contract_turbo ::new(). #reply((deps, env).into(), msg).map_err(Into::into)
+        let ibc_handler = ibc_handlers
+            .iter()
+            .find_map(|(ty, function_name)| (*ty == msg_ty).then_some(function_name));
+        let dispatch = match (msg_ty, ibc_handler) {
+            (MsgType::Reply, _) => quote! {
+                sv::dispatch_reply(deps, env, msg, &#contract_turbo ::new()).map_err(Into::into)
+            },
+            (_, Some(function_name)) => quote! {
+                #contract_turbo ::new(). #function_name ((deps, env).into(), msg).map_err(Into::into)
             },
             _ => quote! {
                 msg.dispatch(& #contract_turbo ::new() , ( #values )).map_err(Into::into)
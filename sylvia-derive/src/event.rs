@@ -0,0 +1,140 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::{Fields, ItemStruct, LitStr, Type};
+
+use crate::crate_module;
+use crate::parser::attributes::EventAttr;
+
+/// Preprocessed `TypedEvent` derive input.
+///
+/// Generates the `sylvia::types::TypedEvent` implementation converting the struct
+/// to `cosmwasm_std::Event` and back. The event type defaults to the snake cased
+/// struct name and every named field is stored as the attribute of the same name.
+pub struct EventInput<'a> {
+    item: &'a ItemStruct,
+    event_type: LitStr,
+    attributes: Vec<EventAttribute<'a>>,
+}
+
+struct EventAttribute<'a> {
+    field: &'a syn::Ident,
+    key: LitStr,
+    required: bool,
+}
+
+impl<'a> EventInput<'a> {
+    pub fn new(item: &'a ItemStruct) -> Self {
+        let event_type = EventAttr::from_attrs(&item.attrs).name.unwrap_or_else(|| {
+            LitStr::new(
+                &item.ident.to_string().to_case(Case::Snake),
+                item.ident.span(),
+            )
+        });
+
+        if !item.generics.params.is_empty() {
+            emit_error!(
+                item.generics, "Generic events are not supported.";
+                note = "Events are parsed back from the attributes, so the type of every attribute has to be known."
+            );
+        }
+
+        let attributes = match &item.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter_map(|field| {
+                    let ident = field.ident.as_ref()?;
+                    let key = EventAttr::from_attrs(&field.attrs)
+                        .name
+                        .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+                    Some(EventAttribute {
+                        field: ident,
+                        key,
+                        required: !is_option(&field.ty),
+                    })
+                })
+                .collect(),
+            Fields::Unit => vec![],
+            Fields::Unnamed(fields) => {
+                emit_error!(
+                    fields, "Tuple structs can't be used as events.";
+                    note = "Every field is stored as the event attribute named after the field."
+                );
+                vec![]
+            }
+        };
+
+        Self {
+            item,
+            event_type,
+            attributes,
+        }
+    }
+
+    pub fn process(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            item,
+            event_type,
+            attributes,
+        } = self;
+
+        let ident = &item.ident;
+        let fields: Vec<_> = attributes.iter().map(|attr| attr.field).collect();
+        let keys: Vec<_> = attributes.iter().map(|attr| &attr.key).collect();
+        let required = attributes.iter().map(|attr| attr.required);
+        let construct = match &item.fields {
+            Fields::Unit => quote! { Self },
+            _ => quote! {
+                Self {
+                    #(#fields: #sylvia ::utils::parse_event_attribute(event, #keys)?,)*
+                }
+            },
+        };
+
+        quote! {
+            impl #sylvia ::types::TypedEvent for #ident {
+                const EVENT_TYPE: &'static str = #event_type;
+
+                const ATTRIBUTES: &'static [(&'static str, bool)] = &[#((#keys, #required)),*];
+
+                fn to_event(&self) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::Event> {
+                    let event = #sylvia ::cw_std::Event::new(Self::EVENT_TYPE);
+                    #(
+                        let event = match #sylvia ::utils::event_attribute_value(&self. #fields)? {
+                            Some(value) => event.add_attribute(#keys, value),
+                            None => event,
+                        };
+                    )*
+                    Ok(event)
+                }
+
+                fn from_event(event: & #sylvia ::cw_std::Event) -> #sylvia ::cw_std::StdResult<Self> {
+                    if !<Self as #sylvia ::types::TypedEvent>::matches(event) {
+                        return Err(#sylvia ::cw_std::StdError::generic_err(format!(
+                            "Expected the `{}` event, received `{}`",
+                            Self::EVENT_TYPE,
+                            event.ty
+                        )));
+                    }
+
+                    Ok(#construct)
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the field is an `Option`, which attribute can be missing from the event.
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
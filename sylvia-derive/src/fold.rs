@@ -1,14 +1,51 @@
 use syn::fold::{self, Fold};
+use syn::parse::{Parse, Parser};
 use syn::punctuated::Punctuated;
 use syn::{
-    FnArg, ImplItemFn, ItemImpl, ItemTrait, PatType, Path, Receiver, Signature, Token, TraitItemFn,
+    FnArg, ImplItem, ImplItemFn, ItemImpl, ItemTrait, Pat, PatIdent, PatType, Path, Receiver,
+    Signature, Token, TraitItemFn,
 };
 
+use crate::parser::attributes::MsgFieldAttr;
 use crate::parser::SylviaAttribute;
 
 /// Utility for stripping all attributes from input before it is emitted
 pub struct StripInput;
 
+/// Patterns are not allowed in the arguments of trait methods without a body.
+/// Such arguments are replaced with the field name from `#[sv::msg(name = ...)]`.
+fn name_pattern_inputs(inputs: Punctuated<FnArg, Token![,]>) -> Punctuated<FnArg, Token![,]> {
+    inputs
+        .into_iter()
+        .map(|input| match input {
+            syn::FnArg::Typed(ty) if !matches!(*ty.pat, Pat::Ident(_)) => {
+                // Errors of the attribute are reported while parsing the message fields.
+                let field_attr = ty
+                    .attrs
+                    .iter()
+                    .find(|attr| matches!(SylviaAttribute::new(attr), Some(SylviaAttribute::Msg)))
+                    .and_then(|attr| attr.meta.require_list().ok())
+                    .and_then(|attr| MsgFieldAttr::parse.parse2(attr.tokens.clone()).ok());
+                let Some(name) = field_attr.and_then(|attr| attr.name) else {
+                    return syn::FnArg::Typed(ty);
+                };
+                let pat = Pat::Ident(PatIdent {
+                    attrs: vec![],
+                    by_ref: None,
+                    mutability: None,
+                    ident: name,
+                    subpat: None,
+                });
+                syn::FnArg::Typed(PatType {
+                    pat: Box::new(pat),
+                    ..ty
+                })
+            }
+            _ => input,
+        })
+        .collect()
+}
+
 fn remove_input_attr(inputs: Punctuated<FnArg, Token![,]>) -> Punctuated<FnArg, Token![,]> {
     inputs
         .into_iter()
@@ -40,7 +77,11 @@ impl Fold for StripInput {
             .filter(|attr| SylviaAttribute::new(attr).is_none())
             .collect();
 
-        let inputs = remove_input_attr(i.sig.inputs);
+        let inputs = match i.default {
+            Some(_) => i.sig.inputs,
+            None => name_pattern_inputs(i.sig.inputs),
+        };
+        let inputs = remove_input_attr(inputs);
         let sig = Signature { inputs, ..i.sig };
         fold::fold_trait_item_fn(self, TraitItemFn { attrs, sig, ..i })
     }
@@ -74,7 +115,19 @@ impl Fold for StripInput {
             .filter(|attr| SylviaAttribute::new(attr).is_none())
             .collect();
 
-        fold::fold_item_impl(self, ItemImpl { attrs, ..i })
+        // Invariants are called only by the generated multitest helpers.
+        let items = i
+            .items
+            .into_iter()
+            .filter(|item| match item {
+                ImplItem::Fn(method) if !cfg!(feature = "mt") => !method.attrs.iter().any(|attr| {
+                    matches!(SylviaAttribute::new(attr), Some(SylviaAttribute::Invariant))
+                }),
+                _ => true,
+            })
+            .collect();
+
+        fold::fold_item_impl(self, ItemImpl { attrs, items, ..i })
     }
 }
 
@@ -32,6 +32,7 @@ pub struct InterfaceInput<'a> {
     item: &'a ItemTrait,
     custom: Custom,
     associated_types: AssociatedTypes<'a>,
+    typed_query_errors: bool,
 }
 
 impl<'a> InterfaceInput<'a> {
@@ -55,9 +56,9 @@ impl<'a> InterfaceInput<'a> {
             );
         }
 
-        let custom = ParsedSylviaAttributes::new(item.attrs.iter())
-            .custom_attr
-            .unwrap_or_default();
+        let parsed_attrs = ParsedSylviaAttributes::new(item.attrs.iter());
+        let custom = parsed_attrs.custom_attr.unwrap_or_default();
+        let typed_query_errors = parsed_attrs.typed_query_errors;
         let associated_types = AssociatedTypes::new(item);
 
         if custom.msg.is_none()
@@ -88,6 +89,7 @@ impl<'a> InterfaceInput<'a> {
             item,
             custom,
             associated_types,
+            typed_query_errors,
         }
     }
 
@@ -97,6 +99,7 @@ impl<'a> InterfaceInput<'a> {
             associated_types,
             item,
             custom,
+            typed_query_errors,
         } = self;
         let messages = self.emit_messages();
         let associated_names: Vec<_> = associated_types
@@ -110,8 +113,18 @@ impl<'a> InterfaceInput<'a> {
             MsgVariants::new(item.as_variants(), MsgType::Query, &associated_names, &None);
         let executor =
             Executor::new(&executor_variants, associated_types, &item.ident).emit_executor_trait();
-        let querier =
-            Querier::new(&query_variants, associated_types, &item.ident).emit_querier_trait();
+        let querier = Querier::new(
+            &query_variants,
+            associated_types,
+            &item.ident,
+            *typed_query_errors,
+        )
+        .emit_querier_trait();
+
+        let query_handlers = match associated_types.without_error().next() {
+            Some(_) => quote! {},
+            None => query_variants.emit_query_handlers(),
+        };
 
         let interface_messages = Api::new(item, custom, associated_types).emit();
 
@@ -126,6 +139,8 @@ impl<'a> InterfaceInput<'a> {
 
                 #executor
 
+                #query_handlers
+
                 #interface_messages
 
                 #multitest_helpers
@@ -207,6 +222,6 @@ impl<'a> InterfaceInput<'a> {
         let Self { item, .. } = self;
         let associated_types = &self.associated_types;
 
-        MtHelpers::new(item, associated_types).emit()
+        MtHelpers::new(item, associated_types, &self.custom).emit()
     }
 }
@@ -99,6 +99,14 @@ impl<'a> Api<'a> {
                 type Querier<'querier> = #sylvia ::types::BoundQuerier<'querier, #custom_query, Self >;
             }
 
+            /// Identifies the interface in `sylvia::types::InterfaceNamespace` implemented by
+            /// the contracts exposing it.
+            pub struct InterfaceMarker;
+
+            impl #sylvia ::types::InterfaceId for InterfaceMarker {
+                const ID: &'static str = concat!(module_path!(), "::", stringify!(#interface_name));
+            }
+
             pub struct Api < #(#generics,)* > {
                 #phantom
             }
@@ -16,6 +16,7 @@ pub struct EnumMessage<'a> {
     resp_type: Type,
     query_type: Type,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    arbitrary: bool,
 }
 
 impl<'a> EnumMessage<'a> {
@@ -44,7 +45,9 @@ impl<'a> EnumMessage<'a> {
             .or(associated_query)
             .unwrap_or_else(Custom::default_type);
 
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let arbitrary = cfg!(feature = "arbitrary") && parsed_attrs.arbitrary.is_some();
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
@@ -58,6 +61,7 @@ impl<'a> EnumMessage<'a> {
             resp_type,
             query_type,
             msg_attrs_to_forward,
+            arbitrary,
         }
     }
 
@@ -70,6 +74,7 @@ impl<'a> EnumMessage<'a> {
             resp_type,
             query_type,
             msg_attrs_to_forward,
+            arbitrary,
         } = self;
 
         let trait_name = &source.ident;
@@ -78,10 +83,14 @@ impl<'a> EnumMessage<'a> {
             Ident::new(&format!("{}{}", trait_name, enum_name), enum_name.span());
 
         let match_arms = variants.emit_dispatch_legs();
-        let mut msgs = variants.as_names_snake_cased();
-        msgs.sort();
+        let msgs = variants.as_msg_names();
         let msgs_cnt = msgs.len();
         let variants_constructors = variants.emit_constructors();
+        let msg_name_method = variants.emit_msg_name_method();
+        let arbitrary_impl = match arbitrary {
+            true => variants.emit_arbitrary_impl(&unique_enum_name),
+            false => quote! {},
+        };
         let msg_variants = variants.emit();
 
         let ctx_type = msg_ty.emit_ctx_type(query_type);
@@ -130,8 +139,12 @@ impl<'a> EnumMessage<'a> {
                     }
                 }
                 #(#variants_constructors)*
+
+                #msg_name_method
             }
 
+            #arbitrary_impl
+
             pub const fn #messages_fn_name () -> [&'static str; #msgs_cnt] {
                 [#(#msgs,)*]
             }
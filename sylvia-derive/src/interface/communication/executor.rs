@@ -1,4 +1,3 @@
-use convert_case::Case;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::Ident;
@@ -9,7 +8,6 @@ use crate::parser::check_generics::GetPath;
 use crate::types::associated_types::{AssociatedTypes, ItemType};
 use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::SvCasing;
 
 pub struct Executor<'a, Generic> {
     variants: &'a MsgVariants<'a, Generic>,
@@ -52,9 +50,14 @@ where
             < dyn #interface_name < Error = (), #(#generics = Self:: #generics,)* > as InterfaceMessagesApi >:: #accessor
         };
 
+        let namespace = quote! { <Contract as #sylvia ::types::InterfaceNamespace<InterfaceMarker>>::NAMESPACE };
         let methods_trait_impl = variants
             .variants()
-            .map(|variant| variant.emit_executor_impl(&executor_api_path))
+            .map(|variant| variant.emit_executor_impl(&executor_api_path, &namespace))
+            .collect::<Vec<_>>();
+        let dyn_methods_trait_impl = variants
+            .variants()
+            .map(|variant| variant.emit_executor_impl(&executor_api_path, &quote! { None }))
             .collect::<Vec<_>>();
 
         let executor_methods_declaration = variants
@@ -73,10 +76,10 @@ where
             impl <#(#all_generics,)*> Executor
                 for #sylvia ::types::ExecutorBuilder<(#sylvia ::types::EmptyExecutorBuilderState, dyn #interface_name <#( #all_generics = #all_generics,)* > ) > #where_clause {
                 #(type #generics = #generics;)*
-                #(#methods_trait_impl)*
+                #(#dyn_methods_trait_impl)*
             }
 
-            impl <Contract: #interface_name> Executor
+            impl <Contract: #interface_name + #sylvia ::types::InterfaceNamespace<InterfaceMarker>> Executor
                 for #sylvia ::types::ExecutorBuilder<( #sylvia ::types::EmptyExecutorBuilderState, Contract )> {
                 #(type #generics = <Contract as #interface_name > :: #generics;)*
                 #(#methods_trait_impl)*
@@ -86,40 +89,38 @@ where
 }
 
 trait EmitExecutorMethod {
-    fn emit_executor_impl(&self, api_path: &TokenStream) -> TokenStream;
+    fn emit_executor_impl(&self, api_path: &TokenStream, namespace: &TokenStream) -> TokenStream;
     fn emit_executor_method_declaration(&self) -> TokenStream;
 }
 
 impl EmitExecutorMethod for MsgVariant<'_> {
-    fn emit_executor_impl(&self, api_path: &TokenStream) -> TokenStream {
-        let name = self.name();
+    fn emit_executor_impl(&self, api_path: &TokenStream, namespace: &TokenStream) -> TokenStream {
         let fields = self.fields();
         let sylvia = crate_module();
 
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
         let fields_names = fields.iter().map(MsgField::name);
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
 
         quote! {
             fn #variant_name(self, #(#parameters),*) -> Result<#sylvia ::types::ExecutorBuilder< #sylvia ::types::ReadyExecutorBuilderState >, #sylvia ::cw_std::StdError> {
                 Ok(#sylvia ::types::ExecutorBuilder::<#sylvia ::types::ReadyExecutorBuilderState>::new(
                     self.contract().to_owned(),
                     self.funds().to_owned(),
-                    #sylvia ::cw_std::to_json_binary( & #api_path :: #variant_name (#(#fields_names),*) )?,
+                    #sylvia ::cw_std::to_json_binary( &#sylvia ::types::NamespacedMsg::new(#namespace, & #api_path :: #variant_name (#(#fields_names),*)) )?,
                 ))
             }
         }
     }
 
     fn emit_executor_method_declaration(&self) -> TokenStream {
-        let name = self.name();
         let sylvia = crate_module();
 
         let parameters = self
             .fields()
             .iter()
             .map(|field| field.emit_method_field_folded());
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
 
         quote! {
             fn #variant_name(self, #(#parameters),*) -> Result< #sylvia ::types::ExecutorBuilder<#sylvia ::types::ReadyExecutorBuilderState>, #sylvia ::cw_std::StdError>;
@@ -1,4 +1,3 @@
-use convert_case::Case;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::Ident;
@@ -9,7 +8,7 @@ use crate::parser::check_generics::GetPath;
 use crate::types::associated_types::{AssociatedTypes, ItemType};
 use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::SvCasing;
+use crate::utils::emit_query_error_type;
 
 /// Emits [query helper](https://cosmwasm-docs.vercel.app/sylvia/macros/generated-types/communication#query-helpers).
 ///
@@ -19,6 +18,7 @@ pub struct Querier<'a, Generic> {
     variants: &'a MsgVariants<'a, Generic>,
     associated_types: &'a AssociatedTypes<'a>,
     interface_name: &'a Ident,
+    typed_errors: bool,
 }
 
 impl<'a, Generic> Querier<'a, Generic>
@@ -29,11 +29,13 @@ where
         variants: &'a MsgVariants<'a, Generic>,
         associated_types: &'a AssociatedTypes,
         interface_name: &'a Ident,
+        typed_errors: bool,
     ) -> Self {
         Self {
             variants,
             associated_types,
             interface_name,
+            typed_errors,
         }
     }
 
@@ -43,6 +45,7 @@ where
             variants,
             associated_types,
             interface_name,
+            typed_errors,
         } = self;
 
         let generics: Vec<_> = associated_types
@@ -55,14 +58,19 @@ where
             < dyn #interface_name < Error = (), #(#generics = Self:: #generics,)* > as InterfaceMessagesApi > :: #accessor
         };
 
+        let namespace = quote! { <Contract as #sylvia ::types::InterfaceNamespace<InterfaceMarker>>::NAMESPACE };
         let methods_trait_impl = variants
             .variants()
-            .map(|variant| variant.emit_querier_impl(&api_path))
+            .map(|variant| variant.emit_querier_impl(&api_path, &namespace, *typed_errors))
+            .collect::<Vec<_>>();
+        let dyn_methods_trait_impl = variants
+            .variants()
+            .map(|variant| variant.emit_querier_impl(&api_path, &quote! { None }, *typed_errors))
             .collect::<Vec<_>>();
 
         let querier_methods_declaration = variants
             .variants()
-            .map(|variant| variant.emit_querier_method_declaration());
+            .map(|variant| variant.emit_querier_method_declaration(*typed_errors));
 
         let types_declaration = associated_types.without_error().collect::<Vec<_>>();
         let where_clause = associated_types.as_where_clause();
@@ -75,10 +83,10 @@ where
 
             impl <'a, C: #sylvia ::cw_std::CustomQuery, #(#all_generics,)*> Querier for #sylvia ::types::BoundQuerier<'a, C, dyn #interface_name <#( #all_generics = #all_generics,)*> > #where_clause {
                 #(type #generics = #generics;)*
-                #(#methods_trait_impl)*
+                #(#dyn_methods_trait_impl)*
             }
 
-            impl <'a, C: #sylvia ::cw_std::CustomQuery, Contract: #interface_name> Querier for #sylvia ::types::BoundQuerier<'a, C, Contract> {
+            impl <'a, C: #sylvia ::cw_std::CustomQuery, Contract: #interface_name + #sylvia ::types::InterfaceNamespace<InterfaceMarker>> Querier for #sylvia ::types::BoundQuerier<'a, C, Contract> {
                 #(type #generics = <Contract as #interface_name > :: #generics;)*
                 #(#methods_trait_impl)*
             }
@@ -87,42 +95,57 @@ where
 }
 
 trait EmitQuerierMethod {
-    fn emit_querier_impl(&self, api_path: &TokenStream) -> TokenStream;
-    fn emit_querier_method_declaration(&self) -> TokenStream;
+    fn emit_querier_impl(
+        &self,
+        api_path: &TokenStream,
+        namespace: &TokenStream,
+        typed_errors: bool,
+    ) -> TokenStream;
+    fn emit_querier_method_declaration(&self, typed_errors: bool) -> TokenStream;
 }
 
 impl EmitQuerierMethod for MsgVariant<'_> {
-    fn emit_querier_impl(&self, api_path: &TokenStream) -> TokenStream {
+    fn emit_querier_impl(
+        &self,
+        api_path: &TokenStream,
+        namespace: &TokenStream,
+        typed_errors: bool,
+    ) -> TokenStream {
         let sylvia = crate_module();
-        let name = self.name();
         let fields = self.fields();
         let return_type = self.return_type();
 
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
         let fields_names = fields.iter().map(MsgField::name);
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
+
+        let error_type = emit_query_error_type(typed_errors);
+        let msg = quote! { &#sylvia ::types::NamespacedMsg::new(#namespace, &query) };
+        let query_call = match typed_errors {
+            true => quote! { self.query_typed(#msg) },
+            false => quote! { self.querier().query_wasm_smart(self.contract(), #msg) },
+        };
 
         quote! {
-            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError> {
+            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #error_type> {
                 let query = #api_path :: #variant_name (#(#fields_names),*);
-                self.querier().query_wasm_smart(self.contract(), &query)
+                #query_call
             }
         }
     }
 
-    fn emit_querier_method_declaration(&self) -> TokenStream {
-        let sylvia = crate_module();
-        let name = self.name();
+    fn emit_querier_method_declaration(&self, typed_errors: bool) -> TokenStream {
         let return_type = self.return_type();
+        let error_type = emit_query_error_type(typed_errors);
 
         let parameters = self
             .fields()
             .iter()
             .map(|field| field.emit_method_field_folded());
-        let variant_name = name.to_case(Case::Snake);
+        let variant_name = self.method_name();
 
         quote! {
-            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError>;
+            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #error_type>;
         }
     }
 }
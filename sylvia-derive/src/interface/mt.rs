@@ -1,20 +1,20 @@
-use convert_case::Case;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_quote, GenericParam, ItemTrait, TraitItem, Type};
 
 use crate::crate_module;
 use crate::parser::attributes::msg::MsgType;
 use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::Custom;
 use crate::types::associated_types::AssociatedTypes;
-use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::SvCasing;
+use crate::types::msg_variant::{MsgVariant, MsgVariants, QueryHandlersReceiver};
 
 /// Emits helpers for testing interface messages using MultiTest.
 pub struct MtHelpers<'a> {
     source: &'a ItemTrait,
     error_type: Type,
     associated_types: &'a AssociatedTypes<'a>,
+    custom: &'a Custom,
     exec_variants: MsgVariants<'a, GenericParam>,
     query_variants: MsgVariants<'a, GenericParam>,
     sudo_variants: MsgVariants<'a, GenericParam>,
@@ -22,7 +22,11 @@ pub struct MtHelpers<'a> {
 }
 
 impl<'a> MtHelpers<'a> {
-    pub fn new(source: &'a ItemTrait, associated_types: &'a AssociatedTypes) -> Self {
+    pub fn new(
+        source: &'a ItemTrait,
+        associated_types: &'a AssociatedTypes,
+        custom: &'a Custom,
+    ) -> Self {
         let where_clause = &source.generics.where_clause;
         let exec_variants =
             MsgVariants::new(source.as_variants(), MsgType::Exec, &[], where_clause);
@@ -44,6 +48,7 @@ impl<'a> MtHelpers<'a> {
             error_type,
             source,
             associated_types,
+            custom,
             where_clause,
             exec_variants,
             query_variants,
@@ -60,6 +65,7 @@ impl<'a> MtHelpers<'a> {
             exec_variants,
             query_variants,
             sudo_variants,
+            ..
         } = self;
 
         let sylvia = crate_module();
@@ -81,6 +87,7 @@ impl<'a> MtHelpers<'a> {
                 DistrT,
                 IbcT,
                 GovT,
+                StargateT,
             >
         };
 
@@ -119,10 +126,14 @@ impl<'a> MtHelpers<'a> {
             .as_ref()
             .map(|where_clause| &where_clause.predicates);
 
+        let mock = self.emit_mock();
+
         quote! {
             pub mod mt {
                 use super::*;
 
+                #mock
+
                 pub trait #trait_name <MtApp, #custom_msg > #where_clause {
                     type #error_type: std::fmt::Debug + std::fmt::Display + Send + Sync + 'static;
                     #(#associated_types_declaration)*
@@ -132,9 +143,10 @@ impl<'a> MtHelpers<'a> {
                     #(#sudo_methods_declarations)*
                 }
 
-                impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, #custom_msg, ContractT: super:: #interface_name > #trait_name < #mt_app, #custom_msg > for #sylvia ::multitest::Proxy<'_, #mt_app, ContractT >
+                impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT, #custom_msg, ContractT: super:: #interface_name > #trait_name < #mt_app, #custom_msg > for #sylvia ::multitest::Proxy<'_, #mt_app, ContractT >
                 where
                     ContractT:: #error_type : std::fmt::Debug + std::fmt::Display + Send + Sync + 'static,
+                    ContractT: #sylvia ::types::InterfaceNamespace<InterfaceMarker>,
                     #custom_msg: #sylvia ::types::CustomMsg + 'static,
                     CustomT: #sylvia ::cw_multi_test::Module,
                     WasmT: #sylvia ::cw_multi_test::Wasm<CustomT::ExecT, CustomT::QueryT>,
@@ -146,6 +158,7 @@ impl<'a> MtHelpers<'a> {
                     DistrT: #sylvia ::cw_multi_test::Distribution,
                     IbcT: #sylvia ::cw_multi_test::Ibc,
                     GovT: #sylvia ::cw_multi_test::Gov,
+                    StargateT: #sylvia ::cw_multi_test::Stargate,
                     CustomT::ExecT: #sylvia ::types::CustomMsg + 'static,
                     CustomT::QueryT: #sylvia:: types::CustomQuery + 'static,
                     #mt_app : #sylvia ::cw_multi_test::Executor< #custom_msg >,
@@ -161,6 +174,126 @@ impl<'a> MtHelpers<'a> {
             }
         }
     }
+
+    /// Emits the mock contract answering the interface messages with the registered handlers.
+    ///
+    /// The mock is not emitted for interfaces with associated types other than `Error`, as the
+    /// types of its messages are not known. The mock answers the plain messages only, so it
+    /// can't stand in for the interface registered with a `namespace`.
+    fn emit_mock(&self) -> TokenStream {
+        let Self {
+            source,
+            associated_types,
+            custom,
+            exec_variants,
+            query_variants,
+            sudo_variants,
+            ..
+        } = self;
+
+        if associated_types.without_error().next().is_some() {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let interface_name = &source.ident;
+        let mock_name = format_ident!("{}Mock", interface_name);
+        let custom_msg = custom.msg_or_default();
+
+        let query_methods = query_variants.emit_query_handler_methods(QueryHandlersReceiver::Mock);
+
+        let exec_methods = exec_variants.variants().map(|variant| {
+            let variant_name = variant.name();
+            let msg_name = variant.msg_name();
+            let on_name = format_ident!("on_{}", variant.method_name());
+            let on_doc = format!(" Handles the `{msg_name}` messages with the given handler.");
+            let fields_names = variant.as_fields_names();
+            let fields_types = variant.fields().iter().map(|field| field.ty());
+
+            quote! {
+                #[doc = #on_doc]
+                pub fn #on_name(
+                    &self,
+                    handler: impl Fn( &#sylvia ::cw_std::MessageInfo, #(#fields_types),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::Response< #custom_msg >> + Send + Sync + 'static,
+                ) -> &Self {
+                    self.0.on_exec(#msg_name, move |msg, info| match msg.clone() {
+                        ExecMsg:: #variant_name { #(#fields_names),* } => handler(info, #(#fields_names),* ),
+                        #[allow(unreachable_patterns)]
+                        _ => unreachable!(),
+                    });
+                    self
+                }
+            }
+        });
+
+        let sudo_methods = sudo_variants.variants().map(|variant| {
+            let variant_name = variant.name();
+            let msg_name = variant.msg_name();
+            let on_name = format_ident!("on_{}", variant.method_name());
+            let on_doc = format!(" Handles the `{msg_name}` sudo messages with the given handler.");
+            let fields_names = variant.as_fields_names();
+            let fields_types = variant.fields().iter().map(|field| field.ty());
+
+            quote! {
+                #[doc = #on_doc]
+                pub fn #on_name(
+                    &self,
+                    handler: impl Fn( #(#fields_types),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::Response< #custom_msg >> + Send + Sync + 'static,
+                ) -> &Self {
+                    self.0.on_sudo(#msg_name, move |msg| match msg.clone() {
+                        SudoMsg:: #variant_name { #(#fields_names),* } => handler( #(#fields_names),* ),
+                        #[allow(unreachable_patterns)]
+                        _ => unreachable!(),
+                    });
+                    self
+                }
+            }
+        });
+
+        quote! {
+            #[derive(Clone)]
+            pub struct #mock_name (#sylvia ::multitest::MockContract<ExecMsg, QueryMsg, SudoMsg, #custom_msg >);
+
+            impl #mock_name {
+                pub fn new() -> Self {
+                    Self(#sylvia ::multitest::MockContract::new(
+                        ExecMsg::sv_msg_name,
+                        QueryMsg::sv_msg_name,
+                        SudoMsg::sv_msg_name,
+                    ))
+                }
+
+                #(#query_methods)*
+                #(#exec_methods)*
+                #(#sudo_methods)*
+
+                /// Returns the execute messages received by the mock, including the failed ones.
+                pub fn executed(&self) -> Vec< #sylvia ::multitest::ReceivedMsg<ExecMsg>> {
+                    self.0.executed()
+                }
+
+                /// Returns the sudo messages received by the mock, including the failed ones.
+                pub fn sudo_received(&self) -> Vec< #sylvia ::multitest::ReceivedSudoMsg<SudoMsg>> {
+                    self.0.sudo_received()
+                }
+
+                /// Stores the mock in the `App`.
+                pub fn store_code<'app, MtApp, Q>(&self, app: &'app #sylvia ::multitest::App<MtApp>) -> #sylvia ::multitest::MockCodeId<'app, MtApp, #custom_msg >
+                where
+                    MtApp: #sylvia ::multitest::StoreCode< #custom_msg, Q>,
+                    Q: #sylvia ::types::CustomQuery + 'static,
+                {
+                    self.0.store_code(app)
+                }
+            }
+
+            impl Default for #mock_name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    }
 }
 
 trait EmitMethods {
@@ -190,7 +323,6 @@ impl EmitMethods for MsgVariant<'_> {
     ) -> TokenStream {
         let sylvia = crate_module();
 
-        let name = self.name();
         let return_type = self.return_type();
 
         let params: Vec<_> = self
@@ -200,7 +332,8 @@ impl EmitMethods for MsgVariant<'_> {
             .collect();
         let arguments = self.as_fields_names();
         let type_name = self.msg_type().as_accessor_name();
-        let name = name.to_case(Case::Snake);
+        let name = self.method_name();
+        let namespace = quote! { <ContractT as #sylvia ::types::InterfaceNamespace<InterfaceMarker>>::NAMESPACE };
 
         match self.msg_type() {
             MsgType::Exec => quote! {
@@ -209,6 +342,7 @@ impl EmitMethods for MsgVariant<'_> {
                     let msg = #api :: #type_name :: #name ( #(#arguments),* );
 
                     #sylvia ::multitest::ExecProxy::new(&self.contract_addr, msg, &self.app)
+                        .with_namespace(#namespace)
                 }
             },
             MsgType::Query => {
@@ -218,19 +352,22 @@ impl EmitMethods for MsgVariant<'_> {
 
                         (*self.app)
                             .querier()
-                            .query_wasm_smart(self.contract_addr.clone(), &msg)
+                            .query_wasm_smart(self.contract_addr.clone(), &#sylvia ::types::NamespacedMsg::new(#namespace, &msg))
                             .map_err(Into::into)
                     }
                 }
             }
             MsgType::Sudo => quote! {
+                #[track_caller]
                 fn #name (&self, #(#params,)* ) -> Result< #sylvia ::cw_multi_test::AppResponse, #error_type> {
                     let msg = #api :: #type_name :: #name ( #(#arguments),* );
 
-                    (*self.app)
+                    let resp = (*self.app)
                         .app_mut()
-                        .wasm_sudo(self.contract_addr.clone(), &msg)
-                        .map_err(|err| err.downcast().unwrap())
+                        .wasm_sudo(self.contract_addr.clone(), &#sylvia ::types::NamespacedMsg::new(#namespace, &msg))
+                        .map_err(|err| #sylvia ::multitest::MtError::< #error_type >::from_anyhow(err, Some(&self.contract_addr)).into_contract_error())?;
+                    self.app.check_invariants(&self.contract_addr, &msg);
+                    Ok(resp)
                 }
             },
             MsgType::Migrate => quote! {
@@ -253,7 +390,6 @@ impl EmitMethods for MsgVariant<'_> {
     ) -> TokenStream {
         let sylvia = crate_module();
 
-        let name = self.name();
         let return_type = self.return_type();
 
         let params: Vec<_> = self
@@ -262,7 +398,7 @@ impl EmitMethods for MsgVariant<'_> {
             .map(|field| field.emit_method_field_folded())
             .collect();
         let type_name = self.msg_type().as_accessor_name();
-        let name = name.to_case(Case::Snake);
+        let name = self.method_name();
 
         match self.msg_type() {
             MsgType::Exec => quote! {
@@ -5,20 +5,24 @@
 use crate::parser::EntryPointArgs;
 use contract::ContractInput;
 use entry_points::EntryPointInput;
+use event::EventInput;
 use fold::StripInput;
 use interface::InterfaceInput;
+use module::ModuleInput;
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro_error::proc_macro_error;
 use quote::quote;
 use syn::fold::Fold;
 use syn::spanned::Spanned;
-use syn::{parse2, parse_quote, ItemImpl, ItemTrait, Path};
+use syn::{parse2, parse_quote, ItemImpl, ItemStruct, ItemTrait, Path};
 
 mod contract;
 mod entry_points;
+mod event;
 mod fold;
 mod interface;
+mod module;
 mod parser;
 mod types;
 mod utils;
@@ -187,6 +191,35 @@ pub(crate) fn crate_module() -> Path {
 /// # fn main() {}
 /// ```
 ///
+/// Arguments of the method can be patterns. Such an argument has to name its message field
+/// with `#[sv::msg(name = field_name)]`. The method body receives the destructured bindings,
+/// while the message, the querier, the executor and the multitest proxy use the field name.
+///
+/// ```rust
+/// # use sylvia::cw_schema::cw_serde;
+/// # use sylvia::cw_std::{Addr, Response, StdError};
+/// # use sylvia::types::ExecCtx;
+/// #
+/// ##[cw_serde]
+/// pub struct Metadata {
+///     pub owner: Addr,
+///     pub memo: String,
+/// }
+///
+/// ##[sylvia::interface]
+/// pub trait SvInterface {
+///    type Error: From<StdError>;
+///
+///    #[sv::msg(exec)]
+///    fn transfer(
+///        &self,
+///        ctx: ExecCtx,
+///        #[sv::msg(name = metadata)] Metadata { owner, memo }: Metadata,
+///    ) -> Result<Response, Self::Error>;
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ### `sv::custom(msg=..., query=...)`
 ///
 /// Allows restricting interface to use specific
@@ -220,6 +253,19 @@ pub(crate) fn crate_module() -> Path {
 /// # fn main() {}
 /// ```
 ///
+/// ### `sv::typed_query_errors`
+///
+/// Changes the error type of the generated `Querier` methods from `StdError` to
+/// `sylvia::types::QueryError`, which keeps a remote contract error, a querier system error
+/// and a failed deserialization of the response apart. The remote error can be decoded into
+/// its original type with `QueryError::decode_contract_error`.
+///
+/// ### `sv::guard(path_to_predicate)`
+///
+/// Calls the predicate before the handler of the `exec` or `sudo` message, the only guarded
+/// message types of an interface. `Self::method` calls the method of the contract implementing
+/// the interface, so the predicate can be declared as a method of the interface trait.
+///
 /// ### `sv::msg_attr(msg_type, {...})`
 ///
 /// This attribute can be used for the whole `trait Interface {}` block and
@@ -429,6 +475,9 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// * `migrate` - migrate message variant
 /// * `reply` - reply message variant
 /// * `sudo` - sudo message variant
+/// * `ibc_channel_open`, `ibc_channel_connect`, `ibc_channel_close`, `ibc_packet_receive`,
+///   `ibc_packet_ack`, `ibc_packet_timeout` - IBC entry point handlers. Require the `stargate`
+///   feature and at most one handler of every kind
 ///
 /// In the case of a query, it is possible to pass a second argument which is its `ResponseType`.
 /// This is required in case of aliased results wrapping their `ResponseType` to properly
@@ -478,6 +527,161 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// Pattern arguments are supported if the message field is named with
+/// `#[sv::msg(name = field_name)]`, e.g.
+/// `#[sv::msg(name = metadata)] Metadata { owner, memo }: Metadata`.
+///
+/// The `exec`, `query` and `sudo` messages can be renamed with the `name = "..."` argument and
+/// accept the alternative names listed with `alias = [...]`. Arguments are renamed with
+/// `#[sv::msg(name = "...", alias = [...])]`. Names of the `query` messages have to be in
+/// `snake_case`.
+///
+/// ```rust
+/// # use sylvia::types::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Response, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// impl SvContract {
+/// #     pub const fn new() -> Self {
+/// #         Self
+/// #     }
+/// #
+/// #     #[sv::msg(instantiate)]
+/// #     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #         Ok(Response::new())
+/// #     }
+/// #
+///     #[sv::msg(exec, name = "transfer_from", alias = ["send_from"])]
+///     fn transfer_legacy(
+///         &self,
+///         ctx: ExecCtx,
+///         #[sv::msg(name = "owner")] from: String,
+///     ) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// In the case of a reply, the handler can be bound to reply ids with the `handlers` argument
+/// and to the result of the submessage with the `reply_on` argument - `success`, `error` or
+/// `always` (default). If `handlers` is not provided, the method name is used as the reply id.
+/// For every reply id a `<REPLY_ID>_REPLY_ID` constant is generated in the `sv` module along with
+/// the `dispatch_reply` function routing the `Reply` to the handlers. The value of the constant is
+/// the 64-bit FNV-1a hash of the reply id name, so it doesn't depend on the order of the handlers.
+/// It can be set explicitly with the `id = ...` argument of the handler bound to a single reply id.
+/// Reply ids with the same value are reported as an error.
+/// A single `#[sv::msg(reply)]` handler without any arguments is a catch-all handler
+/// receiving the raw `Reply` for the unknown reply ids.
+///
+/// Arguments of the handler are decoded from the `Reply`:
+/// * `#[sv::data]` - `data` of the successful submessage response deserialized into the argument type.
+///   Use `#[sv::data(opt)]` for an optional data, `#[sv::data(raw)]` to receive the `Binary` and
+///   `#[sv::data(instantiate)]` to parse the `MsgInstantiateContractResponse`.
+/// * `#[sv::payload]` - `payload` of the submessage deserialized into the argument type.
+///   An empty payload fails to decode with the error naming the reply id.
+///   Use `#[sv::payload(raw)]` to receive the `Binary`.
+/// * not attributed argument - the error message in case of `error` handler or the `SubMsgResult`
+///   in case of `always` handler.
+///
+/// ```rust
+/// # use sylvia::types::{InstantiateCtx, ReplyCtx};
+/// # use sylvia::cw_std::{Binary, Response, StdResult, SubMsgResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(reply, handlers=[remote_exec], reply_on=success)]
+///     fn remote_exec_success(
+///         &self,
+///         ctx: ReplyCtx,
+///         #[sv::data(opt)] data: Option<String>,
+///         #[sv::payload] payload: u64,
+///     ) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(reply, handlers=[remote_exec], reply_on=error)]
+///     fn remote_exec_failure(&self, ctx: ReplyCtx, error: String) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(reply, reply_on=always, id = 1)]
+///     fn remote_cleanup(
+///         &self,
+///         ctx: ReplyCtx,
+///         result: SubMsgResult,
+///         #[sv::payload(raw)] payload: Binary,
+///     ) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {
+/// #     let _ = (sv::REMOTE_EXEC_REPLY_ID, sv::REMOTE_CLEANUP_REPLY_ID);
+/// # }
+/// ```
+///
+/// In the case of `exec` and `instantiate`, the funds policy of the handler can be declared.
+/// It is verified in the `dispatch` of the message before the method is called:
+/// * `payable` - any funds can be sent along with the message
+/// * `nonpayable` - no funds can be sent along with the message
+/// * `must_pay = "denom"` - exactly one coin of the given denom has to be sent along with the message.
+///   The paid amount is passed to the argument marked with `#[sv::funds]`.
+///
+/// The policy is validated with `cw_utils` and requires the `cosmwasm_1_2` feature. The error type
+/// of the contract has to implement `From<cw_utils::PaymentError>`.
+///
+/// ```rust
+/// # use sylvia::types::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Response, StdError, Uint128};
+/// # use sylvia::cw_utils::PaymentError;
+/// #
+/// # #[derive(Debug, PartialEq, thiserror::Error)]
+/// # pub enum ContractError {
+/// #     #[error("{0}")]
+/// #     Std(#[from] StdError),
+/// #     #[error("{0}")]
+/// #     Payment(#[from] PaymentError),
+/// # }
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// ##[sv::error(ContractError)]
+/// impl SvContract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+///     #[sv::msg(instantiate, nonpayable)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> Result<Response, ContractError> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec, must_pay = "ucosm")]
+///     fn deposit(
+///         &self,
+///         ctx: ExecCtx,
+///         #[sv::funds] amount: Uint128,
+///     ) -> Result<Response, ContractError> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ### `sv::custom(msg=..., query=...)`
 ///
 /// Allows restricting interface to use specific
@@ -488,6 +692,67 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 ///
 /// Allows specifing custom error type for the contract. Default is `cosmwasm_std::StdError`.
 ///
+/// ### `sv::version(name = ..., version = ...)`
+///
+/// Stores the `cw2` contract info in the generated `instantiate` dispatch. The generated
+/// `migrate` dispatch refuses migrations from a different contract or a newer version before
+/// calling the method, and updates the stored version after the method succeeds.
+/// The contract info can be queried with the generated `contract_info` query of the
+/// `ContractQueryMsg`.
+///
+/// ```rust
+/// # use sylvia::types::InstantiateCtx;
+/// # use sylvia::cw_std::{Response, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// ##[sv::version(name = "crates.io:sv-contract", version = env!("CARGO_PKG_VERSION"))]
+/// impl SvContract {
+/// #     pub const fn new() -> Self {
+/// #         Self
+/// #     }
+/// #
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::actions`
+///
+/// Adds the `action=<msg_name>` attribute to the responses of the generated `instantiate`,
+/// `exec`, `sudo` and `migrate` dispatch, as expected by the indexers of cw-plus style contracts.
+/// Responses of the interface messages are additionally tagged with `interface=<name>`, where
+/// `name` is the one given in `sv::messages(interface as Name)`.
+///
+/// ### `sv::typed_query_errors`
+///
+/// Works as [`sv::typed_query_errors`](macro@interface#svtyped_query_errors) of the interface
+/// for the `Querier` of the contract.
+///
+/// ### `sv::invariant`
+///
+/// Marks the method taking the `QueryCtx` and returning `Result<(), E>` as an invariant of
+/// the contract. The generated multitest helpers call the invariants after every successful
+/// instantiation, execution, migration and sudo sent to the contract through the proxies,
+/// and panic with the name of the violated invariant and the message which broke it.
+/// The invariants are compiled only with the `mt` feature.
+///
+/// ```rust,ignore
+/// #[sv::invariant]
+/// fn supply_matches_balances(&self, ctx: QueryCtx) -> StdResult<()> {
+///     let supply = self.total_supply.load(ctx.deps.storage)?;
+///     let balances = self.balances.range(ctx.deps.storage, None, None, Order::Ascending)
+///         .map(|balance| balance.map(|(_, amount)| amount))
+///         .sum::<StdResult<Uint128>>()?;
+///     ensure!(supply == balances, StdError::generic_err("Supply doesn't match the balances"));
+///     Ok(())
+/// }
+/// ```
+///
 /// ### `sv::override_entry_point(entry_point_type=<path_to_entry_point(msg_path)>`
 ///
 /// Allows overriding default entry point for specific message type.
@@ -607,6 +872,53 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// The guard called before every `exec` message of the interface can be declared with
+/// `#[sv::messages(interface: guard(path_to_predicate))]`. See `sv::guard` for the predicate
+/// signature. The predicate takes the `ExecCtx`, so an interface with `sudo` messages can't be
+/// guarded as a whole and the contract fails to compile. Guard its methods instead.
+///
+/// Messages of the contract and its interfaces can't overlap. Messages of an interface can be
+/// nested under a namespace with `#[sv::messages(interface as Interface, namespace = "name")]`.
+/// The contract then receives them as `{"name": {"message": {...}}}`.
+///
+/// ### `sv::guard(path_to_predicate)`
+///
+/// Calls the predicate before the handler of the `exec`, `sudo` or `migrate` message. The predicate
+/// takes the contract and the reference to the context of the message and returns
+/// `Result<(), E>`, where `E` is convertible into the contract error type. `Self::method` calls
+/// the method of the contract. Multiple guards are called in the order of declaration.
+///
+/// ```rust
+/// # use sylvia::types::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{ensure, Response, StdError, StdResult};
+/// #
+/// pub struct SvContract;
+///
+/// ##[sylvia::contract]
+/// impl SvContract {
+/// #     pub const fn new() -> Self {
+/// #         Self
+/// #     }
+/// #
+/// #     #[sv::msg(instantiate)]
+/// #     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #         Ok(Response::new())
+/// #     }
+/// #
+///     fn is_admin(&self, ctx: &ExecCtx) -> StdResult<()> {
+///         ensure!(ctx.info.sender.as_str() == "admin", StdError::generic_err("Unauthorized"));
+///         Ok(())
+///     }
+///
+///     #[sv::msg(exec)]
+///     #[sv::guard(Self::is_admin)]
+///     fn update(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ### `sv::msg_attr(msg_type, {...})`
 ///
 /// This attribute can be used for the whole `impl Contract {}` block and
@@ -639,7 +951,7 @@ fn contract_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
         let input = StripInput.fold_item_impl(input);
 
         Ok(quote! {
-            #[cfg_attr(feature = "cargo-clippy", allow(clippy::new_without_default))]
+            #[allow(clippy::new_without_default)]
             #input
 
             #expanded
@@ -764,13 +1076,120 @@ fn entry_points_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
     inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
 }
 
+/// Procedural macro generating the `cw_multi_test::Module` implementation of the custom module
+/// from its impl block, in the style of the contracts.
+///
+/// The methods marked with `#[sv::msg(exec)]`, `#[sv::msg(query)]` and `#[sv::msg(sudo)]` become
+/// the variants of the generated `ExecMsg`, `QueryMsg` and `SudoMsg`, which are the custom
+/// messages of the chain. The handlers take `sylvia::multitest::module::ModuleExecCtx`,
+/// `ModuleQueryCtx` and `ModuleSudoCtx` respectively, giving access to the storage, api, block
+/// and router the module is called with. The `exec` and `sudo` handlers return
+/// `Result<AppResponse, E>`, the `query` handlers return `Result<T, E>`, where `T` is
+/// the serialized response and `E` is convertible into `anyhow::Error`.
+///
+/// The `Querier` trait generated in the `sv` module is implemented on `sylvia::multitest::App`
+/// and queries the module with a method per `query` handler.
+///
+/// ## Example usage
+/// ```rust,ignore
+/// pub struct CounterModule {
+///     counter: Item<u64>,
+/// }
+///
+/// #[sylvia::module]
+/// impl CounterModule {
+///     #[sv::msg(exec)]
+///     fn increment(&self, ctx: ModuleExecCtx) -> StdResult<AppResponse> {
+///         self.counter.update(ctx.storage, |count| StdResult::Ok(count + 1))?;
+///         Ok(AppResponse::default())
+///     }
+///
+///     #[sv::msg(query)]
+///     fn count(&self, ctx: ModuleQueryCtx) -> StdResult<u64> {
+///         self.counter.load(ctx.storage)
+///     }
+/// }
+///
+/// let app = BasicAppBuilder::<sv::ExecMsg, sv::QueryMsg>::new_custom()
+///     .with_custom(CounterModule::default())
+///     .build(|_, _, _| {});
+///
+/// // Contracts send `CosmosMsg::Custom(sv::ExecMsg::increment())`.
+/// use sv::Querier;
+/// assert_eq!(app.count().unwrap(), 0);
+/// ```
+///
+/// Available only with the `mt` feature.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
+    module_impl(attr.into(), item.into()).into()
+}
+
+fn module_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    fn inner(item: TokenStream2) -> syn::Result<TokenStream2> {
+        let input: ItemImpl = parse2(item)?;
+        let expanded = ModuleInput::new(&input).process();
+        let input = StripInput.fold_item_impl(input);
+
+        Ok(quote! {
+            #input
+
+            #expanded
+        })
+    }
+
+    inner(item).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+/// Derive macro implementing `sylvia::types::TypedEvent` for a struct with named fields.
+///
+/// The type of the event is the snake cased name of the struct and every field is stored as
+/// the attribute of the same name. Both can be changed with the `event` helper attribute.
+///
+/// ## Example usage
+/// ```rust
+/// # use sylvia::cw_std::{Addr, Response, StdResult, Uint128};
+/// use sylvia::types::TypedEvent;
+///
+/// #[derive(TypedEvent)]
+/// #[event(name = "transfer")]
+/// pub struct TransferEvent {
+///     pub sender: Addr,
+///     #[event(name = "recipient")]
+///     pub receiver: Addr,
+///     pub amount: Uint128,
+/// }
+///
+/// fn transfer(sender: Addr, receiver: Addr, amount: Uint128) -> StdResult<Response> {
+///     let event = TransferEvent { sender, receiver, amount };
+///     Ok(Response::new().add_event(event.to_event()?))
+/// }
+/// ```
+///
+/// Optional fields set to `None` are not stored and are parsed back as `None` if missing.
+#[proc_macro_error]
+#[proc_macro_derive(TypedEvent, attributes(event))]
+pub fn typed_event(item: TokenStream) -> TokenStream {
+    typed_event_impl(item.into()).into()
+}
+
+fn typed_event_impl(item: TokenStream2) -> TokenStream2 {
+    fn inner(item: TokenStream2) -> syn::Result<TokenStream2> {
+        let input: ItemStruct = parse2(item)?;
+        Ok(EventInput::new(&input).process())
+    }
+
+    inner(item).unwrap_or_else(syn::Error::into_compile_error)
+}
+
 #[cfg(test)]
 mod test {
     use std::{env, fs};
 
     use sylvia_runtime_macros::emulate_attribute_expansion_fallible;
 
-    use crate::{contract_impl, interface_impl};
+    use crate::{contract_impl, interface_impl, module_impl};
 
     // Test expanding macros in sylvia crate tests, to calculate generating code coverage
     #[test]
@@ -789,6 +1208,9 @@ mod test {
 
                 let file = fs::File::open(entry.path()).unwrap();
                 emulate_attribute_expansion_fallible(file, "contract", contract_impl).unwrap();
+
+                let file = fs::File::open(entry.path()).unwrap();
+                emulate_attribute_expansion_fallible(file, "module", module_impl).unwrap();
             }
         }
     }
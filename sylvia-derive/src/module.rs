@@ -0,0 +1,307 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::{GenericParam, Ident, ItemImpl};
+
+use crate::crate_module;
+use crate::parser::attributes::msg::MsgType;
+use crate::parser::attributes::MsgAttrForwarding;
+use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::ParsedSylviaAttributes;
+use crate::types::msg_field::MsgField;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
+
+/// Preprocessed `module` macro input for the impl block of a `cw_multi_test` custom module.
+///
+/// Generates `sv` module containing:
+///     - ExecMsg, QueryMsg and SudoMsg
+///     - `cw_multi_test::Module` trait implementation dispatching the messages
+///     - `Querier` trait implemented on `sylvia::multitest::App` with a method per query
+pub struct ModuleInput<'a> {
+    item: &'a ItemImpl,
+    msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+}
+
+impl<'a> ModuleInput<'a> {
+    pub fn new(item: &'a ItemImpl) -> Self {
+        if !item.generics.params.is_empty() {
+            emit_error!(
+                item.generics, "Generic modules are not supported.";
+                note = "The messages of the module are the custom messages of the chain, so their types have to be known."
+            );
+        }
+
+        for variant in item.as_variants() {
+            let Some(msg_attr) = variant.attr_msg() else {
+                continue;
+            };
+            let sig = variant.into_sig();
+            if !matches!(
+                msg_attr.msg_type(),
+                MsgType::Exec | MsgType::Query | MsgType::Sudo
+            ) {
+                emit_error!(
+                    sig.ident.span(), "Unsupported message type for the module.";
+                    note = "Modules handle only the `exec`, `query` and `sudo` messages."
+                );
+            }
+            if msg_attr.funds().is_some() {
+                emit_error!(
+                    sig.ident.span(), "Funds policy is not supported in the module.";
+                    note = "Modules don't receive the funds along with the messages."
+                );
+            }
+        }
+
+        if let Some(variant) = item
+            .as_variants()
+            .find(|variant| !variant.guards().is_empty())
+        {
+            emit_error!(
+                variant.into_sig().ident.span(), "Guards are not supported in the module.";
+                note = "Check the preconditions in the handler."
+            );
+        }
+
+        let msg_attrs_to_forward = ParsedSylviaAttributes::new(item.attrs.iter()).msg_attrs_forward;
+
+        Self {
+            item,
+            msg_attrs_to_forward,
+        }
+    }
+
+    /// Processes the input and generates the module code.
+    pub fn process(&self) -> TokenStream {
+        let Self { item, .. } = self;
+        let sylvia = crate_module();
+
+        let exec_variants =
+            MsgVariants::<GenericParam>::new(item.as_variants(), MsgType::Exec, &[], &None);
+        let query_variants =
+            MsgVariants::<GenericParam>::new(item.as_variants(), MsgType::Query, &[], &None);
+        let sudo_variants =
+            MsgVariants::<GenericParam>::new(item.as_variants(), MsgType::Sudo, &[], &None);
+
+        let exec_msg = self.emit_msg(&exec_variants);
+        let query_msg = self.emit_msg(&query_variants);
+        let sudo_msg = self.emit_msg(&sudo_variants);
+        let module_impl = self.emit_module_impl(&exec_variants, &query_variants, &sudo_variants);
+        let querier = emit_querier(&query_variants);
+
+        quote! {
+            pub mod sv {
+                use super::*;
+
+                #exec_msg
+
+                impl #sylvia ::cw_std::CustomMsg for ExecMsg {}
+
+                #query_msg
+
+                impl #sylvia ::cw_std::CustomQuery for QueryMsg {}
+
+                #sudo_msg
+
+                #module_impl
+
+                #querier
+            }
+        }
+    }
+
+    fn emit_msg(&self, variants: &MsgVariants<GenericParam>) -> TokenStream {
+        let msg_ty = variants.msg_ty();
+        let enum_name = msg_ty.emit_msg_name();
+        let derive_call = msg_ty.emit_derive_call();
+        let msg_attrs_to_forward = self
+            .msg_attrs_to_forward
+            .iter()
+            .filter(|attr| attr.msg_type == msg_ty)
+            .map(|attr| &attr.attrs);
+        let msg_variants = variants.emit();
+        let variants_constructors = variants.emit_constructors();
+        let msg_name_method = variants.emit_msg_name_method();
+
+        quote! {
+            #[allow(clippy::derive_partial_eq_without_eq)]
+            #derive_call
+            #( #[ #msg_attrs_to_forward ] )*
+            #[serde(rename_all="snake_case")]
+            pub enum #enum_name {
+                #(#msg_variants,)*
+            }
+
+            impl #enum_name {
+                #(#variants_constructors)*
+
+                #msg_name_method
+            }
+        }
+    }
+
+    fn emit_module_impl(
+        &self,
+        exec_variants: &MsgVariants<GenericParam>,
+        query_variants: &MsgVariants<GenericParam>,
+        sudo_variants: &MsgVariants<GenericParam>,
+    ) -> TokenStream {
+        let sylvia = crate_module();
+        let module = &self.item.self_ty;
+
+        let exec_legs = exec_variants
+            .variants()
+            .map(|variant| emit_dispatch_leg(variant, &quote! { ExecMsg }));
+        let query_legs = query_variants
+            .variants()
+            .map(|variant| emit_dispatch_leg(variant, &quote! { QueryMsg }));
+        let sudo_legs = sudo_variants
+            .variants()
+            .map(|variant| emit_dispatch_leg(variant, &quote! { SudoMsg }));
+
+        let router_bounds = quote! {
+            ExecC: #sylvia ::cw_std::CustomMsg + #sylvia ::serde::de::DeserializeOwned + 'static,
+            QueryC: #sylvia ::cw_std::CustomQuery + #sylvia ::serde::de::DeserializeOwned + 'static,
+        };
+
+        quote! {
+            #[allow(unused_variables)]
+            impl #sylvia ::cw_multi_test::Module for #module {
+                type ExecT = ExecMsg;
+                type QueryT = QueryMsg;
+                type SudoT = SudoMsg;
+
+                fn execute<ExecC, QueryC>(
+                    &self,
+                    api: &dyn #sylvia ::cw_std::Api,
+                    storage: &mut dyn #sylvia ::cw_std::Storage,
+                    router: &dyn #sylvia ::cw_multi_test::CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+                    block: &#sylvia ::cw_std::BlockInfo,
+                    sender: #sylvia ::cw_std::Addr,
+                    msg: Self::ExecT,
+                ) -> #sylvia ::anyhow::Result<#sylvia ::cw_multi_test::AppResponse>
+                where
+                    #router_bounds
+                {
+                    let router = #sylvia ::multitest::module::CustomRouter::new(router);
+                    let ctx = #sylvia ::multitest::module::ModuleExecCtx::new(api, storage, &router, block, sender);
+
+                    match msg {
+                        #(#exec_legs,)*
+                    }
+                }
+
+                fn query(
+                    &self,
+                    api: &dyn #sylvia ::cw_std::Api,
+                    storage: &dyn #sylvia ::cw_std::Storage,
+                    querier: &dyn #sylvia ::cw_std::Querier,
+                    block: &#sylvia ::cw_std::BlockInfo,
+                    request: Self::QueryT,
+                ) -> #sylvia ::anyhow::Result<#sylvia ::cw_std::Binary> {
+                    let ctx = #sylvia ::multitest::module::ModuleQueryCtx::new(api, storage, querier, block);
+
+                    match request {
+                        #(#query_legs,)*
+                    }
+                }
+
+                fn sudo<ExecC, QueryC>(
+                    &self,
+                    api: &dyn #sylvia ::cw_std::Api,
+                    storage: &mut dyn #sylvia ::cw_std::Storage,
+                    router: &dyn #sylvia ::cw_multi_test::CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+                    block: &#sylvia ::cw_std::BlockInfo,
+                    msg: Self::SudoT,
+                ) -> #sylvia ::anyhow::Result<#sylvia ::cw_multi_test::AppResponse>
+                where
+                    #router_bounds
+                {
+                    let router = #sylvia ::multitest::module::CustomRouter::new(router);
+                    let ctx = #sylvia ::multitest::module::ModuleSudoCtx::new(api, storage, &router, block);
+
+                    match msg {
+                        #(#sudo_legs,)*
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Emits match leg calling the handler of the variant on `self` with the `ctx` in scope.
+/// The result of the query handler is serialized to `Binary`.
+fn emit_dispatch_leg(variant: &MsgVariant, enum_name: &TokenStream) -> TokenStream {
+    let sylvia = crate_module();
+    let name = variant.name();
+    let function_name = variant.function_name();
+
+    let args: Vec<_> = variant
+        .fields()
+        .iter()
+        .zip(1..)
+        .map(|(field, num)| Ident::new(&format!("field{}", num), field.name().span()))
+        .collect();
+    let fields = variant
+        .fields()
+        .iter()
+        .map(MsgField::name)
+        .zip(&args)
+        .map(|(field, num_field)| quote!(#field : #num_field));
+
+    let method_call = match variant.msg_type() {
+        MsgType::Query => quote! {
+            #sylvia ::cw_std::to_json_binary(&self.#function_name(ctx, #(#args),*)?).map_err(Into::into)
+        },
+        _ => quote! {
+            self.#function_name(ctx, #(#args),*).map_err(Into::into)
+        },
+    };
+
+    quote! {
+        #enum_name :: #name { #(#fields,)* } => #method_call
+    }
+}
+
+/// Emits the `Querier` trait with a method per query of the module, implemented on the
+/// `sylvia::multitest::App` to read the state of the module in tests.
+fn emit_querier(variants: &MsgVariants<GenericParam>) -> TokenStream {
+    let sylvia = crate_module();
+
+    let methods_declarations = variants.variants().map(|variant| {
+        let method_name = variant.method_name();
+        let return_type = variant.return_type();
+        let parameters = variant.fields().iter().map(MsgField::emit_method_field);
+
+        quote! {
+            fn #method_name(&self, #(#parameters),*) -> #sylvia ::cw_std::StdResult< #return_type >;
+        }
+    });
+
+    let methods_impls = variants.variants().map(|variant| {
+        let method_name = variant.method_name();
+        let return_type = variant.return_type();
+        let parameters = variant.fields().iter().map(MsgField::emit_method_field);
+        let fields_names = variant.fields().iter().map(MsgField::name);
+
+        quote! {
+            fn #method_name(&self, #(#parameters),*) -> #sylvia ::cw_std::StdResult< #return_type > {
+                let request = #sylvia ::cw_std::QueryRequest::Custom(QueryMsg:: #method_name (#(#fields_names),*));
+                #sylvia ::cw_std::QuerierWrapper::<QueryMsg>::new(self).query(&request)
+            }
+        }
+    });
+
+    quote! {
+        pub trait Querier {
+            #(#methods_declarations)*
+        }
+
+        impl<MtApp> Querier for #sylvia ::multitest::App<MtApp>
+        where
+            #sylvia ::multitest::App<MtApp>: #sylvia ::cw_std::Querier,
+        {
+            #(#methods_impls)*
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::{Ident, MetaList, Path, Result, Token};
+
+/// Type wrapping data parsed from `sv::arbitrary` attribute.
+///
+/// On the contract or interface, `#[sv::arbitrary]` emits the `Arbitrary` implementations
+/// of its messages with the `arbitrary` feature.
+/// On the message argument, `#[sv::arbitrary(with = path)]` overrides how the field is generated.
+#[derive(Debug, Clone, Default)]
+pub struct ArbitraryParams {
+    /// Function generating the field from `arbitrary::Unstructured`.
+    pub with: Option<Path>,
+}
+
+impl ArbitraryParams {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        ArbitraryParams::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+}
+
+impl Parse for ArbitraryParams {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::default());
+        }
+        let option: Ident = input.parse()?;
+        if option != "with" {
+            return Err(Error::new(
+                option.span(),
+                "Invalid arbitrary parameter.\n
+  = note: Expected `#[sv::arbitrary(with = path::to::function)]`.\n",
+            ));
+        }
+        let _: Token![=] = input.parse()?;
+        let with = input.parse()?;
+        if !input.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                "Unexpected tokens inside `sv::arbitrary` attribute.",
+            ));
+        }
+        Ok(Self { with: Some(with) })
+    }
+}
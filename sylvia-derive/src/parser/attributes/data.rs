@@ -0,0 +1,63 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::{Ident, MetaList, Result, Token};
+
+/// Type wrapping data parsed from `sv::data` attribute.
+///
+/// Marks the reply handler argument into which the `data` field of the
+/// successful submessage response is deserialized.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DataFieldParams {
+    /// Pass the data as raw `Binary` instead of deserializing it.
+    pub raw: bool,
+    /// Argument is an `Option` and the data field may be missing.
+    pub opt: bool,
+    /// Data is parsed as `MsgInstantiateContractResponse`.
+    pub instantiate: bool,
+}
+
+impl DataFieldParams {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        DataFieldParams::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+}
+
+impl Parse for DataFieldParams {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut data = Self::default();
+
+        while !input.is_empty() {
+            let option: Ident = input.parse()?;
+            match option.to_string().as_str() {
+                "raw" => data.raw = true,
+                "opt" => data.opt = true,
+                "instantiate" => data.instantiate = true,
+                _ => {
+                    return Err(Error::new(
+                        option.span(),
+                        "Invalid data parameter.\n
+  = note: Expected one of: `raw`, `opt` or `instantiate`.\n",
+                    ))
+                }
+            }
+            if !input.peek(Token![,]) {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        if data.raw && data.instantiate {
+            return Err(Error::new(
+                input.span(),
+                "The `raw` and `instantiate` data parameters are mutually exclusive.",
+            ));
+        }
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,76 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Attribute, Ident, LitStr, Result, Token};
+
+/// Type wrapping data parsed from the `event` helper attribute of the `TypedEvent` derive.
+///
+/// Placed on the struct, `name = "..."` sets the type of the event. Placed on the field,
+/// it sets the key of the attribute.
+#[derive(Debug, Clone, Default)]
+pub struct EventAttr {
+    pub name: Option<LitStr>,
+}
+
+impl EventAttr {
+    /// Finds and parses the `event` attribute among the attributes of the struct or field.
+    pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut event_attrs = attrs.iter().filter(|attr| attr.path().is_ident("event"));
+        let Some(first) = event_attrs.next() else {
+            return Self::default();
+        };
+
+        if let Some(redefined) = event_attrs.next() {
+            emit_error!(
+                redefined, "The attribute `event` is redefined";
+                note = first.span() => "Previous definition of the attribute `event`";
+            );
+        }
+
+        first
+            .meta
+            .require_list()
+            .and_then(|attr| EventAttr::parse.parse2(attr.tokens.clone()))
+            .unwrap_or_else(|err| {
+                emit_error!(err.span(), err);
+                Self::default()
+            })
+    }
+}
+
+impl Parse for EventAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut result = Self::default();
+
+        while !input.is_empty() {
+            let param: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            match param.to_string().as_str() {
+                "name" => {
+                    let name: LitStr = input.parse()?;
+                    if name.value().is_empty() {
+                        return Err(Error::new(name.span(), "The name can't be empty."));
+                    }
+                    result.name = Some(name);
+                }
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Invalid event parameter.\n
+  = note: Expected `name = \"...\"`.\n",
+                    ))
+                }
+            }
+            if !input.peek(Token![,]) {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        if !input.is_empty() {
+            return Err(Error::new(input.span(), "Unexpected tokens."));
+        }
+
+        Ok(result)
+    }
+}
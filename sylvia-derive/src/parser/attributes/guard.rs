@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{MetaList, Path, Result};
+
+/// Type wrapping data parsed from `sv::guard` attribute.
+///
+/// Points to the predicate called with the contract and the context of the message
+/// before the message handler.
+#[derive(Debug, Clone)]
+pub struct GuardAttr {
+    pub guard: Path,
+}
+
+impl GuardAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        GuardAttr::parse.parse2(attr.tokens.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })
+    }
+
+    /// Emits call of the guard. Expects `contract` and `ctx` to be in scope.
+    ///
+    /// `Self::method` guard is called as a method of the `contract`, so it can be used both in
+    /// the contract and the interface.
+    pub fn emit_call(&self) -> TokenStream {
+        let guard = &self.guard;
+        match guard.segments.iter().collect::<Vec<_>>().as_slice() {
+            [self_segment, method] if self_segment.ident == "Self" => {
+                let method = &method.ident;
+                quote! { contract. #method (&ctx)?; }
+            }
+            _ => quote! { #guard (contract, &ctx)?; },
+        }
+    }
+}
+
+impl Parse for GuardAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse().map(|guard| Self { guard })
+    }
+}
@@ -3,18 +3,22 @@ use proc_macro_error::emit_warning;
 use syn::fold::Fold;
 use syn::parse::{Parse, ParseStream, Parser};
 use syn::spanned::Spanned;
-use syn::{parenthesized, Error, Ident, MetaList, Path, Result, Token};
+use syn::{parenthesized, Error, Ident, LitStr, MetaList, Path, Result, Token};
 
 use proc_macro_error::emit_error;
 
 use crate::fold::StripGenerics;
 
+use super::GuardAttr;
+
 /// Type wrapping data parsed from `sv::message` attribute.
 #[derive(Debug)]
 pub struct ContractMessageAttr {
     pub module: Path,
     pub variant: Ident,
     pub customs: Customs,
+    pub guard: Option<GuardAttr>,
+    pub namespace: Option<LitStr>,
 }
 
 impl ContractMessageAttr {
@@ -39,22 +43,72 @@ pub struct Customs {
     pub has_query: bool,
 }
 
-fn interface_has_custom(content: ParseStream) -> Result<Customs> {
+/// Parses the `: custom(msg, query), guard(path)` part of the `sv::messages` attribute.
+fn interface_params(content: ParseStream) -> Result<(Customs, Option<GuardAttr>)> {
     let mut customs = Customs {
         has_msg: false,
         has_query: false,
     };
+    let mut guard = None;
 
     if !content.peek(Token![:]) {
-        return Ok(customs);
+        return Ok((customs, guard));
     }
 
     let _: Token![:] = content.parse()?;
-    let attr: Ident = content.parse()?;
-    if attr != "custom" {
-        return Ok(customs);
+    loop {
+        let attr: Ident = content.parse()?;
+        if attr == "custom" {
+            customs = interface_has_custom(content)?;
+        } else if attr == "guard" {
+            let guard_content;
+            parenthesized!(guard_content in content);
+            guard = Some(GuardAttr::parse(&guard_content)?);
+        } else {
+            return Err(Error::new(
+                attr.span(),
+                "Invalid interface parameter, expected `custom` or `guard`.\n
+  = note: Expected attribute to be in form `#[sv::messages(interface: custom(msg, query), guard(path))]`.\n",
+            ));
+        }
+
+        if !content.peek(Token![,]) {
+            break;
+        }
+        let _: Token![,] = content.parse()?;
+    }
+    Ok((customs, guard))
+}
+
+/// Parses the `, namespace = "name"` part of the `sv::messages` attribute.
+fn interface_namespace(content: ParseStream) -> Result<Option<LitStr>> {
+    if !content.peek(Token![,]) {
+        return Ok(None);
     }
 
+    let _: Token![,] = content.parse()?;
+    let param: Ident = content.parse()?;
+    if param != "namespace" {
+        return Err(Error::new(
+            param.span(),
+            "Invalid interface parameter, expected `namespace`.\n
+  = note: Expected attribute to be in form `#[sv::messages(interface, namespace = \"name\")]`.\n",
+        ));
+    }
+    let _: Token![=] = content.parse()?;
+    let namespace: LitStr = content.parse()?;
+    if namespace.value().is_empty() {
+        return Err(Error::new(namespace.span(), "Namespace cannot be empty."));
+    }
+    Ok(Some(namespace))
+}
+
+fn interface_has_custom(content: ParseStream) -> Result<Customs> {
+    let mut customs = Customs {
+        has_msg: false,
+        has_query: false,
+    };
+
     let custom_content;
     parenthesized!(custom_content in content);
 
@@ -105,17 +159,20 @@ impl Parse for ContractMessageAttr {
         } else {
             Ident::new("", module.span())
         };
-        let customs = interface_has_custom(input)?;
+        let namespace = interface_namespace(input)?;
+        let (customs, guard) = interface_params(input)?;
         if !input.is_empty() {
             return Err(Error::new(input.span(),
                 "Unexpected tokens inside `sv::messages` attribtue.\n
-  = note: Maximal supported form of attribute: `#[sv::messages(interface::path as InterfaceName: custom(msg, query))]`.\n"
+  = note: Maximal supported form of attribute: `#[sv::messages(interface::path as InterfaceName, namespace = \"name\": custom(msg, query), guard(path))]`.\n"
             ));
         }
         Ok(Self {
             module,
             variant,
             customs,
+            guard,
+            namespace,
         })
     }
 }
@@ -3,21 +3,35 @@
 
 use proc_macro_error::emit_error;
 use syn::spanned::Spanned;
-use syn::{Attribute, MetaList, PathSegment};
+use syn::{Attribute, MacroDelimiter, Meta, MetaList, PathSegment};
 
+pub mod arbitrary;
 pub mod attr;
 pub mod custom;
+pub mod data;
 pub mod error;
+pub mod event;
+pub mod guard;
 pub mod messages;
 pub mod msg;
+pub mod msg_field;
 pub mod override_entry_point;
+pub mod payload;
+pub mod version;
 
+pub use arbitrary::ArbitraryParams;
 pub use attr::{MsgAttrForwarding, VariantAttrForwarding};
 pub use custom::Custom;
+pub use data::DataFieldParams;
 pub use error::ContractErrorAttr;
+pub use event::EventAttr;
+pub use guard::GuardAttr;
 pub use messages::{ContractMessageAttr, Customs};
-pub use msg::{MsgAttr, MsgType};
+pub use msg::{FundsPolicy, MsgAttr, MsgNaming, MsgType};
+pub use msg_field::MsgFieldAttr;
 pub use override_entry_point::{FilteredOverrideEntryPoints, OverrideEntryPoint};
+pub use payload::PayloadFieldParam;
+pub use version::VersionAttr;
 
 /// This struct represents all possible attributes that
 /// are parsed and utilized by sylvia.
@@ -29,6 +43,15 @@ pub enum SylviaAttribute {
     OverrideEntryPoint,
     VariantAttrs,
     MsgAttrs,
+    Data,
+    Payload,
+    Funds,
+    Guard,
+    Version,
+    Actions,
+    TypedQueryErrors,
+    Invariant,
+    Arbitrary,
 }
 
 impl SylviaAttribute {
@@ -50,6 +73,15 @@ impl SylviaAttribute {
             "override_entry_point" => Some(Self::OverrideEntryPoint),
             "attr" => Some(Self::VariantAttrs),
             "msg_attr" => Some(Self::MsgAttrs),
+            "data" => Some(Self::Data),
+            "payload" => Some(Self::Payload),
+            "funds" => Some(Self::Funds),
+            "guard" => Some(Self::Guard),
+            "version" => Some(Self::Version),
+            "actions" => Some(Self::Actions),
+            "typed_query_errors" => Some(Self::TypedQueryErrors),
+            "invariant" => Some(Self::Invariant),
+            "arbitrary" => Some(Self::Arbitrary),
             _ => None,
         }
     }
@@ -66,6 +98,15 @@ pub struct ParsedSylviaAttributes {
     pub override_entry_point_attrs: Vec<OverrideEntryPoint>,
     pub variant_attrs_forward: Vec<VariantAttrForwarding>,
     pub msg_attrs_forward: Vec<MsgAttrForwarding>,
+    pub data: Option<DataFieldParams>,
+    pub payload: Option<PayloadFieldParam>,
+    pub funds: bool,
+    pub guards: Vec<GuardAttr>,
+    pub version_attr: Option<VersionAttr>,
+    pub actions: bool,
+    pub typed_query_errors: bool,
+    pub invariant: bool,
+    pub arbitrary: Option<ArbitraryParams>,
 }
 
 impl ParsedSylviaAttributes {
@@ -73,7 +114,27 @@ impl ParsedSylviaAttributes {
         let mut result = Self::default();
         for attr in attrs {
             let sylvia_attr = SylviaAttribute::new(attr);
-            let attr_content = attr.meta.require_list();
+            let attr_content = match (&sylvia_attr, &attr.meta) {
+                // `sv::data`, `sv::payload`, `sv::funds`, `sv::actions`, `sv::typed_query_errors`,
+                // `sv::invariant` and `sv::arbitrary` can be used without any parameters.
+                (
+                    Some(
+                        SylviaAttribute::Data
+                        | SylviaAttribute::Payload
+                        | SylviaAttribute::Funds
+                        | SylviaAttribute::Actions
+                        | SylviaAttribute::TypedQueryErrors
+                        | SylviaAttribute::Invariant
+                        | SylviaAttribute::Arbitrary,
+                    ),
+                    Meta::Path(path),
+                ) => Ok(MetaList {
+                    path: path.clone(),
+                    delimiter: MacroDelimiter::Paren(Default::default()),
+                    tokens: Default::default(),
+                }),
+                _ => attr.meta.require_list().cloned(),
+            };
 
             if let (Some(sylvia_attr), Ok(attr)) = (sylvia_attr, &attr_content) {
                 result.match_attribute(&sylvia_attr, attr);
@@ -81,7 +142,7 @@ impl ParsedSylviaAttributes {
         }
 
         if let Some(attr) = result.variant_attrs_forward.first() {
-            if let Some(MsgAttr::Instantiate) = result.msg_attr {
+            if let Some(MsgAttr::Instantiate { .. }) = result.msg_attr {
                 emit_error!(
                     attr.span, "The attribute `sv::attr` is not supported for `instantiate`";
                     note = "Message `instantiate` is a structure, use `#[sv::msg_attr] instead`";
@@ -157,6 +218,123 @@ impl ParsedSylviaAttributes {
                     self.msg_attrs_forward.push(message_attrs);
                 }
             }
+            SylviaAttribute::Data => {
+                if self.data.is_none() {
+                    if let Ok(data) = DataFieldParams::new(attr) {
+                        self.data = Some(data);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::data` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::data`";
+                        note = "Only one `sv::data` attribute can exist on a single argument"
+                    );
+                }
+            }
+            SylviaAttribute::Payload => {
+                if self.payload.is_none() {
+                    if let Ok(payload) = PayloadFieldParam::new(attr) {
+                        self.payload = Some(payload);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::payload` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::payload`";
+                        note = "Only one `sv::payload` attribute can exist on a single argument"
+                    );
+                }
+            }
+            SylviaAttribute::Funds => {
+                if !attr.tokens.is_empty() {
+                    emit_error!(
+                        attr.tokens, "The attribute `sv::funds` doesn't take any parameters";
+                        note = "Use `#[sv::funds]` to receive the amount paid to the `must_pay` message handler"
+                    );
+                } else if self.funds {
+                    emit_error!(
+                        attr, "The attribute `sv::funds` is redefined";
+                        note = "Only one `sv::funds` attribute can exist on a single argument"
+                    );
+                } else {
+                    self.funds = true;
+                }
+            }
+            SylviaAttribute::Guard => {
+                if let Ok(guard) = GuardAttr::new(attr) {
+                    self.guards.push(guard);
+                }
+            }
+            SylviaAttribute::Version => {
+                if self.version_attr.is_none() {
+                    if let Ok(version) = VersionAttr::new(attr) {
+                        self.version_attr = Some(version);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::version` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::version`";
+                        note = "Only one `sv::version` attribute can exist on a single contract"
+                    );
+                }
+            }
+            SylviaAttribute::Actions => {
+                if !attr.tokens.is_empty() {
+                    emit_error!(
+                        attr.tokens, "The attribute `sv::actions` doesn't take any parameters";
+                        note = "Use `#[sv::actions]` to add the `action` attribute to the responses of the contract"
+                    );
+                } else if self.actions {
+                    emit_error!(
+                        attr, "The attribute `sv::actions` is redefined";
+                        note = "Only one `sv::actions` attribute can exist on a single contract"
+                    );
+                } else {
+                    self.actions = true;
+                }
+            }
+            SylviaAttribute::TypedQueryErrors => {
+                if !attr.tokens.is_empty() {
+                    emit_error!(
+                        attr.tokens, "The attribute `sv::typed_query_errors` doesn't take any parameters";
+                        note = "Use `#[sv::typed_query_errors]` to return `sylvia::types::QueryError` from the generated `Querier`"
+                    );
+                } else if self.typed_query_errors {
+                    emit_error!(
+                        attr, "The attribute `sv::typed_query_errors` is redefined";
+                        note = "Only one `sv::typed_query_errors` attribute can exist on a single contract or interface"
+                    );
+                } else {
+                    self.typed_query_errors = true;
+                }
+            }
+            SylviaAttribute::Invariant => {
+                if !attr.tokens.is_empty() {
+                    emit_error!(
+                        attr.tokens, "The attribute `sv::invariant` doesn't take any parameters";
+                        note = "Use `#[sv::invariant]` to check the method after every multitest call to the contract"
+                    );
+                } else if self.invariant {
+                    emit_error!(
+                        attr, "The attribute `sv::invariant` is redefined";
+                        note = "Only one `sv::invariant` attribute can exist on a single method"
+                    );
+                } else {
+                    self.invariant = true;
+                }
+            }
+            SylviaAttribute::Arbitrary => {
+                if self.arbitrary.is_none() {
+                    if let Ok(arbitrary) = ArbitraryParams::new(attr) {
+                        self.arbitrary = Some(arbitrary);
+                    }
+                } else {
+                    emit_error!(
+                        attr, "The attribute `sv::arbitrary` is redefined";
+                        note = attr.span() => "Previous definition of the attribute `sv::arbitrary`";
+                        note = "Only one `sv::arbitrary` attribute can exist on a single sylvia entity"
+                    );
+                }
+            }
         }
     }
 }
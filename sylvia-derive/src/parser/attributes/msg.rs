@@ -1,6 +1,9 @@
+use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
+use quote::quote;
 use syn::parse::{Error, Parse, ParseStream, Parser};
-use syn::{Ident, MetaList, Result, Token};
+use syn::punctuated::Punctuated;
+use syn::{bracketed, token, Expr, Ident, LitInt, LitStr, MetaList, Result, Token};
 
 /// Type of message to be generated
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
@@ -11,12 +14,146 @@ pub enum MsgType {
     Migrate,
     Reply,
     Sudo,
+    IbcChannelOpen,
+    IbcChannelConnect,
+    IbcChannelClose,
+    IbcPacketReceive,
+    IbcPacketAck,
+    IbcPacketTimeout,
 }
 
-/// ArgumentParser holds `resp` parameter parsed from `sv::msg` attribute.
+impl MsgType {
+    /// Checks if the message is handled by one of the IBC entry points.
+    pub fn is_ibc(&self) -> bool {
+        matches!(
+            self,
+            Self::IbcChannelOpen
+                | Self::IbcChannelConnect
+                | Self::IbcChannelClose
+                | Self::IbcPacketReceive
+                | Self::IbcPacketAck
+                | Self::IbcPacketTimeout
+        )
+    }
+
+    /// All IBC message types in the order of the IBC channel lifecycle.
+    pub fn ibc_msg_types() -> [Self; 6] {
+        [
+            Self::IbcChannelOpen,
+            Self::IbcChannelConnect,
+            Self::IbcChannelClose,
+            Self::IbcPacketReceive,
+            Self::IbcPacketAck,
+            Self::IbcPacketTimeout,
+        ]
+    }
+}
+
+/// Result of the submessage on which the reply handler should be called.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub enum ReplyOn {
+    Success,
+    Error,
+    #[default]
+    Always,
+}
+
+impl ReplyOn {
+    fn new(reply_on: &Ident) -> Result<Self> {
+        match reply_on.to_string().as_str() {
+            "success" => Ok(Self::Success),
+            "error" => Ok(Self::Error),
+            "always" => Ok(Self::Always),
+            _ => Err(Error::new(
+                reply_on.span(),
+                "Invalid argument type, expected one of: `success`, `error` or `always`.",
+            )),
+        }
+    }
+
+    /// Checks if two handlers can be bound to the same reply id.
+    pub fn excludes(&self, other: &Self) -> bool {
+        self == other || *self == Self::Always || *other == Self::Always
+    }
+}
+
+/// Funds accepted by the `exec` or `instantiate` message handler.
+#[derive(Debug, Clone)]
+pub enum FundsPolicy {
+    /// Any funds can be sent along with the message.
+    Payable,
+    /// No funds can be sent along with the message.
+    Nonpayable,
+    /// Exactly one coin of the given denom has to be sent along with the message.
+    MustPay(Expr),
+}
+
+/// Serialized name of the message or its field and the alternative names it is
+/// deserialized from, defined with `name = "..."` and `alias = [...]` parameters.
+#[derive(Debug, Clone, Default)]
+pub struct MsgNaming {
+    pub name: Option<LitStr>,
+    pub aliases: Vec<LitStr>,
+}
+
+impl MsgNaming {
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.aliases.is_empty()
+    }
+
+    /// Parses the value of the `alias` parameter - a single string or a list of strings.
+    pub fn parse_aliases(input: ParseStream) -> Result<Vec<LitStr>> {
+        if input.peek(token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            let aliases: Punctuated<LitStr, Token![,]> =
+                content.parse_terminated(|input| input.parse(), Token![,])?;
+            Ok(aliases.into_iter().collect())
+        } else {
+            input.parse().map(|alias| vec![alias])
+        }
+    }
+
+    /// Emits serde attributes renaming the item. The `name` is skipped if `rename` is false.
+    pub fn emit_serde_attrs(&self, rename: bool) -> TokenStream {
+        let name = self
+            .name
+            .as_ref()
+            .filter(|_| rename)
+            .map(|name| quote! { #[serde(rename = #name)] });
+        let aliases = &self.aliases;
+
+        quote! {
+            #name
+            #( #[serde(alias = #aliases)] )*
+        }
+    }
+}
+
+/// ArgumentParser holds `resp`, `handlers`, `reply_on`, `id`, funds policy and naming parameters
+/// parsed from `sv::msg` attribute.
 #[derive(Default)]
 struct ArgumentParser {
     pub resp_type: Option<Ident>,
+    pub handlers: Vec<Ident>,
+    pub reply_on: Option<ReplyOn>,
+    pub id: Option<LitInt>,
+    pub funds: Option<FundsPolicy>,
+    pub naming: MsgNaming,
+}
+
+impl ArgumentParser {
+    fn set_funds(&mut self, arg_type: &Ident, funds: FundsPolicy) -> Result<()> {
+        if self.funds.is_some() {
+            return Err(Error::new(
+                arg_type.span(),
+                "Funds policy is redefined.\n
+  = note: Use only one of `payable`, `nonpayable` or `must_pay`.\n",
+            ));
+        }
+        self.funds = Some(funds);
+        Ok(())
+    }
 }
 
 impl Parse for ArgumentParser {
@@ -25,16 +162,55 @@ impl Parse for ArgumentParser {
         while input.peek2(Ident) {
             let _: Token![,] = input.parse()?;
             let arg_type: Ident = input.parse()?;
+            match arg_type.to_string().as_str() {
+                "payable" => {
+                    result.set_funds(&arg_type, FundsPolicy::Payable)?;
+                    continue;
+                }
+                "nonpayable" => {
+                    result.set_funds(&arg_type, FundsPolicy::Nonpayable)?;
+                    continue;
+                }
+                _ => (),
+            }
             let _: Token![=] = input.parse()?;
             match arg_type.to_string().as_str() {
                 "resp" => {
                     let resp_type: Ident = input.parse()?;
                     result.resp_type = Some(resp_type);
                 }
+                "handlers" => {
+                    let content;
+                    bracketed!(content in input);
+                    let handlers: Punctuated<Ident, Token![,]> =
+                        content.parse_terminated(Ident::parse, Token![,])?;
+                    result.handlers = handlers.into_iter().collect();
+                }
+                "reply_on" => {
+                    let reply_on: Ident = input.parse()?;
+                    result.reply_on = Some(ReplyOn::new(&reply_on)?);
+                }
+                "id" => {
+                    let id: LitInt = input.parse()?;
+                    id.base10_parse::<u64>()?;
+                    result.id = Some(id);
+                }
+                "must_pay" => {
+                    let denom: Expr = input.parse()?;
+                    result.set_funds(&arg_type, FundsPolicy::MustPay(denom))?;
+                }
+                "name" => {
+                    let name: LitStr = input.parse()?;
+                    result.naming.name = Some(name);
+                }
+                "alias" => {
+                    let aliases = MsgNaming::parse_aliases(input)?;
+                    result.naming.aliases.extend(aliases);
+                }
                 _ => {
                     return Err(Error::new(
-                        input.span(),
-                        "Invalid argument type, expected `resp` or no argument.",
+                        arg_type.span(),
+                        "Invalid argument type, expected `resp`, `handlers`, `reply_on`, `id`, `payable`, `nonpayable`, `must_pay`, `name`, `alias` or no argument.",
                     ))
                 }
             }
@@ -44,14 +220,34 @@ impl Parse for ArgumentParser {
 }
 
 /// Parsed representation of `#[sv::msg(...)]` attribute.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum MsgAttr {
-    Exec,
-    Query { resp_type: Option<Ident> },
-    Instantiate,
+    Exec {
+        funds: Option<FundsPolicy>,
+        naming: MsgNaming,
+    },
+    Query {
+        resp_type: Option<Ident>,
+        naming: MsgNaming,
+    },
+    Instantiate {
+        funds: Option<FundsPolicy>,
+    },
     Migrate,
-    Reply,
-    Sudo,
+    Reply {
+        handlers: Vec<Ident>,
+        reply_on: Option<ReplyOn>,
+        id: Option<LitInt>,
+    },
+    Sudo {
+        naming: MsgNaming,
+    },
+    IbcChannelOpen,
+    IbcChannelConnect,
+    IbcChannelClose,
+    IbcPacketReceive,
+    IbcPacketAck,
+    IbcPacketTimeout,
 }
 
 impl MsgAttr {
@@ -78,6 +274,30 @@ impl MsgAttr {
             Self::Migrate { .. } => MsgType::Migrate,
             Self::Reply { .. } => MsgType::Reply,
             Self::Sudo { .. } => MsgType::Sudo,
+            Self::IbcChannelOpen => MsgType::IbcChannelOpen,
+            Self::IbcChannelConnect => MsgType::IbcChannelConnect,
+            Self::IbcChannelClose => MsgType::IbcChannelClose,
+            Self::IbcPacketReceive => MsgType::IbcPacketReceive,
+            Self::IbcPacketAck => MsgType::IbcPacketAck,
+            Self::IbcPacketTimeout => MsgType::IbcPacketTimeout,
+        }
+    }
+
+    /// Funds policy of the `exec` or `instantiate` message handler.
+    pub fn funds(&self) -> Option<&FundsPolicy> {
+        match self {
+            Self::Exec { funds, .. } | Self::Instantiate { funds } => funds.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Serialized name and aliases of the `exec`, `query` or `sudo` message.
+    pub fn naming(&self) -> Option<&MsgNaming> {
+        match self {
+            Self::Exec { naming, .. } | Self::Query { naming, .. } | Self::Sudo { naming } => {
+                Some(naming)
+            }
+            _ => None,
         }
     }
 }
@@ -85,20 +305,92 @@ impl MsgAttr {
 impl Parse for MsgAttr {
     fn parse(input: ParseStream) -> Result<Self> {
         let ty: Ident = input.parse()?;
-        let ArgumentParser { resp_type } = ArgumentParser::parse(input)?;
+        let ArgumentParser {
+            resp_type,
+            handlers,
+            reply_on,
+            id,
+            funds,
+            naming,
+        } = ArgumentParser::parse(input)?;
+
+        if ty != "reply" && (!handlers.is_empty() || reply_on.is_some() || id.is_some()) {
+            return Err(Error::new(
+                ty.span(),
+                "Arguments `handlers`, `reply_on` and `id` are supported only for the `reply` message type.",
+            ));
+        }
+
+        if let Some(id) = &id {
+            if handlers.len() > 1 {
+                return Err(Error::new(
+                    id.span(),
+                    "Argument `id` can be used only with a single reply id.\n
+  = note: Split the handler or bind it to a single reply id with `handlers=[...]`.\n",
+                ));
+            }
+            if handlers.is_empty() && reply_on.is_none() {
+                return Err(Error::new(
+                    id.span(),
+                    "Argument `id` is not supported for the catch-all reply handler.\n
+  = note: Bind the handler to the reply id with `handlers=[...]` or `reply_on`.\n",
+                ));
+            }
+        }
+
+        if funds.is_some() && ty != "exec" && ty != "instantiate" {
+            return Err(Error::new(
+                ty.span(),
+                "Funds policy is supported only for the `exec` and `instantiate` message types.",
+            ));
+        }
+
+        if !naming.is_empty() && ty != "exec" && ty != "query" && ty != "sudo" {
+            return Err(Error::new(
+                ty.span(),
+                "Arguments `name` and `alias` are supported only for the `exec`, `query` and `sudo` message types.",
+            ));
+        }
+
+        if funds.is_some() && !cfg!(feature = "cosmwasm_1_2") {
+            return Err(Error::new(
+                ty.span(),
+                "Funds policy requires the `cosmwasm_1_2` feature.\n
+  = note: Enable `cosmwasm_1_2` feature of the `sylvia` crate.\n",
+            ));
+        }
 
         let result = match ty.to_string().as_str() {
-            "exec" => Self::Exec,
-            "query" => Self::Query { resp_type },
-            "instantiate" => Self::Instantiate,
+            "exec" => Self::Exec { funds, naming },
+            "query" => Self::Query { resp_type, naming },
+            "instantiate" => Self::Instantiate { funds },
             "migrate" => Self::Migrate,
-            "reply" => Self::Reply,
-            "sudo" => Self::Sudo,
+            "reply" => Self::Reply {
+                handlers,
+                reply_on,
+                id,
+            },
+            "sudo" => Self::Sudo { naming },
+            "ibc_channel_open" => Self::IbcChannelOpen,
+            "ibc_channel_connect" => Self::IbcChannelConnect,
+            "ibc_channel_close" => Self::IbcChannelClose,
+            "ibc_packet_receive" => Self::IbcPacketReceive,
+            "ibc_packet_ack" => Self::IbcPacketAck,
+            "ibc_packet_timeout" => Self::IbcPacketTimeout,
             _ => return Err(Error::new(
                 input.span(),
-                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply` or `sudo`.",
+                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply`, `sudo` or one of the `ibc_*` entry points.",
             ))
         };
+
+        if result.msg_type().is_ibc() && !cfg!(feature = "stargate") {
+            return Err(Error::new(
+                ty.span(),
+                "IBC entry points require the `stargate` feature.\n
+  = note: Enable `stargate` feature of the `sylvia` crate.\n",
+            ));
+        }
+
         Ok(result)
     }
 }
@@ -0,0 +1,88 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Attribute, Ident, Lit, MetaList, Result, Token};
+
+use super::msg::MsgNaming;
+use super::SylviaAttribute;
+
+/// Type wrapping data parsed from `sv::msg` attribute placed on the method argument.
+///
+/// `name = field_name` names the message field of the argument, which is required if the
+/// argument is a pattern. `name = "..."` and `alias = [...]` define the serialized name of
+/// the field and the alternative names it is deserialized from.
+#[derive(Debug, Clone, Default)]
+pub struct MsgFieldAttr {
+    pub name: Option<Ident>,
+    pub naming: MsgNaming,
+}
+
+impl MsgFieldAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        MsgFieldAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+
+    /// Finds and parses the `sv::msg` attribute among the argument attributes.
+    pub fn from_attrs(attrs: &[Attribute]) -> Option<Self> {
+        let mut msg_attrs = attrs
+            .iter()
+            .filter(|attr| matches!(SylviaAttribute::new(attr), Some(SylviaAttribute::Msg)));
+        let first = msg_attrs.next()?;
+
+        if let Some(redefined) = msg_attrs.next() {
+            emit_error!(
+                redefined, "The attribute `sv::msg` is redefined";
+                note = first.span() => "Previous definition of the attribute `sv::msg`";
+                note = "Only one `sv::msg` attribute can exist on a single argument"
+            );
+        }
+
+        let attr = first
+            .meta
+            .require_list()
+            .map_err(|err| emit_error!(err.span(), err))
+            .ok()?;
+        Self::new(attr).ok()
+    }
+}
+
+impl Parse for MsgFieldAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut result = Self::default();
+
+        while !input.is_empty() {
+            let param: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            match param.to_string().as_str() {
+                "name" if input.peek(Lit) => result.naming.name = Some(input.parse()?),
+                "name" => result.name = Some(input.parse()?),
+                "alias" => result
+                    .naming
+                    .aliases
+                    .extend(MsgNaming::parse_aliases(input)?),
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Invalid argument parameter.\n
+  = note: Expected `name = field_name`, `name = \"serialized_name\"` or `alias = [...]`.\n",
+                    ))
+                }
+            }
+            if !input.peek(Token![,]) {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        if !input.is_empty() {
+            return Err(Error::new(input.span(), "Unexpected tokens."));
+        }
+
+        Ok(result)
+    }
+}
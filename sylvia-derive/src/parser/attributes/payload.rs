@@ -0,0 +1,42 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::{Ident, MetaList, Result};
+
+/// Type wrapping data parsed from `sv::payload` attribute.
+///
+/// Marks the reply handler argument into which the `payload` of the
+/// submessage is deserialized.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PayloadFieldParam {
+    /// Pass the payload as raw `Binary` instead of deserializing it.
+    pub raw: bool,
+}
+
+impl PayloadFieldParam {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        PayloadFieldParam::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+}
+
+impl Parse for PayloadFieldParam {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let option: Ident = input.parse()?;
+        match option.to_string().as_str() {
+            "raw" => Ok(Self { raw: true }),
+            _ => Err(Error::new(
+                option.span(),
+                "Invalid payload parameter.\n
+  = note: Expected `#[sv::payload]` or `#[sv::payload(raw)]`.\n",
+            )),
+        }
+    }
+}
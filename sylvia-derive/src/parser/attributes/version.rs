@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Error, Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, MetaList, Result, Token};
+
+use crate::crate_module;
+
+/// Type wrapping data parsed from `sv::version` attribute.
+///
+/// Contract name and version stored as the `cw2` contract info on instantiation and
+/// validated on migration.
+#[derive(Debug, Clone)]
+pub struct VersionAttr {
+    pub name: Expr,
+    pub version: Expr,
+}
+
+impl VersionAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        VersionAttr::parse
+            .parse2(attr.tokens.clone())
+            .and_then(|version| {
+                if !cfg!(feature = "cw2") {
+                    return Err(Error::new(
+                        attr.span(),
+                        "The attribute `sv::version` requires the `cw2` feature.\n
+  = note: Enable `cw2` feature of the `sylvia` crate.\n",
+                    ));
+                }
+                Ok(version)
+            })
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+
+    /// Emits storing of the contract info. Expects `storage` to be `&mut dyn Storage`.
+    pub fn emit_set_version(&self, storage: TokenStream) -> TokenStream {
+        let sylvia = crate_module();
+        let Self { name, version } = self;
+
+        quote! {
+            #sylvia ::cw2::set_contract_version(#storage, #name, #version)?;
+        }
+    }
+
+    /// Emits validation that the stored contract can be migrated to this one.
+    /// Expects `storage` to be `&dyn Storage`.
+    pub fn emit_ensure_upgrade(&self, storage: TokenStream) -> TokenStream {
+        let sylvia = crate_module();
+        let Self { name, version } = self;
+
+        quote! {
+            #sylvia ::version::ensure_from_older_version(#storage, #name, #version)?;
+        }
+    }
+}
+
+impl Parse for VersionAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut name = None;
+        let mut version = None;
+
+        while !input.is_empty() {
+            let param: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let value: Expr = input.parse()?;
+            let slot = match param.to_string().as_str() {
+                "name" => &mut name,
+                "version" => &mut version,
+                _ => {
+                    return Err(Error::new(
+                        param.span(),
+                        "Invalid version parameter.\n
+  = note: Expected `name = ...` and `version = ...`.\n",
+                    ))
+                }
+            };
+            if slot.replace(value).is_some() {
+                return Err(Error::new(
+                    param.span(),
+                    format!("The `{}` parameter is redefined.", param),
+                ));
+            }
+            if !input.peek(Token![,]) {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        match (name, version) {
+            (Some(name), Some(version)) => Ok(Self { name, version }),
+            _ => Err(Error::new(
+                input.span(),
+                "Missing version parameter.\n
+  = note: Expected `#[sv::version(name = \"crates.io:my-contract\", version = env!(\"CARGO_PKG_VERSION\"))]`.\n",
+            )),
+        }
+    }
+}
@@ -8,7 +8,7 @@ pub mod variant_descs;
 
 pub use attributes::{
     ContractErrorAttr, ContractMessageAttr, Custom, Customs, FilteredOverrideEntryPoints, MsgAttr,
-    MsgType, OverrideEntryPoint, ParsedSylviaAttributes, SylviaAttribute,
+    MsgType, OverrideEntryPoint, ParsedSylviaAttributes, SylviaAttribute, VersionAttr,
 };
 use check_generics::{CheckGenerics, GetPath};
 pub use entry_point::EntryPointArgs;
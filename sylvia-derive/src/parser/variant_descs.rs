@@ -1,4 +1,4 @@
-use crate::parser::attributes::VariantAttrForwarding;
+use crate::parser::attributes::{GuardAttr, VariantAttrForwarding};
 use crate::parser::{MsgAttr, ParsedSylviaAttributes};
 use syn::{Attribute, ImplItem, ItemImpl, ItemTrait, Signature, TraitItem};
 
@@ -6,6 +6,7 @@ use syn::{Attribute, ImplItem, ItemImpl, ItemTrait, Signature, TraitItem};
 pub struct VariantDesc<'a> {
     msg_attr: Option<MsgAttr>,
     attrs_to_forward: Vec<VariantAttrForwarding>,
+    guards: Vec<GuardAttr>,
     sig: &'a Signature,
 }
 
@@ -14,9 +15,11 @@ impl<'a> VariantDesc<'a> {
         let sylvia_params = ParsedSylviaAttributes::new(attrs.iter());
         let attrs_to_forward = sylvia_params.variant_attrs_forward;
         let msg_attr = sylvia_params.msg_attr;
+        let guards = sylvia_params.guards;
         Self {
             msg_attr,
             attrs_to_forward,
+            guards,
             sig,
         }
     }
@@ -32,6 +35,10 @@ impl<'a> VariantDesc<'a> {
     pub fn attrs_to_forward(&self) -> Vec<VariantAttrForwarding> {
         self.attrs_to_forward.clone()
     }
+
+    pub fn guards(&self) -> Vec<GuardAttr> {
+        self.guards.clone()
+    }
 }
 
 pub type VariantDescs<'a> = Box<dyn Iterator<Item = VariantDesc<'a>> + 'a>;
@@ -1,5 +1,5 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{ItemImpl, Path, Type};
 
@@ -19,78 +19,171 @@ impl Interfaces {
         Self { interfaces }
     }
 
+    /// Emits glue message variants of the interfaces.
+    ///
+    /// Variants of the namespaced interfaces are externally tagged with the namespace and
+    /// have to precede the untagged ones.
     pub fn emit_glue_message_variants(
         &self,
         msg_ty: &MsgType,
         contract: &Type,
     ) -> Vec<TokenStream> {
-        self.interfaces
+        let (namespaced, plain): (Vec<_>, Vec<_>) = self
+            .interfaces
             .iter()
+            .partition(|interface| interface.namespace.is_some());
+
+        namespaced
+            .into_iter()
+            .chain(plain)
             .map(|interface| {
                 let ContractMessageAttr {
-                    module, variant, ..
+                    module,
+                    variant,
+                    namespace,
+                    ..
                 } = interface;
 
                 let interface_enum = quote! { < #contract as #module ::sv::InterfaceMessagesApi> };
                 let type_name = msg_ty.as_accessor_name();
+                let serde_attr = match namespace {
+                    Some(namespace) => quote! { #[serde(rename = #namespace)] },
+                    None => quote! { #[serde(untagged)] },
+                };
 
-                quote! { #variant ( #interface_enum :: #type_name) }
+                quote! { #serde_attr #variant ( #interface_enum :: #type_name) }
             })
             .collect()
     }
 
-    pub fn emit_glue_message_types(&self, msg_ty: &MsgType, contract: &Type) -> Vec<TokenStream> {
+    pub fn emit_glue_message_schemas(&self, msg_ty: &MsgType, contract: &Type) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
         self.interfaces
             .iter()
             .map(|interface| {
-                let ContractMessageAttr { module, .. } = interface;
+                let ContractMessageAttr {
+                    module, namespace, ..
+                } = interface;
 
                 let interface_enum = quote! { < #contract as #module ::sv::InterfaceMessagesApi> };
                 let type_name = msg_ty.as_accessor_name();
 
-                quote! { #interface_enum :: #type_name }
+                match namespace {
+                    Some(namespace) => quote! {
+                        #sylvia ::utils::namespaced_schema::< #interface_enum :: #type_name >(gen, #namespace)
+                    },
+                    None => quote! { gen.subschema_for::< #interface_enum :: #type_name >() },
+                }
             })
             .collect()
     }
 
+    /// Emits names of the messages the glue message deserializes into the interface variants.
+    /// Namespaced interfaces claim only their namespace.
     pub fn emit_messages_call(&self, msg_ty: &MsgType) -> Vec<TokenStream> {
         self.interfaces
             .iter()
             .map(|interface| {
-                let ContractMessageAttr { module, .. } = interface;
+                let ContractMessageAttr {
+                    module, namespace, ..
+                } = interface;
 
                 let ep_name = msg_ty.emit_ep_name();
                 let messages_fn_name = Ident::new(&format!("{}_messages", ep_name), module.span());
-                quote! {
-                    &#module ::sv:: #messages_fn_name()
+                match namespace {
+                    Some(namespace) => quote! { &[#namespace] },
+                    None => quote! { &#module ::sv:: #messages_fn_name() },
                 }
             })
             .collect()
     }
 
-    pub fn emit_deserialization_attempts(&self, msg_ty: &MsgType) -> Vec<TokenStream> {
+    /// Emits messages declared by the interfaces, reported by the MultiTest coverage.
+    pub fn emit_declared_messages(&self, msg_ty: &MsgType) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
         self.interfaces
             .iter()
             .map(|interface| {
                 let ContractMessageAttr {
-                    module, variant, ..
+                    module,
+                    variant,
+                    namespace,
+                    ..
                 } = interface;
+
                 let ep_name = msg_ty.emit_ep_name();
                 let messages_fn_name = Ident::new(&format!("{}_messages", ep_name), module.span());
+                let source = variant.to_string();
+                let namespace = match namespace {
+                    Some(namespace) => quote! { Some(#namespace) },
+                    None => quote! { None },
+                };
 
                 quote! {
-                    let msgs = &#module ::sv:: #messages_fn_name();
-                    if msgs.into_iter().any(|msg| msg == &recv_msg_name) {
-                        match val.deserialize_into() {
-                            Ok(msg) => return Ok(Self:: #variant (msg)),
-                            Err(err) => return Err(D::Error::custom(err)).map(Self:: #variant),
-                        };
+                    #sylvia ::multitest::coverage::DeclaredMessages {
+                        source: #source,
+                        namespace: #namespace,
+                        messages: &#module ::sv:: #messages_fn_name (),
                     }
                 }
             })
             .collect()
     }
 
+    /// Emits the names used to report message collisions between the interfaces.
+    pub fn emit_collision_names(&self) -> Vec<String> {
+        self.interfaces
+            .iter()
+            .map(|interface| interface.variant.to_string())
+            .collect()
+    }
+
+    /// Emits `NAMESPACES` of the `sylvia::types::ContractApi` listing the namespaced interfaces.
+    pub fn emit_namespaces(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let namespaces: Vec<_> = self
+            .interfaces
+            .iter()
+            .filter_map(|interface| {
+                let ContractMessageAttr {
+                    module, namespace, ..
+                } = interface;
+
+                namespace.as_ref().map(|namespace| {
+                    quote! { (<#module ::sv::InterfaceMarker as #sylvia ::types::InterfaceId>::ID, #namespace) }
+                })
+            })
+            .collect();
+
+        if namespaces.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            const NAMESPACES: &'static [(&'static str, &'static str)] = &[#(#namespaces),*];
+        }
+    }
+
+    /// Emits deserialization of the received message into the interface variants.
+    /// Namespaced interfaces deserialize only the payload nested under the namespace.
+    pub fn emit_variants_deserialization(&self) -> Vec<TokenStream> {
+        self.interfaces
+            .iter()
+            .map(|interface| {
+                let ContractMessageAttr {
+                    variant, namespace, ..
+                } = interface;
+
+                match namespace {
+                    Some(_) => quote! { msg.deserialize_payload().map(Self:: #variant) },
+                    None => quote! { msg.deserialize().map(Self:: #variant) },
+                }
+            })
+            .collect()
+    }
+
     pub fn emit_response_schemas_calls(
         &self,
         msg_ty: &MsgType,
@@ -111,25 +204,77 @@ impl Interfaces {
             .collect()
     }
 
-    pub fn emit_dispatch_arms(&self, msg_ty: &MsgType) -> Vec<TokenStream> {
+    /// Emits dispatch arms of the glue message. With `actions` set, the responses are tagged
+    /// with the `action` and `interface` attributes.
+    pub fn emit_dispatch_arms(&self, msg_ty: &MsgType, actions: bool) -> Vec<TokenStream> {
         let sylvia = crate_module();
         let contract_enum_name = msg_ty.emit_msg_wrapper_name();
 
         self.interfaces.iter().map(|interface| {
             let ContractMessageAttr {
+                module,
                 variant,
                 customs,
+                guard,
                 ..
             } = interface;
 
             let ctx = msg_ty.emit_ctx_dispatch_values(customs);
 
-            match (msg_ty, customs.has_msg) {
+            let dispatch = match (msg_ty, customs.has_msg) {
                 (MsgType::Exec, true) | (MsgType::Sudo, true) => quote! {
-                    #contract_enum_name:: #variant(msg) => #sylvia ::into_response::IntoResponse::into_response(msg.dispatch(contract, Into::into( #ctx ))?).map_err(Into::into)
+                    #sylvia ::into_response::IntoResponse::into_response(msg.dispatch(contract, Into::into( #ctx ))?).map_err(Into::into)
                 },
                 _ => quote! {
-                    #contract_enum_name :: #variant(msg) => msg.dispatch(contract, Into::into( #ctx ))
+                    msg.dispatch(contract, Into::into( #ctx ))
+                },
+            };
+            let dispatch = match actions {
+                true => {
+                    let interface_name = variant.to_string();
+                    quote! {
+                        {
+                            let action = msg.sv_msg_name();
+                            #dispatch.map(|resp| resp
+                                .add_attribute("action", action)
+                                .add_attribute("interface", #interface_name))
+                        }
+                    }
+                }
+                false => dispatch,
+            };
+
+            // Interface guard is called only for the `exec` messages. The predicate takes
+            // the `ExecCtx`, so guarding the interface with `sudo` messages is rejected.
+            match (msg_ty, guard) {
+                (MsgType::Exec, Some(guard)) => {
+                    let ctx_type = msg_ty.emit_ctx_struct_type();
+                    let guard = guard.emit_call();
+                    quote! {
+                        #contract_enum_name :: #variant(msg) => {
+                            let ctx: #ctx_type = Into::into(ctx);
+                            #guard
+                            let ctx = (ctx.deps, ctx.env, ctx.info);
+                            #dispatch
+                        }
+                    }
+                }
+                (MsgType::Sudo, Some(guard)) => {
+                    let assertion = quote_spanned! { guard.guard.span() =>
+                        const _: () = assert!(
+                            #module ::sv::sudo_messages().is_empty(),
+                            "Interface with `sudo` messages can't be guarded. Use `sv::guard` on its methods instead."
+                        );
+                    };
+                    quote! {
+                        #contract_enum_name :: #variant(msg) => {
+                            #assertion
+                            #dispatch
+                        }
+                    }
+                }
+                _ => quote! {
+                    #contract_enum_name :: #variant(msg) => #dispatch
                 },
             }
         }).collect()
@@ -1,5 +1,9 @@
 use crate::fold::StripSelfPath;
+use crate::parser::attributes::{
+    ArbitraryParams, DataFieldParams, MsgFieldAttr, MsgNaming, PayloadFieldParam,
+};
 use crate::parser::check_generics::{CheckGenerics, GetPath};
+use crate::parser::{ParsedSylviaAttributes, SylviaAttribute};
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
 use quote::quote;
@@ -11,10 +15,15 @@ use syn::{Attribute, Ident, Pat, PatType, Type};
 /// Representation of single message variant field
 #[derive(Debug)]
 pub struct MsgField<'a> {
-    name: &'a Ident,
+    name: Ident,
     ty: &'a Type,
     stripped_ty: Type,
-    attrs: &'a Vec<Attribute>,
+    attrs: Vec<&'a Attribute>,
+    data: Option<DataFieldParams>,
+    payload: Option<PayloadFieldParam>,
+    funds: bool,
+    arbitrary: Option<ArbitraryParams>,
+    naming: MsgNaming,
 }
 
 impl<'a> MsgField<'a> {
@@ -26,33 +35,32 @@ impl<'a> MsgField<'a> {
     where
         Generic: GetPath + PartialEq,
     {
-        let name = match &*item.pat {
-            Pat::Ident(p) => Some(&p.ident),
-            pat => {
-                // TODO: Support pattern arguments, when decorated with argument with item
-                // name
-                //
-                // Eg.
-                //
-                // ```
-                // fn exec_foo(&self, ctx: Ctx, #[sv::msg(name=metadata)] SomeData { addr, sender }: SomeData);
-                // ```
-                //
-                // should expand to enum variant:
-                //
-                // ```
-                // ExecFoo {
-                //   metadata: SomeDaa
-                // }
-                // ```
-                emit_error!(pat.span(), "Expected argument name, pattern occurred");
+        let MsgFieldAttr { name, naming } =
+            MsgFieldAttr::from_attrs(&item.attrs).unwrap_or_default();
+        let name = match (&*item.pat, name) {
+            (_, Some(name)) => Some(name),
+            (Pat::Ident(p), None) => Some(p.ident.clone()),
+            (pat, None) => {
+                emit_error!(
+                    pat.span(), "Expected argument name, pattern occurred";
+                    note = "Name the message field of the pattern argument with `#[sv::msg(name = field_name)]`"
+                );
                 None
             }
         }?;
 
         let ty = &item.ty;
         let stripped_ty = StripSelfPath.fold_type((*item.ty).clone());
-        let attrs = &item.attrs;
+        let sylvia_attrs = ParsedSylviaAttributes::new(
+            item.attrs
+                .iter()
+                .filter(|attr| !matches!(SylviaAttribute::new(attr), Some(SylviaAttribute::Msg))),
+        );
+        let attrs = item
+            .attrs
+            .iter()
+            .filter(|attr| SylviaAttribute::new(attr).is_none())
+            .collect();
         generics_checker.visit_type(&stripped_ty);
 
         Some(Self {
@@ -60,6 +68,11 @@ impl<'a> MsgField<'a> {
             ty,
             stripped_ty,
             attrs,
+            data: sylvia_attrs.data,
+            payload: sylvia_attrs.payload,
+            funds: sylvia_attrs.funds,
+            arbitrary: sylvia_attrs.arbitrary,
+            naming,
         })
     }
 
@@ -69,11 +82,14 @@ impl<'a> MsgField<'a> {
             name,
             stripped_ty,
             attrs,
+            naming,
             ..
         } = self;
+        let naming = naming.emit_serde_attrs(true);
 
         quote! {
             #(#attrs)*
+            #naming
             #name: #stripped_ty
         }
     }
@@ -84,11 +100,14 @@ impl<'a> MsgField<'a> {
             name,
             stripped_ty,
             attrs,
+            naming,
             ..
         } = self;
+        let naming = naming.emit_serde_attrs(true);
 
         quote! {
             #(#attrs)*
+            #naming
             pub #name: #stripped_ty
         }
     }
@@ -104,6 +123,15 @@ impl<'a> MsgField<'a> {
         }
     }
 
+    /// Emits the field generated from `arbitrary::Unstructured` in scope as `u`.
+    pub fn emit_arbitrary(&self) -> TokenStream {
+        let name = &self.name;
+        match &self.arbitrary {
+            Some(ArbitraryParams { with: Some(with) }) => quote! { #name: #with (u)? },
+            _ => quote! { #name: u.arbitrary()? },
+        }
+    }
+
     pub fn emit_method_field_folded(&self) -> TokenStream {
         let Self { name, ty, .. } = self;
 
@@ -112,7 +140,24 @@ impl<'a> MsgField<'a> {
         }
     }
 
-    pub fn name(&self) -> &'a Ident {
-        self.name
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &Type {
+        self.ty
+    }
+
+    pub fn data(&self) -> Option<&DataFieldParams> {
+        self.data.as_ref()
+    }
+
+    pub fn payload(&self) -> Option<&PayloadFieldParam> {
+        self.payload.as_ref()
+    }
+
+    /// Checks if the argument receives the amount paid to the `must_pay` message handler.
+    pub fn is_funds(&self) -> bool {
+        self.funds
     }
 }
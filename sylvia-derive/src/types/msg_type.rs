@@ -19,7 +19,8 @@ impl MsgType {
             Exec | Instantiate => quote! {
                 (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env, #sylvia ::cw_std::MessageInfo)
             },
-            Migrate | Reply | Sudo => quote! {
+            Migrate | Reply | Sudo | IbcChannelOpen | IbcChannelConnect | IbcChannelClose
+            | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => quote! {
                 (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env)
             },
             Query => quote! {
@@ -28,6 +29,28 @@ impl MsgType {
         }
     }
 
+    /// Emits the context type passed to the message handler, leaving the custom query to be
+    /// inferred.
+    pub fn emit_ctx_struct_type(self) -> TokenStream {
+        let sylvia = crate_module();
+        let ctx_name = match self {
+            Self::Exec => quote! { ExecCtx },
+            Self::Query => quote! { QueryCtx },
+            Self::Instantiate => quote! { InstantiateCtx },
+            Self::Migrate => quote! { MigrateCtx },
+            Self::Reply => quote! { ReplyCtx },
+            Self::Sudo => quote! { SudoCtx },
+            Self::IbcChannelOpen => quote! { IbcChannelOpenCtx },
+            Self::IbcChannelConnect => quote! { IbcChannelConnectCtx },
+            Self::IbcChannelClose => quote! { IbcChannelCloseCtx },
+            Self::IbcPacketReceive => quote! { IbcPacketReceiveCtx },
+            Self::IbcPacketAck => quote! { IbcPacketAckCtx },
+            Self::IbcPacketTimeout => quote! { IbcPacketTimeoutCtx },
+        };
+
+        quote! { #sylvia ::types:: #ctx_name <_> }
+    }
+
     pub fn emit_ctx_dispatch_values(self, customs: &Customs) -> TokenStream {
         use MsgType::*;
 
@@ -51,7 +74,8 @@ impl MsgType {
             Exec | Instantiate => quote! {
                 deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env, info: #sylvia ::cw_std::MessageInfo
             },
-            Migrate | Reply | Sudo => quote! {
+            Migrate | Reply | Sudo | IbcChannelOpen | IbcChannelConnect | IbcChannelClose
+            | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => quote! {
                 deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env
             },
             Query => quote! {
@@ -68,6 +92,12 @@ impl MsgType {
             Self::Sudo => parse_quote! { sudo },
             Self::Reply => parse_quote! { reply },
             Self::Query => parse_quote! { query },
+            Self::IbcChannelOpen => parse_quote! { ibc_channel_open },
+            Self::IbcChannelConnect => parse_quote! { ibc_channel_connect },
+            Self::IbcChannelClose => parse_quote! { ibc_channel_close },
+            Self::IbcPacketReceive => parse_quote! { ibc_packet_receive },
+            Self::IbcPacketAck => parse_quote! { ibc_packet_ack },
+            Self::IbcPacketTimeout => parse_quote! { ibc_packet_timeout },
         }
     }
 
@@ -76,7 +106,10 @@ impl MsgType {
 
         match self {
             Exec | Instantiate => quote! { deps, env, info },
-            Migrate | Reply | Query | Sudo => quote! { deps, env },
+            Migrate | Reply | Query | Sudo | IbcChannelOpen | IbcChannelConnect
+            | IbcChannelClose | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => {
+                quote! { deps, env }
+            }
         }
     }
 
@@ -95,6 +128,15 @@ impl MsgType {
             Query => quote! {
                 std::result::Result<#sylvia ::cw_std::Binary, #err_type>
             },
+            IbcChannelOpen => quote! {
+                std::result::Result<#sylvia ::cw_std::IbcChannelOpenResponse, #err_type>
+            },
+            IbcPacketReceive => quote! {
+                std::result::Result<#sylvia ::cw_std::IbcReceiveResponse <#msg_type>, #err_type>
+            },
+            IbcChannelConnect | IbcChannelClose | IbcPacketAck | IbcPacketTimeout => quote! {
+                std::result::Result<#sylvia ::cw_std::IbcBasicResponse <#msg_type>, #err_type>
+            },
         }
     }
 
@@ -115,6 +157,12 @@ impl MsgType {
             MsgType::Migrate => parse_quote! { MigrateMsg },
             MsgType::Reply => parse_quote! { ReplyMsg },
             MsgType::Sudo => parse_quote! { SudoMsg },
+            MsgType::IbcChannelOpen => parse_quote! { IbcChannelOpenMsg },
+            MsgType::IbcChannelConnect => parse_quote! { IbcChannelConnectMsg },
+            MsgType::IbcChannelClose => parse_quote! { IbcChannelCloseMsg },
+            MsgType::IbcPacketReceive => parse_quote! { IbcPacketReceiveMsg },
+            MsgType::IbcPacketAck => parse_quote! { IbcPacketAckMsg },
+            MsgType::IbcPacketTimeout => parse_quote! { IbcPacketTimeoutMsg },
         }
     }
 
@@ -135,6 +183,12 @@ impl MsgType {
             MsgType::Migrate => parse_quote! { Migrate },
             MsgType::Sudo => parse_quote! { Sudo },
             MsgType::Reply => parse_quote! { Reply },
+            MsgType::IbcChannelOpen => parse_quote! { IbcChannelOpen },
+            MsgType::IbcChannelConnect => parse_quote! { IbcChannelConnect },
+            MsgType::IbcChannelClose => parse_quote! { IbcChannelClose },
+            MsgType::IbcPacketReceive => parse_quote! { IbcPacketReceive },
+            MsgType::IbcPacketAck => parse_quote! { IbcPacketAck },
+            MsgType::IbcPacketTimeout => parse_quote! { IbcPacketTimeout },
         }
     }
 
@@ -174,7 +228,7 @@ impl MsgType {
         }
     }
 
-    pub fn emit_dispatch_leg(&self, function_name: &Ident, args: &Vec<Ident>) -> TokenStream {
+    pub fn emit_dispatch_leg(&self, function_name: &Ident, args: &[TokenStream]) -> TokenStream {
         use MsgType::*;
         let sylvia = crate_module();
 
@@ -185,7 +239,8 @@ impl MsgType {
             Query => quote! {
                 #sylvia ::cw_std::to_json_binary(&contract.#function_name(Into::into(ctx), #(#args),*)?).map_err(Into::into)
             },
-            Instantiate | Migrate | Reply => {
+            Instantiate | Migrate | Reply | IbcChannelOpen | IbcChannelConnect
+            | IbcChannelClose | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => {
                 emit_error!(function_name.span(), "Internal Error";
                 note = "Dispatch leg should be called only for `Enum` type messages.");
                 quote! {}
@@ -1,16 +1,18 @@
 use crate::crate_module;
 use crate::fold::StripSelfPath;
-use crate::parser::attributes::VariantAttrForwarding;
+use crate::parser::attributes::{FundsPolicy, GuardAttr, VariantAttrForwarding};
 use crate::parser::check_generics::{CheckGenerics, GetPath};
 use crate::parser::variant_descs::VariantDescs;
 use crate::parser::{process_fields, MsgAttr, MsgType};
-use crate::utils::{extract_return_type, filter_wheres, SvCasing};
+use crate::utils::{emit_bracketed_generics, extract_return_type, filter_wheres, SvCasing};
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote, ToTokens};
 use syn::fold::Fold;
+use syn::spanned::Spanned;
 use syn::visit::Visit;
-use syn::{parse_quote, Ident, Signature, Type, WhereClause, WherePredicate};
+use syn::{parse_quote, Ident, LitStr, Signature, Type, WhereClause, WherePredicate};
 
 use super::msg_field::MsgField;
 
@@ -19,12 +21,21 @@ use super::msg_field::MsgField;
 pub struct MsgVariant<'a> {
     name: Ident,
     function_name: &'a Ident,
+    /// Name of the message constructor and of the querier, executor and multitest proxy methods.
+    method_name: Ident,
+    /// Names under which the variant is deserialized. The first one is used for serialization.
+    msg_names: Vec<String>,
     fields: Vec<MsgField<'a>>,
+    /// Position of the `#[sv::funds]` argument receiving the amount paid to the `must_pay` handler.
+    /// The argument is not a part of the message.
+    funds_position: Option<usize>,
     /// Type extracted only in case of `Query` and used in `cosmwasm_schema::QueryResponses`
     /// `returns` attribute.
     return_type: Option<Type>,
     msg_type: MsgType,
+    msg_attr: MsgAttr,
     attrs_to_forward: Vec<VariantAttrForwarding>,
+    guards: Vec<GuardAttr>,
 }
 
 impl<'a> MsgVariant<'a> {
@@ -34,17 +45,30 @@ impl<'a> MsgVariant<'a> {
         generics_checker: &mut CheckGenerics<Generic>,
         msg_attr: MsgAttr,
         attrs_to_forward: Vec<VariantAttrForwarding>,
+        guards: Vec<GuardAttr>,
     ) -> MsgVariant<'a>
     where
         Generic: GetPath + PartialEq,
     {
         let function_name = &sig.ident;
 
-        let name = function_name.to_case(Case::UpperCamel);
-        let fields = process_fields(sig, generics_checker);
+        let (name, method_name) = match msg_attr.naming().and_then(|naming| naming.name.as_ref()) {
+            Some(msg_name) => (renamed_variant(msg_name, &msg_attr), function_name.clone()),
+            None => {
+                let name = function_name.to_case(Case::UpperCamel);
+                let method_name = name.to_case(Case::Snake);
+                (name, method_name)
+            }
+        };
+        let msg_names = msg_names(&name, &msg_attr);
+        let mut fields = process_fields(sig, generics_checker);
         let msg_type = msg_attr.msg_type();
+        let funds_position = fields.iter().position(MsgField::is_funds);
+        validate_funds_fields(&msg_attr, &fields);
+        fields.retain(|field| !field.is_funds());
+        validate_guards(msg_type, &guards);
 
-        let return_type = if let MsgAttr::Query { resp_type, .. } = msg_attr {
+        let return_type = if let MsgAttr::Query { resp_type, .. } = &msg_attr {
             match resp_type {
                 Some(resp_type) => {
                     let resp_type = parse_quote! { #resp_type };
@@ -65,10 +89,15 @@ impl<'a> MsgVariant<'a> {
         Self {
             name,
             function_name,
+            method_name,
+            msg_names,
             fields,
+            funds_position,
             return_type,
             msg_type,
+            msg_attr,
             attrs_to_forward,
+            guards,
         }
     }
 
@@ -80,15 +109,22 @@ impl<'a> MsgVariant<'a> {
             msg_type,
             return_type,
             attrs_to_forward,
+            msg_attr,
             ..
         } = self;
         let fields = fields.iter().map(MsgField::emit);
         let returns_attribute = msg_type.emit_returns_attribute(return_type);
         let attrs_to_forward = attrs_to_forward.iter().map(|attr| &attr.attrs);
+        // Variant is already named after the message, unless the name can't be expressed
+        // by the `snake_case` variant name.
+        let naming = msg_attr
+            .naming()
+            .map(|naming| naming.emit_serde_attrs(serde_snake_case(name) != self.msg_names[0]));
 
         quote! {
             #returns_attribute
             #( #[ #attrs_to_forward ] )*
+            #naming
             #name {
                 #(#fields,)*
             }
@@ -119,20 +155,82 @@ impl<'a> MsgVariant<'a> {
             .zip(args.clone())
             .map(|(field, num_field)| quote!(#field : #num_field));
 
+        let funds_check = self.emit_funds_check();
+        let guards = self.emit_guards();
+        let args = self.emit_call_args(&args);
         let method_call = msg_type.emit_dispatch_leg(function_name, &args);
 
         quote! {
             #name {
                 #(#fields,)*
-            } => #method_call
+            } => {
+                #funds_check
+                #guards
+                #method_call
+            }
+        }
+    }
+
+    /// Emits calls of the guards of this variant. Expects the `contract` and the `ctx` tuple to be
+    /// in scope. The `ctx` is converted into the context type of the message, so the guards can
+    /// borrow it before it's passed to the handler.
+    pub fn emit_guards(&self) -> TokenStream {
+        let Self {
+            msg_type, guards, ..
+        } = self;
+
+        if guards.is_empty() {
+            return quote! {};
+        }
+
+        let ctx_type = msg_type.emit_ctx_struct_type();
+        let guards = guards.iter().map(GuardAttr::emit_call);
+
+        quote! {
+            let ctx: #ctx_type = Into::into(ctx);
+            #(#guards)*
         }
     }
 
+    /// Emits validation of the funds sent along with the message against the funds policy of
+    /// this variant. Expects the `ctx` tuple with `MessageInfo` as its third element to be in
+    /// scope. Amount paid to the `must_pay` handler is assigned to the `paid_funds` variable.
+    pub fn emit_funds_check(&self) -> TokenStream {
+        let sylvia = crate_module();
+
+        match self.msg_attr.funds() {
+            Some(FundsPolicy::Nonpayable) => quote! {
+                #sylvia ::cw_utils::nonpayable(&ctx.2)?;
+            },
+            Some(FundsPolicy::MustPay(denom)) if self.funds_position.is_some() => quote! {
+                let paid_funds = #sylvia ::cw_utils::must_pay(&ctx.2, #denom)?;
+            },
+            Some(FundsPolicy::MustPay(denom)) => quote! {
+                #sylvia ::cw_utils::must_pay(&ctx.2, #denom)?;
+            },
+            Some(FundsPolicy::Payable) | None => quote! {},
+        }
+    }
+
+    /// Emits arguments of the method call, passing the `paid_funds` in place of the
+    /// `#[sv::funds]` argument.
+    pub fn emit_call_args(&self, args: &[impl ToTokens]) -> Vec<TokenStream> {
+        let mut args: Vec<_> = args.iter().map(ToTokens::to_token_stream).collect();
+        if let Some(position) = self.funds_position {
+            args.insert(position, quote! { paid_funds });
+        }
+        args
+    }
+
     /// Emits variants constructors. Constructors names are variants names in snake_case.
     pub fn emit_variants_constructors(&self) -> TokenStream {
-        let Self { name, fields, .. } = self;
+        let Self {
+            name,
+            fields,
+            method_name,
+            ..
+        } = self;
 
-        let method_name = name.to_case(Case::Snake);
         let parameters = fields.iter().map(MsgField::emit_method_field);
         let arguments = fields.iter().map(MsgField::name);
 
@@ -162,7 +260,11 @@ impl<'a> MsgVariant<'a> {
         self.function_name
     }
 
-    pub fn fields(&self) -> &Vec<MsgField> {
+    pub fn method_name(&self) -> &Ident {
+        &self.method_name
+    }
+
+    pub fn fields(&self) -> &Vec<MsgField<'_>> {
         &self.fields
     }
 
@@ -173,6 +275,131 @@ impl<'a> MsgVariant<'a> {
     pub fn return_type(&self) -> &Option<Type> {
         &self.return_type
     }
+
+    pub fn msg_attr(&self) -> &MsgAttr {
+        &self.msg_attr
+    }
+
+    /// Name under which the message is serialized.
+    pub fn msg_name(&self) -> &str {
+        &self.msg_names[0]
+    }
+}
+
+/// Creates the variant for the message renamed with `#[sv::msg(..., name = "...")]`.
+fn renamed_variant(msg_name: &LitStr, msg_attr: &MsgAttr) -> Ident {
+    let name = msg_name.value().to_case(Case::UpperCamel);
+    let Ok(name) = syn::parse_str::<Ident>(&name) else {
+        emit_error!(msg_name.span(), "Message name `{}` can't be expressed as an enum variant", msg_name.value();
+            note = "Use the name starting with a letter and consisting of letters, digits and `_`");
+        return Ident::new("InvalidName", msg_name.span());
+    };
+    let name = Ident::new(&name.to_string(), msg_name.span());
+
+    if msg_attr.msg_type() == MsgType::Query && serde_snake_case(&name) != msg_name.value() {
+        emit_error!(msg_name.span(), "Query message name `{}` is not in `snake_case`", msg_name.value();
+            note = "Names of query messages have to be in `snake_case` to match the `QueryResponses`.");
+    }
+
+    name
+}
+
+/// Collects names under which the variant is deserialized.
+fn msg_names(name: &Ident, msg_attr: &MsgAttr) -> Vec<String> {
+    let naming = msg_attr.naming();
+    let msg_name = naming
+        .and_then(|naming| naming.name.as_ref())
+        .map(LitStr::value)
+        .unwrap_or_else(|| name.to_string().to_case(Case::Snake));
+    let aliases = naming
+        .into_iter()
+        .flat_map(|naming| naming.aliases.iter().map(LitStr::value));
+
+    std::iter::once(msg_name).chain(aliases).collect()
+}
+
+/// Converts the variant name into the name serialized by `#[serde(rename_all = "snake_case")]`.
+fn serde_snake_case(name: &Ident) -> String {
+    name.to_string()
+        .chars()
+        .enumerate()
+        .fold(String::new(), |mut acc, (idx, ch)| {
+            if ch.is_uppercase() && idx != 0 {
+                acc.push('_');
+            }
+            acc.push(ch.to_ascii_lowercase());
+            acc
+        })
+}
+
+/// `#[sv::funds]` argument can be used only once and only with the `must_pay` funds policy.
+fn validate_funds_fields(msg_attr: &MsgAttr, fields: &[MsgField]) {
+    let mut funds_fields = fields.iter().filter(|field| field.is_funds());
+    let Some(first) = funds_fields.next() else {
+        return;
+    };
+
+    if let Some(second) = funds_fields.next() {
+        emit_error!(
+            second.name().span(), "More than one `#[sv::funds]` argument";
+            note = first.name().span() => "Previous `#[sv::funds]` argument defined here"
+        );
+    }
+
+    if !matches!(msg_attr.funds(), Some(FundsPolicy::MustPay(_))) {
+        emit_error!(
+            first.name().span(), "The `#[sv::funds]` argument requires the `must_pay` funds policy";
+            note = "Use `#[sv::msg(exec, must_pay = \"denom\")]` to receive the paid amount"
+        );
+    }
+}
+
+/// Guards are supported only for the messages sent by the users or the chain and changing the state.
+fn validate_guards(msg_type: MsgType, guards: &[GuardAttr]) {
+    if matches!(msg_type, MsgType::Exec | MsgType::Sudo | MsgType::Migrate) {
+        return;
+    }
+
+    for guard in guards {
+        emit_error!(
+            guard.guard.span(), "The attribute `sv::guard` is not supported for `{}`", msg_type.emit_ep_name();
+            note = "Guards can be used only on `exec`, `sudo` and `migrate` messages"
+        );
+    }
+}
+
+/// Type the query handlers are registered in by [MsgVariants::emit_query_handler_methods].
+#[derive(Clone, Copy)]
+pub enum QueryHandlersReceiver {
+    /// `sylvia::testing::QueryHandlers` built by value.
+    Builder,
+    /// `sylvia::multitest::MockContract` shared by reference.
+    Mock,
+}
+
+impl QueryHandlersReceiver {
+    /// Emits the receiver parameter, the return type of the methods and the bounds of the handler.
+    fn emit_signature_parts(self) -> (TokenStream, TokenStream, TokenStream) {
+        match self {
+            Self::Builder => (quote! { self }, quote! { Self }, quote! { 'static }),
+            Self::Mock => (
+                quote! { &self },
+                quote! { &Self },
+                quote! { Send + Sync + 'static },
+            ),
+        }
+    }
+
+    /// Emits the body of the method registering the `handler` of the `msg_name` query.
+    fn emit_register(self, msg_name: &str, handler: TokenStream) -> TokenStream {
+        match self {
+            Self::Builder => quote! { Self(self.0.on(#msg_name, #handler)) },
+            Self::Mock => quote! {
+                self.0.on_query(#msg_name, #handler);
+                self
+            },
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -199,6 +426,7 @@ where
             .filter_map(|variant_desc| {
                 let msg_attr: MsgAttr = variant_desc.attr_msg()?;
                 let attrs_to_forward = variant_desc.attrs_to_forward();
+                let guards = variant_desc.guards();
 
                 if msg_attr.msg_type() != msg_ty {
                     return None;
@@ -209,6 +437,7 @@ where
                     &mut generics_checker,
                     msg_attr,
                     attrs_to_forward,
+                    guards,
                 ))
             })
             .collect();
@@ -234,7 +463,7 @@ where
         }
     }
 
-    pub fn variants(&self) -> impl Iterator<Item = &MsgVariant> {
+    pub fn variants(&self) -> impl Iterator<Item = &MsgVariant<'_>> {
         self.variants.iter()
     }
 
@@ -267,13 +496,183 @@ where
             .map(|variant| variant.emit_dispatch_leg())
     }
 
-    pub fn as_names_snake_cased(&self) -> Vec<String> {
+    /// Names of all the messages including aliases, sorted as required by
+    /// `sylvia::utils::assert_no_intersection`.
+    pub fn as_msg_names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self
+            .variants
+            .iter()
+            .flat_map(|variant| variant.msg_names.iter().cloned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Emits the `sv_msg_name` method returning the name under which the message is serialized.
+    /// The `sv_` prefix keeps it from clashing with the constructors of the messages.
+    pub fn emit_msg_name_method(&self) -> TokenStream {
+        let legs = self.variants.iter().map(|variant| {
+            let name = &variant.name;
+            let msg_name = &variant.msg_names[0];
+            quote! { Self:: #name { .. } => #msg_name }
+        });
+        let phantom_leg = match self.used_generics.is_empty() {
+            true => quote! {},
+            false => quote! {
+                Self::_Phantom(_) => unreachable!("Phantom message should not be constructed."),
+            },
+        };
+
+        quote! {
+            pub fn sv_msg_name(&self) -> &'static str {
+                match *self {
+                    #(#legs,)*
+                    #phantom_leg
+                }
+            }
+        }
+    }
+
+    /// Emits the `arbitrary::Arbitrary` implementation of the message enum, choosing one
+    /// of the variants.
+    pub fn emit_arbitrary_impl(&self, enum_name: &Ident) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            variants,
+            used_generics,
+            where_predicates,
+            ..
+        } = self;
+
+        let variants_cnt = variants.len();
+        let variants_indexes = 0..variants_cnt;
+        let variants = variants.iter().map(|variant| {
+            let name = &variant.name;
+            let fields = variant.fields.iter().map(MsgField::emit_arbitrary);
+            quote! { Self:: #name { #(#fields,)* } }
+        });
+        let bracketed_used_generics = emit_bracketed_generics(used_generics);
+
+        // `choose_index` fails if there are no variants to choose from.
+        let body = match variants_cnt {
+            0 => quote! {
+                u.choose_index(0)?;
+                unreachable!()
+            },
+            _ => quote! {
+                Ok(match u.choose_index(#variants_cnt)? {
+                    #(#variants_indexes => #variants,)*
+                    _ => unreachable!(),
+                })
+            },
+        };
+
+        quote! {
+            impl<'sv_arb, #(#used_generics,)* > #sylvia ::arbitrary::Arbitrary<'sv_arb> for #enum_name #bracketed_used_generics
+            where
+                #(#where_predicates,)*
+                #(#used_generics: #sylvia ::arbitrary::Arbitrary<'sv_arb>,)*
+            {
+                fn arbitrary(u: &mut #sylvia ::arbitrary::Unstructured<'sv_arb>) -> #sylvia ::arbitrary::Result<Self> {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Emits the `with_*` and `on_*` methods registering the typed handlers of the `QueryMsg`
+    /// in the `receiver`.
+    pub fn emit_query_handler_methods(&self, receiver: QueryHandlersReceiver) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
         self.variants
             .iter()
-            .map(|variant| variant.name.to_string().to_case(Case::Snake))
+            .map(|variant| {
+                let MsgVariant {
+                    name,
+                    method_name,
+                    return_type,
+                    ..
+                } = variant;
+                let msg_name = variant.msg_name();
+                let with_name = format_ident!("with_{}", method_name);
+                let on_name = format_ident!("on_{}", method_name);
+                let with_doc = format!(" Answers every `{msg_name}` query with the given response.");
+                let on_doc =
+                    format!(" Answers the `{msg_name}` queries with the result of the handler.");
+                let fields_names = variant.as_fields_names();
+                let fields_types = variant.fields.iter().map(MsgField::ty);
+
+                let with_handler = quote! {
+                    move |_| #sylvia ::cw_std::to_json_binary(&response)
+                };
+                let on_handler = quote! {
+                    move |msg| match msg.clone() {
+                        QueryMsg:: #name { #(#fields_names),* } => #sylvia ::cw_std::to_json_binary(&handler( #(#fields_names),* )?),
+                        #[allow(unreachable_patterns)]
+                        _ => unreachable!(),
+                    }
+                };
+                let (self_param, self_type, bounds) = receiver.emit_signature_parts();
+                let with_body = receiver.emit_register(msg_name, with_handler);
+                let on_body = receiver.emit_register(msg_name, on_handler);
+
+                quote! {
+                    #[doc = #with_doc]
+                    pub fn #with_name (#self_param, response: #return_type) -> #self_type {
+                        #with_body
+                    }
+
+                    #[doc = #on_doc]
+                    pub fn #on_name (
+                        #self_param,
+                        handler: impl Fn( #(#fields_types),* ) -> #sylvia ::cw_std::StdResult< #return_type > + #bounds,
+                    ) -> #self_type {
+                        #on_body
+                    }
+                }
+            })
             .collect()
     }
 
+    /// Emits the `QueryHandlers` type registering the typed handlers of the `QueryMsg` for
+    /// unit tests with `cosmwasm_std::testing::MockQuerier`.
+    ///
+    /// The handlers match the plain `QueryMsg`, so the queries of a namespaced interface are
+    /// not answered by the interface handlers.
+    pub fn emit_query_handlers(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let methods = self.emit_query_handler_methods(QueryHandlersReceiver::Builder);
+
+        quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            pub struct QueryHandlers(#sylvia ::testing::QueryHandlers<QueryMsg>);
+
+            #[cfg(not(target_arch = "wasm32"))]
+            impl QueryHandlers {
+                pub fn new() -> Self {
+                    Self(#sylvia ::testing::QueryHandlers::new(QueryMsg::sv_msg_name))
+                }
+
+                #(#methods)*
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            impl Default for QueryHandlers {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            impl #sylvia ::testing::SmartQueryHandler for QueryHandlers {
+                fn handle(&self, msg: &[u8]) -> Option<#sylvia ::cw_std::StdResult<#sylvia ::cw_std::Binary>> {
+                    #sylvia ::testing::SmartQueryHandler::handle(&self.0, msg)
+                }
+            }
+        }
+    }
+
     pub fn emit_constructors(&self) -> impl Iterator<Item = TokenStream> + '_ {
         self.variants
             .iter()
@@ -284,7 +683,7 @@ where
         self.variants.iter().map(MsgVariant::emit)
     }
 
-    pub fn get_only_variant(&self) -> Option<&MsgVariant> {
+    pub fn get_only_variant(&self) -> Option<&MsgVariant<'_>> {
         self.variants.first()
     }
 
@@ -87,6 +87,15 @@ pub fn emit_bracketed_generics<GenericT: ToTokens>(unbonded_generics: &[GenericT
     }
 }
 
+/// Emits the error type returned by the generated `Querier` methods.
+pub fn emit_query_error_type(typed_errors: bool) -> TokenStream {
+    let sylvia = crate::crate_module();
+    match typed_errors {
+        true => quote! { #sylvia ::types::QueryError },
+        false => quote! { #sylvia ::cw_std::StdError },
+    }
+}
+
 /// Trait for converting `Ident` to different cases preserving original [proc_macro2::Span].
 pub trait SvCasing {
     fn to_case(&self, case: convert_case::Case) -> Self;
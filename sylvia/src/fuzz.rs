@@ -0,0 +1,111 @@
+//! Utilities for the property-based and fuzz testing of the generated messages.
+//!
+//! With the `arbitrary` feature enabled, the messages generated by the `contract` and
+//! `interface` macros marked with `#[sv::arbitrary]` implement [arbitrary::Arbitrary].
+//! The interfaces of the contract have to be marked as well. The fields of types not
+//! implementing it, like [Addr] or [Uint128], use one of the generators provided here:
+//!
+//! ```rust,ignore
+//! #[sv::msg(exec)]
+//! fn transfer(
+//!     &self,
+//!     ctx: ExecCtx,
+//!     #[sv::arbitrary(with = sylvia::fuzz::addr)] recipient: Addr,
+//!     #[sv::arbitrary(with = sylvia::fuzz::uint128)] amount: Uint128,
+//! ) -> StdResult<Response>;
+//! ```
+//!
+//! The messages can be generated by any engine consuming [arbitrary::Arbitrary], like
+//! `cargo fuzz`, `proptest` with `proptest-arbitrary-interop` or the multitest
+//! [Fuzz](crate::multitest::fuzz::Fuzz) driver.
+
+use std::fmt::Debug;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{from_json, to_json_vec, Addr, Binary, Coin, Uint128, Uint64};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Number of the addresses [addr] picks from.
+pub const ADDR_POOL_SIZE: u8 = 8;
+
+/// Denominations [coins] picks from.
+pub const DENOMS: [&str; 2] = ["uatom", "uosmo"];
+
+/// Returns the address of the pool [addr] picks from.
+///
+/// Useful to pass the same addresses as the senders of the fuzzed messages.
+pub fn pool_addr(index: u8) -> Addr {
+    MockApi::default().addr_make(&format!("addr{}", index % ADDR_POOL_SIZE))
+}
+
+/// Generates a valid address from a small pool, so the messages refer to the same accounts.
+pub fn addr(u: &mut Unstructured) -> Result<Addr> {
+    u.int_in_range(0..=ADDR_POOL_SIZE - 1).map(pool_addr)
+}
+
+pub fn uint128(u: &mut Unstructured) -> Result<Uint128> {
+    u.arbitrary().map(Uint128::new)
+}
+
+pub fn uint64(u: &mut Unstructured) -> Result<Uint64> {
+    u.arbitrary().map(Uint64::new)
+}
+
+pub fn binary(u: &mut Unstructured) -> Result<Binary> {
+    u.arbitrary().map(Binary::new)
+}
+
+/// Generates up to three coins of the [DENOMS].
+pub fn coins(u: &mut Unstructured) -> Result<Vec<Coin>> {
+    let len = u.int_in_range(0..=3)?;
+    (0..len)
+        .map(|_| {
+            let denom = u.choose(&DENOMS)?;
+            Ok(Coin::new(uint128(u)?, *denom))
+        })
+        .collect()
+}
+
+/// Checks that the message is the same after being serialized to JSON and deserialized back.
+pub fn check_round_trip<T>(msg: &T) -> std::result::Result<(), String>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = to_json_vec(msg).map_err(|err| format!("Serializing {msg:?} failed: {err}"))?;
+    let deserialized: T = from_json(&json).map_err(|err| {
+        format!(
+            "Deserializing {msg:?} from {} failed: {err}",
+            String::from_utf8_lossy(&json)
+        )
+    })?;
+    match &deserialized == msg {
+        true => Ok(()),
+        false => Err(format!(
+            "Message {msg:?} deserialized as {deserialized:?} from {}",
+            String::from_utf8_lossy(&json)
+        )),
+    }
+}
+
+/// Generates the message from the raw `data` and panics if it doesn't survive the
+/// serialization round-trip.
+///
+/// Meant to be the body of the `cargo fuzz` target:
+///
+/// ```rust,ignore
+/// fuzz_target!(|data: &[u8]| {
+///     sylvia::fuzz::round_trip::<ContractExecMsg<MyContract>>(data);
+/// });
+/// ```
+pub fn round_trip<T>(data: &[u8])
+where
+    T: for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    if let Ok(msg) = T::arbitrary(&mut Unstructured::new(data)) {
+        if let Err(err) = check_round_trip(&msg) {
+            panic!("{err}");
+        }
+    }
+}
@@ -0,0 +1,506 @@
+//! Module providing the deserialization of the glue messages generated by the
+//! [contract](crate::contract) macro.
+//!
+//! The glue message reads the name of the received message once, finds the variant handling
+//! it in a lookup table sorted at compile time and deserializes the payload straight from the
+//! input into the type of the variant.
+//!
+//! Message not handled by any of the variants is rejected with the [UnknownMessage] error.
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::value::StringDeserializer;
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, Error, IgnoredAny, IntoDeserializer, MapAccess,
+    VariantAccess, Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer};
+
+/// Glue message composed of the variants handling messages of the contract and its interfaces.
+pub trait GlueMessage<'de>: Sized {
+    /// Names of the messages handled by each of the variants, in order of the variants.
+    fn messages() -> &'static [&'static [&'static str]];
+
+    /// Names of the interfaces and the contract handled by the variants, in order of the variants.
+    fn variants() -> &'static [&'static str];
+
+    /// Names of the messages paired with the index of the variant handling them,
+    /// sorted by the name. Built with [sorted_lookup].
+    fn lookup() -> &'static [(&'static str, usize)];
+
+    /// Deserializes the received message into the variant of the given index.
+    fn deserialize_variant<D: Deserializer<'de>>(
+        index: usize,
+        msg: ReceivedMsg<D>,
+    ) -> Result<Self, D::Error>;
+}
+
+/// Message which name was already read from the input, with the payload not read yet.
+pub struct ReceivedMsg<D> {
+    name: String,
+    payload: D,
+}
+
+impl<'de, D: Deserializer<'de>> ReceivedMsg<D> {
+    /// Deserializes the whole message, e.g. `{"name": {...}}`.
+    pub fn deserialize<T: Deserialize<'de>>(self) -> Result<T, D::Error> {
+        T::deserialize(self)
+    }
+
+    /// Deserializes only the payload of the message, e.g. `{...}` of `{"name": {...}}`.
+    pub fn deserialize_payload<T: Deserialize<'de>>(self) -> Result<T, D::Error> {
+        T::deserialize(self.payload)
+    }
+}
+
+/// Presents the message as the externally tagged enum with the name as the tag.
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for ReceivedMsg<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, D: Deserializer<'de>> EnumAccess<'de> for ReceivedMsg<D> {
+    type Error = D::Error;
+    type Variant = Payload<D>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let name: StringDeserializer<D::Error> = self.name.into_deserializer();
+        let variant = seed.deserialize(name)?;
+        Ok((variant, Payload(self.payload)))
+    }
+}
+
+/// Payload of the message deserialized as the content of the enum variant.
+#[doc(hidden)]
+pub struct Payload<D>(D);
+
+impl<'de, D: Deserializer<'de>> VariantAccess<'de> for Payload<D> {
+    type Error = D::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Deserialize::deserialize(self.0)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self.0)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_struct("", fields, visitor)
+    }
+}
+
+/// Deserializes the glue message `T`.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: GlueMessage<'de>,
+{
+    deserializer.deserialize_any(GlueVisitor(PhantomData))
+}
+
+struct GlueVisitor<T>(PhantomData<T>);
+
+impl<T> GlueVisitor<T> {
+    fn wrong_format<E: Error>(self) -> Result<T, E> {
+        Err(E::custom("Wrong message format!"))
+    }
+}
+
+/// Deserializes the payload of the message straight into the variant of the given index.
+struct VariantSeed<T> {
+    index: usize,
+    name: String,
+    glue: PhantomData<T>,
+}
+
+impl<'de, T: GlueMessage<'de>> DeserializeSeed<'de> for VariantSeed<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, payload: D) -> Result<T, D::Error> {
+        let msg = ReceivedMsg {
+            name: self.name,
+            payload,
+        };
+        T::deserialize_variant(self.index, msg)
+    }
+}
+
+impl<'de, T: GlueMessage<'de>> Visitor<'de> for GlueVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map with exactly one message")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let Some(name) = map.next_key::<String>()? else {
+            return Err(A::Error::custom("Expected exactly one message. Received 0"));
+        };
+
+        let Some(index) = find(T::lookup(), &name) else {
+            map.next_value::<IgnoredAny>()?;
+            ensure_single(count_remaining(&mut map)?)?;
+            return Err(A::Error::custom(UnknownMessage::new(
+                name,
+                T::messages(),
+                T::variants(),
+            )));
+        };
+
+        let msg = map.next_value_seed(VariantSeed {
+            index,
+            name,
+            glue: PhantomData,
+        })?;
+        ensure_single(count_remaining(&mut map)?)?;
+        Ok(msg)
+    }
+
+    fn visit_bool<E: Error>(self, _: bool) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_i64<E: Error>(self, _: i64) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_u64<E: Error>(self, _: u64) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_f64<E: Error>(self, _: f64) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_str<E: Error>(self, _: &str) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_none<E: Error>(self) -> Result<T, E> {
+        self.wrong_format()
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, _: A) -> Result<T, A::Error> {
+        self.wrong_format()
+    }
+}
+
+fn find(lookup: &[(&str, usize)], name: &str) -> Option<usize> {
+    lookup
+        .binary_search_by(|(msg, _)| (*msg).cmp(name))
+        .ok()
+        .map(|position| lookup[position].1)
+}
+
+fn ensure_single<E: Error>(remaining: usize) -> Result<(), E> {
+    match remaining {
+        0 => Ok(()),
+        _ => Err(E::custom(format!(
+            "Expected exactly one message. Received {}",
+            remaining + 1
+        ))),
+    }
+}
+
+fn count_remaining<'de, A: MapAccess<'de>>(map: &mut A) -> Result<usize, A::Error> {
+    let mut remaining = 0;
+    while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {
+        remaining += 1;
+    }
+    Ok(remaining)
+}
+
+/// Error returned when the received message is not handled by any of the variants
+/// of the glue message.
+///
+/// Deserialization through [from_json](crate::cw_std::from_json) reports it as the `msg` of
+/// [StdError::ParseErr](crate::cw_std::StdError::ParseErr). Its format is stable, so clients can match on it:
+///
+/// ```text
+/// Unknown message `transfr`. Closest known message: `transfer`. Checked: Cw20, MyContract
+/// ```
+///
+/// If the contract handles no messages, the closest message is reported as `none`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMessage {
+    /// Name of the received message.
+    pub received: String,
+    /// Known message with the smallest edit distance to the received one.
+    pub closest: Option<&'static str>,
+    /// Names of the interfaces and the contract which messages were checked.
+    pub checked: &'static [&'static str],
+}
+
+impl UnknownMessage {
+    /// Finds the closest of the `messages` to the `received` one.
+    pub fn new(
+        received: String,
+        messages: &'static [&'static [&'static str]],
+        checked: &'static [&'static str],
+    ) -> Self {
+        // Ties are resolved in favor of the message of the earlier variant.
+        let closest = messages
+            .iter()
+            .copied()
+            .flatten()
+            .copied()
+            .min_by_key(|msg| edit_distance(&received, msg));
+
+        Self {
+            received,
+            closest,
+            checked,
+        }
+    }
+}
+
+impl fmt::Display for UnknownMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown message `{}`. ", self.received)?;
+        match self.closest {
+            Some(closest) => write!(f, "Closest known message: `{closest}`. ")?,
+            None => write!(f, "Closest known message: none. ")?,
+        }
+        write!(f, "Checked: {}", self.checked.join(", "))
+    }
+}
+
+impl std::error::Error for UnknownMessage {}
+
+/// Levenshtein distance between two names, counted in characters.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut row: Vec<usize> = (0..=rhs.len()).collect();
+
+    for (i, lhs_char) in lhs.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, rhs_char) in rhs.iter().enumerate() {
+            let substitution = diagonal + usize::from(lhs_char != *rhs_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[rhs.len()]
+}
+
+/// Total number of the messages handled by the glue message.
+pub const fn messages_count(msgs: &[&[&str]]) -> usize {
+    let mut count = 0;
+    konst::for_range! {i in 0..msgs.len() =>
+        count += msgs[i].len();
+    }
+    count
+}
+
+/// Builds the lookup table of the glue message at compile time.
+///
+/// Pairs each of the messages with the index of its array in `msgs` and sorts the pairs by
+/// the message name. `LEN` has to be equal to [messages_count] of `msgs`.
+/// ```
+///     const MSGS: [&[&str]; 2] = [&["msg_c", "msg_d"], &["msg_a", "msg_e"]];
+///     const LOOKUP: [(&str, usize); sylvia::glue::messages_count(&MSGS)] =
+///         sylvia::glue::sorted_lookup(&MSGS);
+///
+///     assert_eq!(LOOKUP, [("msg_a", 1), ("msg_c", 0), ("msg_d", 0), ("msg_e", 1)]);
+/// ```
+pub const fn sorted_lookup<const LEN: usize>(
+    msgs: &[&[&'static str]],
+) -> [(&'static str, usize); LEN] {
+    assert!(
+        messages_count(msgs) == LEN,
+        "Lookup length has to be equal to the number of messages!"
+    );
+
+    let mut lookup = [("", 0); LEN];
+    let mut len = 0;
+    konst::for_range! {i in 0..msgs.len() =>
+        konst::for_range! {j in 0..msgs[i].len() =>
+            // Insertion sort, moving greater names one position right.
+            let mut position = len;
+            while position > 0
+                && konst::cmp_str(lookup[position - 1].0, msgs[i][j]).is_gt()
+            {
+                lookup[position] = lookup[position - 1];
+                position -= 1;
+            }
+            lookup[position] = (msgs[i][j], i);
+            len += 1;
+        }
+    }
+    lookup
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum ExecMsg {
+        Increment { value: u32 },
+        Reset {},
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum OtherMsg {
+        Noop {},
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Glue {
+        Exec(ExecMsg),
+        Namespaced(OtherMsg),
+    }
+
+    impl<'de> GlueMessage<'de> for Glue {
+        fn messages() -> &'static [&'static [&'static str]] {
+            &[&["increment", "reset"], &["other"]]
+        }
+
+        fn variants() -> &'static [&'static str] {
+            &["Exec", "Namespaced"]
+        }
+
+        fn lookup() -> &'static [(&'static str, usize)] {
+            const MSGS: [&[&str]; 2] = [&["increment", "reset"], &["other"]];
+            const LOOKUP: [(&str, usize); messages_count(&MSGS)] = sorted_lookup(&MSGS);
+            &LOOKUP
+        }
+
+        fn deserialize_variant<D: Deserializer<'de>>(
+            index: usize,
+            msg: ReceivedMsg<D>,
+        ) -> Result<Self, D::Error> {
+            match index {
+                0 => msg.deserialize().map(Self::Exec),
+                _ => msg.deserialize_payload().map(Self::Namespaced),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Glue {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize(deserializer)
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(
+            Glue::lookup(),
+            [("increment", 0), ("other", 1), ("reset", 0)]
+        );
+        assert_eq!(find(Glue::lookup(), "reset"), Some(0));
+        assert_eq!(find(Glue::lookup(), "other"), Some(1));
+        assert_eq!(find(Glue::lookup(), "unknown"), None);
+    }
+
+    #[test]
+    fn deserialize_variants() {
+        let msg: Glue = serde_json_wasm::from_str(r#"{"increment":{"value":1}}"#).unwrap();
+        assert_eq!(msg, Glue::Exec(ExecMsg::Increment { value: 1 }));
+
+        let msg: Glue = serde_json_wasm::from_str(r#"{"reset":{}}"#).unwrap();
+        assert_eq!(msg, Glue::Exec(ExecMsg::Reset {}));
+
+        let msg: Glue = serde_json_wasm::from_str(r#"{"other":{"noop":{}}}"#).unwrap();
+        assert_eq!(msg, Glue::Namespaced(OtherMsg::Noop {}));
+    }
+
+    #[test]
+    fn deserialization_errors() {
+        let err = serde_json_wasm::from_str::<Glue>(r#"[]"#).unwrap_err();
+        assert_eq!(err.to_string(), "Wrong message format!");
+
+        let err = serde_json_wasm::from_str::<Glue>(r#"{}"#).unwrap_err();
+        assert_eq!(err.to_string(), "Expected exactly one message. Received 0");
+
+        let err = serde_json_wasm::from_str::<Glue>(r#"{"reset":{},"increment":{"value":1}}"#)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Expected exactly one message. Received 2");
+
+        let err = serde_json_wasm::from_str::<Glue>(r#"{"incremnt":{"value":1}}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown message `incremnt`. Closest known message: `increment`. Checked: Exec, Namespaced"
+        );
+
+        let err = serde_json_wasm::from_str::<Glue>(r#"{"ohter":{},"reset":{}}"#).unwrap_err();
+        assert_eq!(err.to_string(), "Expected exactly one message. Received 2");
+    }
+
+    #[test]
+    fn payload_errors() {
+        let err =
+            serde_json_wasm::from_str::<Glue>(r#"{"increment":{"value":"one"}}"#).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid type");
+
+        let err = serde_json_wasm::from_str::<Glue>(r#"{"increment":{}}"#).unwrap_err();
+        assert_eq!(err.to_string(), "missing field `value`");
+
+        let err = serde_json_wasm::from_str::<Glue>(r#"{"other":{"nop":{}}}"#).unwrap_err();
+        assert_eq!(err.to_string(), "unknown variant `nop`, expected `noop`");
+
+        // The payload is deserialized as it is read, before the other messages are counted.
+        let err = serde_json_wasm::from_str::<Glue>(r#"{"increment":{"value":"one"},"reset":{}}"#)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Invalid type");
+    }
+
+    #[test]
+    fn unknown_message() {
+        let err = UnknownMessage::new("rest".to_owned(), Glue::messages(), Glue::variants());
+        assert_eq!(err.closest, Some("reset"));
+
+        let err = UnknownMessage::new("msg".to_owned(), &[], &["Contract"]);
+        assert_eq!(
+            err.to_string(),
+            "Unknown message `msg`. Closest known message: none. Checked: Contract"
+        );
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("reset", "reset"), 0);
+        assert_eq!(edit_distance("rest", "reset"), 1);
+        assert_eq!(edit_distance("increment", "decrement"), 2);
+        assert_eq!(edit_distance("", "other"), 5);
+    }
+}
@@ -3,16 +3,31 @@
 //!
 //! Most of implementation lies in `sylvia-derive` crate which is reexported here
 
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+#[cfg(all(feature = "arbitrary", not(target_arch = "wasm32")))]
+pub mod fuzz;
+pub mod glue;
 pub mod into_response;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub mod multitest;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod testing;
 pub mod types;
 pub mod utils;
+#[cfg_attr(docsrs, doc(cfg(feature = "cw2")))]
+#[cfg(feature = "cw2")]
+pub mod version;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use anyhow;
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+#[cfg_attr(docsrs, doc(cfg(feature = "cw2")))]
+#[cfg(feature = "cw2")]
+pub use cw2;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use cw_multi_test;
@@ -22,7 +37,10 @@ pub use cw_utils;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use derivative;
-pub use sylvia_derive::{contract, entry_points, interface};
+#[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
+#[cfg(feature = "mt")]
+pub use sylvia_derive::module;
+pub use sylvia_derive::{contract, entry_points, interface, TypedEvent};
 pub use {
     cosmwasm_schema as cw_schema, cosmwasm_std as cw_std, schemars, serde,
     serde_cw_value as serde_value, serde_json_wasm as serde_json,
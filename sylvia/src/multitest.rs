@@ -49,6 +49,7 @@
 //!
 //! # fn main() {}
 //! ```
+#![allow(clippy::test_attr_in_doctest)]
 
 use std::cell::{Ref, RefCell, RefMut};
 use std::fmt::{Debug, Display};
@@ -68,7 +69,30 @@ use cw_multi_test::{
 use derivative::Derivative;
 use serde::Serialize;
 
-use crate::types::{CustomMsg, CustomQuery};
+use crate::types::{CustomMsg, CustomQuery, NamespacedMsg};
+
+mod app_builder;
+pub mod coverage;
+mod errors;
+mod events;
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+mod invariants;
+mod mock;
+pub mod module;
+mod snapshot;
+mod transaction;
+
+pub use app_builder::{AppBuilder, BasicAppBuilder};
+use coverage::{ContractMessages, Coverage, CoverageRecorder, CoverageReport};
+pub use errors::{FailedContract, MtError};
+pub use events::TypedEvents;
+pub use invariants::InvariantViolation;
+use invariants::InvariantsCheck;
+pub use mock::{MockCodeId, MockContract, ReceivedMsg, ReceivedSudoMsg, StoreCode};
+pub use snapshot::{AppSnapshot, AppState};
+pub use transaction::{Transaction, TransactionMsg};
 
 /// Proxy to interact with a smart contract initialized on the [App].
 #[derive(Derivative)]
@@ -101,6 +125,8 @@ impl<'app, MtApp, Contract> From<(cosmwasm_std::Addr, &'app App<MtApp>)>
 /// Wrapper around `cw_multi_test::App` to provide additional functionalities.
 pub struct App<MtApp> {
     app: RefCell<MtApp>,
+    invariants: RefCell<Vec<InvariantsCheck<MtApp>>>,
+    coverage: Option<Coverage>,
 }
 
 impl<MtApp> Default for App<MtApp>
@@ -133,9 +159,7 @@ impl<ExecC, QueryC> App<cw_multi_test::BasicApp<ExecC, QueryC>> {
             &mut dyn Storage,
         ),
     {
-        App {
-            app: RefCell::new(cw_multi_test::custom_app(init_fn)),
-        }
+        Self::new(cw_multi_test::custom_app(init_fn))
     }
 }
 
@@ -143,9 +167,35 @@ impl<MtApp> App<MtApp> {
     pub fn new(app: MtApp) -> Self {
         Self {
             app: RefCell::new(app),
+            invariants: RefCell::default(),
+            coverage: None,
         }
     }
 
+    /// Enables recording of the messages received by the contracts, reported with
+    /// [App::coverage_report].
+    ///
+    /// Only the contracts of the codes stored after enabling the coverage are recorded.
+    pub fn with_coverage(mut self) -> Self {
+        self.coverage = Some(Coverage::default());
+        self
+    }
+
+    /// Starts recording the messages received by the contract, if the coverage is enabled.
+    ///
+    /// Used by the generated `CodeId::store_code` to wrap the stored contract in the
+    /// [CoveredContract](coverage::CoveredContract).
+    pub fn coverage_recorder(&self, messages: ContractMessages) -> Option<CoverageRecorder> {
+        self.coverage
+            .as_ref()
+            .map(|coverage| coverage.recorder(messages))
+    }
+
+    /// Lists the messages never received by the contracts, if the coverage is enabled.
+    pub fn coverage_report(&self) -> Option<CoverageReport> {
+        self.coverage.as_ref().map(Coverage::report)
+    }
+
     /// Immutable borrow on the underlying `cw_multi_test::App`.
     pub fn app(&self) -> Ref<'_, MtApp> {
         Ref::map(self.app.borrow(), |app| app)
@@ -213,6 +263,61 @@ where
     pub fn querier(&self) -> QuerierWrapper<'_, CustomT::QueryT> {
         QuerierWrapper::new(self)
     }
+
+    /// Calls the `entry_point` of the contract instantiated at `contract_addr` with its
+    /// `DepsMut` and `Env`.
+    ///
+    /// Used by the generated proxies for the IBC entry points, which `cw_multi_test` doesn't
+    /// route. Changes made to the contract storage are kept only if the `entry_point` succeeds.
+    /// Messages in the returned response are not executed.
+    #[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+    #[cfg(feature = "stargate")]
+    pub fn call_entry_point<Q, T>(
+        &self,
+        contract_addr: &Addr,
+        entry_point: impl FnOnce(cosmwasm_std::DepsMut<Q>, cosmwasm_std::Env) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T>
+    where
+        Q: cosmwasm_std::CustomQuery,
+    {
+        let mut storage = cosmwasm_std::MemoryStorage::new();
+        let (original, result) = {
+            let app = self.app();
+            app.contract_data(contract_addr)?;
+            let original = app.dump_wasm_raw(contract_addr);
+            for (key, value) in &original {
+                storage.set(key, value);
+            }
+
+            let deps = cosmwasm_std::DepsMut {
+                storage: &mut storage,
+                api: app.api(),
+                querier: QuerierWrapper::new(self),
+            };
+            let env = cosmwasm_std::Env {
+                block: app.block_info(),
+                transaction: Some(cosmwasm_std::TransactionInfo { index: 0 }),
+                contract: cosmwasm_std::ContractInfo {
+                    address: contract_addr.clone(),
+                },
+            };
+            (original, entry_point(deps, env)?)
+        };
+
+        let mut app = self.app_mut();
+        let mut contract_storage = app.contract_storage_mut(contract_addr);
+        for (key, _) in original {
+            contract_storage.remove(&key);
+        }
+        for (key, value) in storage.range(None, None, cosmwasm_std::Order::Ascending) {
+            contract_storage.set(&key, &value);
+        }
+        drop(contract_storage);
+        drop(app);
+
+        self.try_check_invariants(contract_addr)?;
+        Ok(result)
+    }
 }
 
 impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT> Querier
@@ -259,6 +364,7 @@ where
 {
     funds: &'a [Coin],
     contract_addr: &'a Addr,
+    namespace: Option<&'a str>,
     msg: Msg,
     app: &'app App<MtApp>,
     phantom: PhantomData<(Error, ExecC)>,
@@ -275,6 +381,7 @@ where
         Self {
             funds: &[],
             contract_addr,
+            namespace: None,
             msg,
             app,
             phantom: PhantomData,
@@ -286,18 +393,55 @@ where
         Self { funds, ..self }
     }
 
+    /// Nests the execute message under the namespace of the interface.
+    /// Used by the generated interface proxies.
+    pub fn with_namespace(self, namespace: Option<&'a str>) -> Self {
+        Self { namespace, ..self }
+    }
+
     /// Sends the execute message to the contract.
+    ///
+    /// Panics if the message fails with an error other than `Error`
+    /// or violates the invariants of the contract.
+    /// Use [ExecProxy::try_call] to handle the errors of the other contracts and modules.
     #[track_caller]
     pub fn call(self, sender: &'a Addr) -> Result<cw_multi_test::AppResponse, Error> {
+        let resp = self.send(sender).map_err(MtError::into_contract_error)?;
+        self.app.check_invariants(self.contract_addr, &self.msg);
+        Ok(resp)
+    }
+
+    /// Sends the execute message to the contract, returning any failure as [MtError].
+    #[track_caller]
+    pub fn try_call(self, sender: &'a Addr) -> Result<cw_multi_test::AppResponse, MtError<Error>> {
+        let resp = self.send(sender)?;
+        self.app
+            .try_check_invariants(self.contract_addr)
+            .map_err(MtError::InvariantViolated)?;
+        Ok(resp)
+    }
+
+    fn send(&self, sender: &Addr) -> Result<cw_multi_test::AppResponse, MtError<Error>> {
+        let msg = NamespacedMsg::new(self.namespace, &self.msg);
         (*self.app)
             .app_mut()
             .execute_contract(
                 sender.clone(),
                 Addr::unchecked(self.contract_addr),
-                &self.msg,
+                &msg,
                 self.funds,
             )
-            .map_err(|err| err.downcast().unwrap())
+            .map_err(|err| MtError::from_anyhow(err, Some(self.contract_addr)))
+    }
+
+    /// Sends the execute message to the contract and reverts the changes it made.
+    #[track_caller]
+    pub fn dry_run(self, sender: &'a Addr) -> Result<cw_multi_test::AppResponse, Error>
+    where
+        MtApp: AppState,
+    {
+        let app = self.app;
+        app.dry_run(|_| self.call(sender))
     }
 }
 
@@ -332,12 +476,42 @@ where
     }
 
     /// Sends the migrate message to the contract.
+    ///
+    /// Panics if the message fails with an error other than `Error`
+    /// or violates the invariants of the contract.
+    /// Use [MigrateProxy::try_call] to handle the errors of the other contracts and modules.
     #[track_caller]
     pub fn call(
         self,
         sender: &Addr,
         new_code_id: u64,
     ) -> Result<cw_multi_test::AppResponse, Error> {
+        let resp = self
+            .send(sender, new_code_id)
+            .map_err(MtError::into_contract_error)?;
+        self.app.check_invariants(self.contract_addr, &self.msg);
+        Ok(resp)
+    }
+
+    /// Sends the migrate message to the contract, returning any failure as [MtError].
+    #[track_caller]
+    pub fn try_call(
+        self,
+        sender: &Addr,
+        new_code_id: u64,
+    ) -> Result<cw_multi_test::AppResponse, MtError<Error>> {
+        let resp = self.send(sender, new_code_id)?;
+        self.app
+            .try_check_invariants(self.contract_addr)
+            .map_err(MtError::InvariantViolated)?;
+        Ok(resp)
+    }
+
+    fn send(
+        &self,
+        sender: &Addr,
+        new_code_id: u64,
+    ) -> Result<cw_multi_test::AppResponse, MtError<Error>> {
         (*self.app)
             .app_mut()
             .migrate_contract(
@@ -346,7 +520,21 @@ where
                 &self.msg,
                 new_code_id,
             )
-            .map_err(|err| err.downcast().unwrap())
+            .map_err(|err| MtError::from_anyhow(err, Some(self.contract_addr)))
+    }
+
+    /// Sends the migrate message to the contract and reverts the changes it made.
+    #[track_caller]
+    pub fn dry_run(
+        self,
+        sender: &Addr,
+        new_code_id: u64,
+    ) -> Result<cw_multi_test::AppResponse, Error>
+    where
+        MtApp: AppState,
+    {
+        let app = self.app;
+        app.dry_run(|_| self.call(sender, new_code_id))
     }
 }
 
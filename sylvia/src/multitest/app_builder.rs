@@ -0,0 +1,266 @@
+//! Builder of the [App] with every `cw_multi_test` module replaceable.
+
+use std::fmt::Debug;
+
+use cosmwasm_std::testing::{MockApi, MockStorage};
+use cosmwasm_std::{Api, BlockInfo, Empty, Storage};
+use cw_multi_test::{
+    Bank, BankKeeper, Distribution, DistributionKeeper, FailingModule, Gov, GovFailingModule, Ibc,
+    IbcFailingModule, Module, Router, StakeKeeper, Staking, Stargate, StargateFailing, Wasm,
+    WasmKeeper,
+};
+
+use super::App;
+use crate::types::{CustomMsg, CustomQuery};
+
+/// Thin wrapper around the `cw_multi_test::AppBuilder` building the [App] and setting the bech32
+/// prefix of the [MockApi].
+///
+/// The built [App] is accepted by the generated `CodeId::store_code` and the proxies
+/// of the contracts, whose custom messages match the ones of the custom module.
+/// The custom module has to work with the custom messages the builder was created with.
+///
+/// ```rust
+/// use sylvia::cw_multi_test::GovAcceptingModule;
+/// use sylvia::cw_std::{coins, Response, StdResult};
+/// use sylvia::multitest::BasicAppBuilder;
+/// use sylvia::types::InstantiateCtx;
+///
+/// pub struct Contract;
+///
+/// #[sylvia::contract]
+/// impl Contract {
+///     pub const fn new() -> Self {
+///         Self
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+///
+/// # fn main() {
+/// let app = BasicAppBuilder::new()
+///     .with_gov(GovAcceptingModule::new())
+///     .with_bech32_prefix("juno")
+///     .build(|router, api, storage| {
+///         let owner = api.addr_make("owner");
+///         router.bank.init_balance(storage, &owner, coins(100, "juno")).unwrap();
+///     });
+///
+/// let owner = app.app().api().addr_make("owner");
+/// let contract = sv::mt::CodeId::store_code(&app)
+///     .instantiate()
+///     .with_funds(&coins(10, "juno"))
+///     .call(&owner)
+///     .unwrap();
+///
+/// assert!(contract.contract_addr.as_str().starts_with("juno1"));
+/// let balance = app.querier().query_balance(&contract.contract_addr, "juno").unwrap();
+/// assert_eq!(balance.amount.u128(), 10);
+/// # }
+/// ```
+pub struct AppBuilder<
+    BankT,
+    ApiT,
+    StorageT,
+    CustomT,
+    WasmT,
+    StakingT,
+    DistrT,
+    IbcT,
+    GovT,
+    StargateT,
+>(
+    cw_multi_test::AppBuilder<
+        BankT,
+        ApiT,
+        StorageT,
+        CustomT,
+        WasmT,
+        StakingT,
+        DistrT,
+        IbcT,
+        GovT,
+        StargateT,
+    >,
+);
+
+/// [AppBuilder] with the default modules working with the `Empty` custom messages.
+pub type BasicAppBuilder<ExecC = Empty, QueryC = Empty> = AppBuilder<
+    BankKeeper,
+    MockApi,
+    MockStorage,
+    FailingModule<ExecC, QueryC, Empty>,
+    WasmKeeper<ExecC, QueryC>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    StargateFailing,
+>;
+
+impl Default for BasicAppBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BasicAppBuilder {
+    /// Creates the builder with the default modules working with the `Empty` custom messages.
+    pub fn new() -> Self {
+        Self::new_custom()
+    }
+}
+
+impl<ExecC, QueryC> BasicAppBuilder<ExecC, QueryC>
+where
+    ExecC: CustomMsg + 'static,
+    QueryC: Debug + CustomQuery + 'static,
+{
+    /// Creates the builder with the default modules working with the custom messages
+    /// `ExecC` and `QueryC`, which fail on any custom message.
+    pub fn new_custom() -> Self {
+        Self(cw_multi_test::AppBuilder::new_custom())
+    }
+}
+
+impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    AppBuilder<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+where
+    CustomT: Module,
+    WasmT: Wasm<CustomT::ExecT, CustomT::QueryT>,
+{
+    pub fn with_api<NewApi: Api>(
+        self,
+        api: NewApi,
+    ) -> AppBuilder<BankT, NewApi, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_api(api))
+    }
+
+    /// Uses the [MockApi] creating and validating the addresses with the bech32 `prefix`.
+    pub fn with_bech32_prefix(
+        self,
+        prefix: &'static str,
+    ) -> AppBuilder<BankT, MockApi, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    {
+        self.with_api(MockApi::default().with_prefix(prefix))
+    }
+
+    /// Sets the block the chain starts at.
+    pub fn with_block(self, block: BlockInfo) -> Self {
+        Self(self.0.with_block(block))
+    }
+
+    pub fn with_storage<NewStorage: Storage>(
+        self,
+        storage: NewStorage,
+    ) -> AppBuilder<BankT, ApiT, NewStorage, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_storage(storage))
+    }
+
+    pub fn with_bank<NewBank: Bank>(
+        self,
+        bank: NewBank,
+    ) -> AppBuilder<NewBank, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_bank(bank))
+    }
+
+    /// Sets the module handling the custom messages.
+    pub fn with_custom<NewCustom: Module>(
+        self,
+        custom: NewCustom,
+    ) -> AppBuilder<BankT, ApiT, StorageT, NewCustom, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_custom(custom))
+    }
+
+    pub fn with_wasm<NewWasm: Wasm<CustomT::ExecT, CustomT::QueryT>>(
+        self,
+        wasm: NewWasm,
+    ) -> AppBuilder<BankT, ApiT, StorageT, CustomT, NewWasm, StakingT, DistrT, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_wasm(wasm))
+    }
+
+    pub fn with_staking<NewStaking: Staking>(
+        self,
+        staking: NewStaking,
+    ) -> AppBuilder<BankT, ApiT, StorageT, CustomT, WasmT, NewStaking, DistrT, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_staking(staking))
+    }
+
+    pub fn with_distribution<NewDistr: Distribution>(
+        self,
+        distribution: NewDistr,
+    ) -> AppBuilder<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, NewDistr, IbcT, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_distribution(distribution))
+    }
+
+    pub fn with_ibc<NewIbc: Ibc>(
+        self,
+        ibc: NewIbc,
+    ) -> AppBuilder<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, NewIbc, GovT, StargateT>
+    {
+        AppBuilder(self.0.with_ibc(ibc))
+    }
+
+    pub fn with_gov<NewGov: Gov>(
+        self,
+        gov: NewGov,
+    ) -> AppBuilder<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, NewGov, StargateT>
+    {
+        AppBuilder(self.0.with_gov(gov))
+    }
+
+    pub fn with_stargate<NewStargate: Stargate>(
+        self,
+        stargate: NewStargate,
+    ) -> AppBuilder<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, NewStargate>
+    {
+        AppBuilder(self.0.with_stargate(stargate))
+    }
+
+    /// Builds the [App], initializing the modules with the `init_fn`.
+    #[allow(clippy::type_complexity)]
+    pub fn build<F>(
+        self,
+        init_fn: F,
+    ) -> App<
+        cw_multi_test::App<
+            BankT,
+            ApiT,
+            StorageT,
+            CustomT,
+            WasmT,
+            StakingT,
+            DistrT,
+            IbcT,
+            GovT,
+            StargateT,
+        >,
+    >
+    where
+        BankT: Bank,
+        ApiT: Api,
+        StorageT: Storage,
+        StakingT: Staking,
+        DistrT: Distribution,
+        IbcT: Ibc,
+        GovT: Gov,
+        StargateT: Stargate,
+        F: FnOnce(
+            &mut Router<BankT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>,
+            &ApiT,
+            &mut dyn Storage,
+        ),
+    {
+        App::new(self.0.build(init_fn))
+    }
+}
@@ -0,0 +1,370 @@
+//! Coverage of the messages received by the contracts during the multitest run.
+//!
+//! The coverage is enabled with [App::with_coverage](super::App::with_coverage) and recorded
+//! for the codes stored afterwards. Every message delivered to the contract is recorded, whether
+//! it was sent directly by the test or dispatched as a submessage by another contract.
+//!
+//! ```rust,ignore
+//! let app = App::default().with_coverage();
+//! let code_id = CodeId::store_code(&app);
+//! // ...
+//! let report = app.coverage_report().unwrap();
+//! println!("{report}");
+//! std::fs::write("coverage.json", report.to_json().unwrap()).unwrap();
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use cosmwasm_std::{
+    from_json, to_json_string, Binary, CustomMsg, CustomQuery, Deps, DepsMut, Env, MessageInfo,
+    Reply, Response, StdResult,
+};
+use serde::de::IgnoredAny;
+use serde::Serialize;
+
+/// Entry point of the contract receiving the messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryPoint {
+    Exec,
+    Query,
+    Sudo,
+    Migrate,
+    Reply,
+}
+
+impl fmt::Display for EntryPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Exec => "exec",
+            Self::Query => "query",
+            Self::Sudo => "sudo",
+            Self::Migrate => "migrate",
+            Self::Reply => "reply",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Messages of a single entry point declared by the contract or one of its interfaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeclaredMessages {
+    /// Name of the contract or of the interface variant of the glue message.
+    pub source: &'static str,
+    /// Namespace the messages of the interface are nested under.
+    pub namespace: Option<&'static str>,
+    pub messages: &'static [&'static str],
+}
+
+/// Messages declared by the contract, generated by the `contract` macro for the
+/// `CodeId::store_code`.
+#[derive(Clone, Copy)]
+pub struct ContractMessages {
+    pub contract: &'static str,
+    pub exec: &'static [DeclaredMessages],
+    pub query: &'static [DeclaredMessages],
+    pub sudo: &'static [DeclaredMessages],
+    /// Name of the migrate handler, if the contract has one.
+    pub migrate: &'static [&'static str],
+    /// Names of the reply handlers.
+    pub reply: &'static [&'static str],
+    /// Finds the reply handler called for the reply.
+    pub reply_handler: fn(&Reply) -> Option<&'static str>,
+}
+
+impl ContractMessages {
+    fn declared(&self, entry_point: EntryPoint) -> &'static [DeclaredMessages] {
+        match entry_point {
+            EntryPoint::Exec => self.exec,
+            EntryPoint::Query => self.query,
+            EntryPoint::Sudo => self.sudo,
+            EntryPoint::Migrate | EntryPoint::Reply => &[],
+        }
+    }
+
+    /// Lists all the declared messages in order of the entry points and the declaration.
+    fn all(&self) -> Vec<ReceivedMsg> {
+        let enum_msgs = [EntryPoint::Exec, EntryPoint::Query, EntryPoint::Sudo]
+            .into_iter()
+            .flat_map(|entry_point| {
+                self.declared(entry_point).iter().flat_map(move |declared| {
+                    declared.messages.iter().map(move |message| ReceivedMsg {
+                        entry_point,
+                        source: declared.source,
+                        message,
+                    })
+                })
+            });
+        let handlers = [
+            (EntryPoint::Migrate, self.migrate),
+            (EntryPoint::Reply, self.reply),
+        ]
+        .into_iter()
+        .flat_map(|(entry_point, handlers)| {
+            handlers.iter().map(move |message| ReceivedMsg {
+                entry_point,
+                source: self.contract,
+                message,
+            })
+        });
+        enum_msgs.chain(handlers).collect()
+    }
+
+    /// Finds the declared message matching the received `{"name": {...}}` message.
+    fn resolve(&self, entry_point: EntryPoint, msg: &[u8]) -> Option<ReceivedMsg> {
+        let name = message_name(msg)?;
+        let declared = self.declared(entry_point);
+        let (declared, name) = match declared
+            .iter()
+            .find(|declared| declared.namespace == Some(name.as_str()))
+        {
+            Some(namespaced) => {
+                let msg: BTreeMap<String, BTreeMap<String, IgnoredAny>> = from_json(msg).ok()?;
+                let name = msg.into_values().next()?.into_keys().next()?;
+                (namespaced, name)
+            }
+            None => declared
+                .iter()
+                .filter(|declared| declared.namespace.is_none())
+                .find(|declared| declared.messages.contains(&name.as_str()))
+                .map(|declared| (declared, name))?,
+        };
+        let message = declared
+            .messages
+            .iter()
+            .copied()
+            .find(|message| *message == name)?;
+        Some(ReceivedMsg {
+            entry_point,
+            source: declared.source,
+            message,
+        })
+    }
+}
+
+fn message_name(msg: &[u8]) -> Option<String> {
+    let msg: BTreeMap<String, IgnoredAny> = from_json(msg).ok()?;
+    msg.into_keys().next()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct ReceivedMsg {
+    entry_point: EntryPoint,
+    source: &'static str,
+    message: &'static str,
+}
+
+#[derive(Default)]
+struct CoverageState {
+    contracts: Vec<ContractMessages>,
+    received: BTreeSet<(&'static str, ReceivedMsg)>,
+}
+
+/// Messages received by the contracts stored on the [App](super::App).
+#[derive(Clone, Default)]
+pub struct Coverage {
+    state: Arc<Mutex<CoverageState>>,
+}
+
+impl Coverage {
+    /// Starts recording the messages received by the contract.
+    pub fn recorder(&self, messages: ContractMessages) -> CoverageRecorder {
+        let mut state = self.state();
+        if !state
+            .contracts
+            .iter()
+            .any(|contract| contract.contract == messages.contract)
+        {
+            state.contracts.push(messages);
+        }
+        CoverageRecorder {
+            coverage: self.clone(),
+            messages,
+        }
+    }
+
+    /// Lists the declared messages never received by the contracts.
+    pub fn report(&self) -> CoverageReport {
+        let state = self.state();
+        let contracts = state
+            .contracts
+            .iter()
+            .map(|contract| {
+                let declared = contract.all();
+                let missed: Vec<_> = declared
+                    .iter()
+                    .filter(|msg| !state.received.contains(&(contract.contract, **msg)))
+                    .map(|msg| MissedMessage {
+                        entry_point: msg.entry_point,
+                        source: msg.source,
+                        message: msg.message,
+                    })
+                    .collect();
+                ContractCoverage {
+                    contract: contract.contract,
+                    declared: declared.len(),
+                    received: declared.len() - missed.len(),
+                    missed,
+                }
+            })
+            .collect();
+        CoverageReport { contracts }
+    }
+
+    fn state(&self) -> MutexGuard<'_, CoverageState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Records the messages received by a single contract.
+#[derive(Clone)]
+pub struct CoverageRecorder {
+    coverage: Coverage,
+    messages: ContractMessages,
+}
+
+impl CoverageRecorder {
+    fn record(&self, msg: Option<ReceivedMsg>) {
+        if let Some(msg) = msg {
+            self.coverage
+                .state()
+                .received
+                .insert((self.messages.contract, msg));
+        }
+    }
+
+    fn record_msg(&self, entry_point: EntryPoint, msg: &[u8]) {
+        self.record(self.messages.resolve(entry_point, msg));
+    }
+
+    fn record_handler(&self, entry_point: EntryPoint, handler: Option<&'static str>) {
+        self.record(handler.map(|message| ReceivedMsg {
+            entry_point,
+            source: self.messages.contract,
+            message,
+        }));
+    }
+}
+
+/// Contract recording the received messages before passing them to the wrapped contract.
+pub struct CoveredContract<T> {
+    contract: T,
+    recorder: CoverageRecorder,
+}
+
+impl<T> CoveredContract<T> {
+    pub fn new(contract: T, recorder: CoverageRecorder) -> Self {
+        Self { contract, recorder }
+    }
+}
+
+impl<T, C, Q> cw_multi_test::Contract<C, Q> for CoveredContract<T>
+where
+    T: cw_multi_test::Contract<C, Q>,
+    C: CustomMsg,
+    Q: CustomQuery,
+{
+    fn execute(
+        &self,
+        deps: DepsMut<Q>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<C>> {
+        self.recorder.record_msg(EntryPoint::Exec, &msg);
+        self.contract.execute(deps, env, info, msg)
+    }
+
+    fn instantiate(
+        &self,
+        deps: DepsMut<Q>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<C>> {
+        self.contract.instantiate(deps, env, info, msg)
+    }
+
+    fn query(&self, deps: Deps<Q>, env: Env, msg: Vec<u8>) -> anyhow::Result<Binary> {
+        self.recorder.record_msg(EntryPoint::Query, &msg);
+        self.contract.query(deps, env, msg)
+    }
+
+    fn sudo(&self, deps: DepsMut<Q>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response<C>> {
+        self.recorder.record_msg(EntryPoint::Sudo, &msg);
+        self.contract.sudo(deps, env, msg)
+    }
+
+    fn reply(&self, deps: DepsMut<Q>, env: Env, msg: Reply) -> anyhow::Result<Response<C>> {
+        let handler = (self.recorder.messages.reply_handler)(&msg);
+        self.recorder.record_handler(EntryPoint::Reply, handler);
+        self.contract.reply(deps, env, msg)
+    }
+
+    fn migrate(&self, deps: DepsMut<Q>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response<C>> {
+        let handler = self.recorder.messages.migrate.first().copied();
+        self.recorder.record_handler(EntryPoint::Migrate, handler);
+        self.contract.migrate(deps, env, msg)
+    }
+}
+
+/// Declared messages never received by the contracts.
+///
+/// Displayed as the text report, or serialized to JSON with [CoverageReport::to_json].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CoverageReport {
+    pub contracts: Vec<ContractCoverage>,
+}
+
+impl CoverageReport {
+    /// Checks if every declared message was received.
+    pub fn is_complete(&self) -> bool {
+        self.contracts
+            .iter()
+            .all(|contract| contract.missed.is_empty())
+    }
+
+    pub fn to_json(&self) -> StdResult<String> {
+        to_json_string(self)
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for contract in &self.contracts {
+            writeln!(
+                f,
+                "{}: {} of {} messages received",
+                contract.contract, contract.received, contract.declared
+            )?;
+            for missed in &contract.missed {
+                writeln!(
+                    f,
+                    "  {} {}::{}",
+                    missed.entry_point, missed.source, missed.message
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Coverage of the messages declared by a single contract and its interfaces.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ContractCoverage {
+    pub contract: &'static str,
+    pub declared: usize,
+    pub received: usize,
+    pub missed: Vec<MissedMessage>,
+}
+
+/// Declared message never received by the contract.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MissedMessage {
+    pub entry_point: EntryPoint,
+    /// Name of the contract or the interface declaring the message.
+    pub source: &'static str,
+    pub message: &'static str,
+}
@@ -0,0 +1,215 @@
+//! Errors of the messages sent with the multitest proxies.
+
+use std::fmt::{Debug, Display};
+
+use cosmwasm_std::Addr;
+
+use super::InvariantViolation;
+
+/// Context added by `cw_multi_test` to the errors of every processed `WasmMsg`.
+const WASM_MSG_CONTEXT: &str = "Error executing WasmMsg";
+
+/// Context attached by the multitest `Contract` implementation generated by the `contract` macro
+/// to the errors returned by the entry points, identifying the failing contract for [MtError].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedContract {
+    pub contract: Addr,
+}
+
+impl FailedContract {
+    pub fn new(env: &cosmwasm_std::Env) -> Self {
+        Self {
+            contract: env.contract.address.clone(),
+        }
+    }
+}
+
+impl Display for FailedContract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Error in contract {}", self.contract)
+    }
+}
+
+/// Failure of the message sent with the `try_call` of the multitest proxies.
+///
+/// ```rust
+/// use sylvia::cw_std::{to_json_binary, Response, StdError, StdResult, WasmMsg};
+/// use sylvia::multitest::{App, MtError};
+/// use sylvia::types::{ExecCtx, InstantiateCtx};
+///
+/// pub struct Vault;
+///
+/// #[sylvia::contract]
+/// impl Vault {
+///     pub const fn new() -> Self {
+///         Self
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     fn withdraw(&self, _ctx: ExecCtx, amount: u128) -> StdResult<Response> {
+///         Err(StdError::generic_err(format!("Can't withdraw {amount}")))
+///     }
+///
+///     #[sv::msg(exec)]
+///     fn withdraw_from(&self, _ctx: ExecCtx, vault: String, amount: u128) -> StdResult<Response> {
+///         let msg = WasmMsg::Execute {
+///             contract_addr: vault,
+///             msg: to_json_binary(&sv::ExecMsg::withdraw(amount))?,
+///             funds: vec![],
+///         };
+///         Ok(Response::new().add_message(msg))
+///     }
+/// }
+///
+/// # fn main() {
+/// use sylvia::cw_multi_test::IntoBech32;
+/// use sv::mt::{CodeId, VaultProxy};
+///
+/// let app = App::default();
+/// let owner = "owner".into_bech32();
+/// let code_id = CodeId::store_code(&app);
+/// let vault = code_id.instantiate().call(&owner).unwrap();
+/// let other = code_id.instantiate().call(&owner).unwrap();
+///
+/// let err = vault.withdraw(100).try_call(&owner).unwrap_err();
+/// assert!(matches!(err, MtError::Contract(StdError::GenericErr { .. })));
+///
+/// let err = other
+///     .withdraw_from(vault.contract_addr.to_string(), 100)
+///     .try_call(&owner)
+///     .unwrap_err();
+/// assert_eq!(err.contract(), Some(&vault.contract_addr));
+/// assert!(err.contract_error::<StdError>().is_some());
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum MtError<E> {
+    /// Error returned by the called contract.
+    Contract(E),
+    /// Error returned by another contract while processing the submessages of the called one,
+    /// or raised by the modules processing the messages of that contract.
+    /// `contract` is the address of the failing contract, known only from the [FailedContract]
+    /// context of the contracts generated by sylvia.
+    ForeignContract {
+        contract: Option<Addr>,
+        error: anyhow::Error,
+    },
+    /// Error of the `cw_multi_test` modules processing the messages of the called contract,
+    /// f.e. the bank failing to send the funds.
+    System(anyhow::Error),
+    /// The message succeeded, but the called contract violates one of its invariants.
+    InvariantViolated(InvariantViolation),
+}
+
+impl<E> MtError<E>
+where
+    E: Debug + Display + Send + Sync + 'static,
+{
+    /// Categorizes the error returned by the `cw_multi_test::App` for the message sent to
+    /// `contract`, or to the instantiated contract if `None`.
+    ///
+    /// The failing contract is identified by the [FailedContract] context of the contracts
+    /// generated by sylvia. The errors without it and the errors of the instantiated contract
+    /// fall back to the `WasmMsg` contexts added by `cw_multi_test`, considering the failure
+    /// foreign if it occurred in a nested `WasmMsg`. The address of the failing contract is then
+    /// taken only from the [FailedContract] context, if there is one.
+    pub fn from_anyhow(error: anyhow::Error, contract: Option<&Addr>) -> Self {
+        let failed = error
+            .downcast_ref::<FailedContract>()
+            .map(|failed| failed.contract.clone());
+
+        let foreign = match (failed, contract) {
+            (Some(failed), Some(called)) => (failed != *called).then_some(Some(failed)),
+            (failed, _) => {
+                let wasm_contexts: Vec<_> = error
+                    .chain()
+                    .map(ToString::to_string)
+                    .filter(|layer| layer.starts_with(WASM_MSG_CONTEXT))
+                    .collect();
+                match wasm_contexts.as_slice() {
+                    [_, _, ..] => Some(failed),
+                    _ => None,
+                }
+            }
+        };
+        if let Some(contract) = foreign {
+            return MtError::ForeignContract { contract, error };
+        }
+
+        match error.downcast::<E>() {
+            Ok(err) => MtError::Contract(err),
+            Err(error) => MtError::System(error),
+        }
+    }
+
+    /// Returns the error of type `T` returned by the called or the foreign contract.
+    pub fn contract_error<T>(&self) -> Option<&T>
+    where
+        T: Display + Debug + Send + Sync + 'static,
+    {
+        match self {
+            MtError::Contract(err) => (err as &dyn std::any::Any).downcast_ref(),
+            MtError::ForeignContract { error, .. } => error.downcast_ref(),
+            MtError::System(_) | MtError::InvariantViolated(_) => None,
+        }
+    }
+
+    /// Returns the address of the failing foreign contract.
+    pub fn contract(&self) -> Option<&Addr> {
+        match self {
+            MtError::ForeignContract { contract, .. } => contract.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the error as `E`, the way the `call` method of the proxies does.
+    ///
+    /// Panics if the failure can't be represented as `E`.
+    #[track_caller]
+    pub fn into_contract_error(self) -> E {
+        match self {
+            MtError::Contract(err) => err,
+            MtError::InvariantViolated(violation) => panic!("{violation}"),
+            MtError::ForeignContract { error, .. } | MtError::System(error) => {
+                match error.downcast() {
+                    Ok(err) => err,
+                    Err(error) => panic!(
+                        "Expected error of type `{}`, received: {error:?}",
+                        std::any::type_name::<E>()
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl<E> From<E> for MtError<E> {
+    fn from(err: E) -> Self {
+        MtError::Contract(err)
+    }
+}
+
+impl<E: Display> Display for MtError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MtError::Contract(err) => write!(f, "{err}"),
+            MtError::ForeignContract {
+                contract: Some(contract),
+                error,
+            } => write!(f, "Error in contract {contract}: {}", error.root_cause()),
+            MtError::ForeignContract {
+                contract: None,
+                error,
+            } => write!(f, "Error in contract: {}", error.root_cause()),
+            MtError::System(error) => write!(f, "{error:#}"),
+            MtError::InvariantViolated(violation) => write!(f, "{violation}"),
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for MtError<E> {}
@@ -0,0 +1,83 @@
+//! Typed access to the events emitted during the multitest run.
+
+use cosmwasm_std::StdResult;
+
+use crate::types::TypedEvent;
+
+/// Typed access to the events of the [AppResponse](cw_multi_test::AppResponse)
+/// returned f.e. by the [ExecProxy::call](super::ExecProxy::call).
+///
+/// ```rust
+/// use sylvia::cw_std::{Addr, Response, StdResult};
+/// use sylvia::multitest::{App, TypedEvents};
+/// use sylvia::types::{ExecCtx, InstantiateCtx, TypedEvent};
+///
+/// #[derive(TypedEvent, Debug, PartialEq)]
+/// #[event(name = "transfer")]
+/// pub struct TransferEvent {
+///     pub sender: Addr,
+///     pub amount: u128,
+/// }
+///
+/// pub struct Token;
+///
+/// #[sylvia::contract]
+/// impl Token {
+///     pub const fn new() -> Self {
+///         Self
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     fn transfer(&self, ctx: ExecCtx, amount: u128) -> StdResult<Response> {
+///         let event = TransferEvent { sender: ctx.info.sender, amount };
+///         Ok(Response::new().add_event(event.to_event()?))
+///     }
+/// }
+///
+/// # fn main() {
+/// use sylvia::cw_multi_test::IntoBech32;
+/// use sv::mt::{CodeId, TokenProxy};
+///
+/// let app = App::default();
+/// let owner = "owner".into_bech32();
+/// let contract = CodeId::store_code(&app)
+///     .instantiate()
+///     .call(&owner)
+///     .unwrap();
+///
+/// let resp = contract.transfer(100).call(&owner).unwrap();
+///
+/// let event = TransferEvent { sender: owner, amount: 100 };
+/// assert!(resp.has_typed_event(&event));
+/// assert_eq!(resp.events_of::<TransferEvent>().unwrap(), [event]);
+/// # }
+/// ```
+pub trait TypedEvents {
+    /// Parses all the events of type `E`, in order of their emission.
+    fn events_of<E: TypedEvent>(&self) -> StdResult<Vec<E>>;
+
+    /// Checks if the `event` was emitted.
+    fn has_typed_event<E: TypedEvent + PartialEq>(&self, event: &E) -> bool;
+}
+
+impl TypedEvents for cw_multi_test::AppResponse {
+    fn events_of<E: TypedEvent>(&self) -> StdResult<Vec<E>> {
+        self.events
+            .iter()
+            .filter(|event| E::matches(event))
+            .map(E::from_event)
+            .collect()
+    }
+
+    fn has_typed_event<E: TypedEvent + PartialEq>(&self, event: &E) -> bool {
+        self.events
+            .iter()
+            .filter(|emitted| E::matches(emitted))
+            .any(|emitted| E::from_event(emitted).is_ok_and(|emitted| &emitted == event))
+    }
+}
@@ -0,0 +1,342 @@
+//! Fuzzing of the contracts with random sequences of the generated messages.
+//!
+//! Requires the `arbitrary` feature and the contract marked with `#[sv::arbitrary]`.
+//! Every run instantiates the contract and sends it a random
+//! sequence of the execute and query messages from the given senders. The run fails if:
+//! * a message doesn't survive the serialization round-trip,
+//! * the contract panics,
+//! * one of the `#[sv::invariant]` methods of the contract is violated.
+//!
+//! Errors returned by the contract are expected and don't fail the run.
+//! The failing sequence is shrunk to the steps required to reproduce the failure.
+//!
+//! ```rust,ignore
+//! let app = App::default();
+//! let code_id = CodeId::store_code(&app);
+//! code_id
+//!     .fuzz(&[sylvia::fuzz::pool_addr(0), sylvia::fuzz::pool_addr(1)])
+//!     .with_runs(64)
+//!     .with_steps(16)
+//!     .check();
+//! ```
+
+use std::fmt::{self, Debug, Display};
+use std::panic::{self, AssertUnwindSafe};
+
+use arbitrary::{Arbitrary, Unstructured};
+use cosmwasm_std::{Addr, Querier, QuerierWrapper};
+use cw_multi_test::Executor;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+
+use super::{App, AppSnapshot, AppState};
+use crate::fuzz::check_round_trip;
+use crate::types::ContractApi;
+
+/// Number of the random bytes the messages of a single run are generated from.
+const RUN_DATA_LEN: usize = 4096;
+
+/// Single message sent to the fuzzed contract.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FuzzStep<Exec, Query> {
+    Execute { sender: Addr, msg: Exec },
+    Query { msg: Query },
+}
+
+/// Failing run of the [Fuzz] driver, shrunk to the steps required to reproduce the failure.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuzzFailure<Instantiate, Exec, Query> {
+    /// Seed of the failing run. Passing it to [Fuzz::with_seed] reproduces the failure
+    /// in the first run.
+    pub seed: u64,
+    pub instantiate: Instantiate,
+    pub steps: Vec<FuzzStep<Exec, Query>>,
+    pub error: String,
+}
+
+impl<Instantiate, Exec, Query> Display for FuzzFailure<Instantiate, Exec, Query>
+where
+    Instantiate: Debug,
+    Exec: Debug,
+    Query: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Fuzzing failed with seed {}: {}", self.seed, self.error)?;
+        writeln!(f, "  instantiate: {:?}", self.instantiate)?;
+        for (index, step) in self.steps.iter().enumerate() {
+            match step {
+                FuzzStep::Execute { sender, msg } => {
+                    writeln!(f, "  {}. execute from {sender}: {msg:?}", index + 1)?
+                }
+                FuzzStep::Query { msg } => writeln!(f, "  {}. query: {msg:?}", index + 1)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+type ContractFuzzFailure<Contract> = FuzzFailure<
+    <Contract as ContractApi>::Instantiate,
+    <Contract as ContractApi>::Exec,
+    <Contract as ContractApi>::Query,
+>;
+
+/// Failure of the replayed sequence with the number of the steps sent until it occurred.
+struct ReplayFailure {
+    steps: usize,
+    error: String,
+}
+
+/// Driver running random sequences of the messages against the contract.
+///
+/// Created with the generated `CodeId::fuzz`. The state of the [App] is restored after fuzzing.
+pub struct Fuzz<'app, Contract: ContractApi, MtApp> {
+    app: &'app App<MtApp>,
+    code_id: u64,
+    senders: Vec<Addr>,
+    instantiate: Option<Contract::Instantiate>,
+    runs: u32,
+    steps: usize,
+    seed: u64,
+}
+
+impl<'app, Contract: ContractApi, MtApp> Fuzz<'app, Contract, MtApp> {
+    /// Creates the driver fuzzing the contracts of the `code_id`.
+    ///
+    /// The contract is instantiated by the first of the `senders`.
+    pub fn new(app: &'app App<MtApp>, code_id: u64, senders: &[Addr]) -> Self {
+        assert!(!senders.is_empty(), "Fuzzing requires at least one sender");
+        Self {
+            app,
+            code_id,
+            senders: senders.to_vec(),
+            instantiate: None,
+            runs: 32,
+            steps: 16,
+            seed: 0,
+        }
+    }
+
+    /// Sets the number of the runs, each starting with a newly instantiated contract.
+    pub fn with_runs(self, runs: u32) -> Self {
+        Self { runs, ..self }
+    }
+
+    /// Sets the maximum number of the messages sent in a single run.
+    pub fn with_steps(self, steps: usize) -> Self {
+        Self { steps, ..self }
+    }
+
+    /// Sets the seed the messages are generated from.
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    /// Instantiates the contract with the `msg` instead of a random one.
+    ///
+    /// Without it, the runs in which the random instantiation fails are skipped.
+    pub fn with_instantiate(self, msg: Contract::Instantiate) -> Self {
+        Self {
+            instantiate: Some(msg),
+            ..self
+        }
+    }
+}
+
+impl<'app, Contract, MtApp> Fuzz<'app, Contract, MtApp>
+where
+    Contract: ContractApi,
+    Contract::Instantiate:
+        for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + Debug + Clone,
+    Contract::Exec:
+        for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + Debug + Clone,
+    Contract::Query:
+        for<'a> Arbitrary<'a> + Serialize + DeserializeOwned + PartialEq + Debug + Clone,
+    Contract::CustomMsg: 'static,
+    MtApp: Executor<Contract::CustomMsg> + AppState,
+    App<MtApp>: Querier,
+{
+    /// Runs the random sequences of the messages, returning the first failure found.
+    pub fn run(&self) -> Result<(), ContractFuzzFailure<Contract>> {
+        let snapshot = self.app.snapshot();
+        let result = (0..self.runs).try_for_each(|run| {
+            let seed = self.seed.wrapping_add(run.into());
+            self.fuzz_run(&snapshot, seed)
+        });
+        self.app.restore(&snapshot);
+        result
+    }
+
+    /// Runs the random sequences of the messages.
+    ///
+    /// Panics with the shrunk failing sequence.
+    #[track_caller]
+    pub fn check(&self) {
+        if let Err(failure) = self.run() {
+            panic!("{failure}");
+        }
+    }
+
+    fn fuzz_run(
+        &self,
+        snapshot: &AppSnapshot,
+        seed: u64,
+    ) -> Result<(), ContractFuzzFailure<Contract>> {
+        let data = run_data(seed);
+        let mut u = Unstructured::new(&data);
+        let instantiate = match &self.instantiate {
+            Some(msg) => msg.clone(),
+            None => match u.arbitrary() {
+                Ok(msg) => msg,
+                Err(_) => return Ok(()),
+            },
+        };
+        let mut steps = Vec::with_capacity(self.steps);
+        while steps.len() < self.steps && !u.is_empty() {
+            match self.arbitrary_step(&mut u) {
+                Ok(step) => steps.push(step),
+                Err(_) => break,
+            }
+        }
+
+        let Err(failure) = self.replay(snapshot, &instantiate, &steps) else {
+            return Ok(());
+        };
+        steps.truncate(failure.steps);
+        let mut error = failure.error;
+
+        // Greedily drops the steps not required to reproduce the failure.
+        let mut index = 0;
+        while index < steps.len() {
+            let mut shrunk = steps.clone();
+            shrunk.remove(index);
+            match self.replay(snapshot, &instantiate, &shrunk) {
+                Err(failure) => {
+                    shrunk.truncate(failure.steps);
+                    steps = shrunk;
+                    error = failure.error;
+                }
+                Ok(()) => index += 1,
+            }
+        }
+
+        Err(FuzzFailure {
+            seed,
+            instantiate,
+            steps,
+            error,
+        })
+    }
+
+    fn arbitrary_step(
+        &self,
+        u: &mut Unstructured,
+    ) -> arbitrary::Result<FuzzStep<Contract::Exec, Contract::Query>> {
+        let step = match u.arbitrary()? {
+            true => FuzzStep::Execute {
+                sender: u.choose(&self.senders)?.clone(),
+                msg: u.arbitrary()?,
+            },
+            false => FuzzStep::Query {
+                msg: u.arbitrary()?,
+            },
+        };
+        Ok(step)
+    }
+
+    /// Instantiates the contract on the restored `snapshot` and sends it the `steps`.
+    ///
+    /// Failed instantiation of the contract is not considered a failure of the sequence.
+    fn replay(
+        &self,
+        snapshot: &AppSnapshot,
+        instantiate: &Contract::Instantiate,
+        steps: &[FuzzStep<Contract::Exec, Contract::Query>],
+    ) -> Result<(), ReplayFailure> {
+        self.app.restore(snapshot);
+        let failure = |steps: usize| move |error: String| ReplayFailure { steps, error };
+
+        check_round_trip(instantiate).map_err(failure(0))?;
+        let instantiated = catch_panic(|| {
+            self.app.app_mut().instantiate_contract(
+                self.code_id,
+                self.senders[0].clone(),
+                instantiate,
+                &[],
+                "Fuzz",
+                None,
+            )
+        })
+        .map_err(failure(0))?;
+        let Ok(contract) = instantiated else {
+            return Ok(());
+        };
+        self.check_invariants(&contract).map_err(failure(0))?;
+
+        steps
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, step)| self.send(&contract, step).map_err(failure(index + 1)))
+    }
+
+    fn send(
+        &self,
+        contract: &Addr,
+        step: &FuzzStep<Contract::Exec, Contract::Query>,
+    ) -> Result<(), String> {
+        match step {
+            FuzzStep::Execute { sender, msg } => {
+                check_round_trip(msg)?;
+                let executed = catch_panic(|| {
+                    self.app
+                        .app_mut()
+                        .execute_contract(sender.clone(), contract.clone(), msg, &[])
+                })?;
+                if executed.is_ok() {
+                    self.check_invariants(contract)?;
+                }
+            }
+            FuzzStep::Query { msg } => {
+                check_round_trip(msg)?;
+                let _ = catch_panic(|| {
+                    QuerierWrapper::<Contract::CustomQuery>::new(self.app)
+                        .query_wasm_smart::<IgnoredAny>(contract, msg)
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_invariants(&self, contract: &Addr) -> Result<(), String> {
+        self.app
+            .try_check_invariants(contract)
+            .map_err(|violation| violation.to_string())
+    }
+}
+
+fn catch_panic<T>(action: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(action)).map_err(|panic| {
+        let msg = match panic.downcast_ref::<&str>() {
+            Some(msg) => msg.to_string(),
+            None => panic
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_else(|| "unknown panic payload".to_owned()),
+        };
+        format!("Contract panicked: {msg}")
+    })
+}
+
+/// Generates the bytes the messages of the run are created from with the `splitmix64`.
+fn run_data(seed: u64) -> Vec<u8> {
+    let mut state = seed;
+    (0..RUN_DATA_LEN / 8)
+        .flat_map(|_| {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            (z ^ (z >> 31)).to_le_bytes()
+        })
+        .collect()
+}
@@ -0,0 +1,113 @@
+//! Invariants of the contracts checked after every message sent with the multitest proxies.
+
+use std::fmt::{Debug, Display};
+use std::rc::Rc;
+
+use cosmwasm_std::Addr;
+
+use super::App;
+
+pub(super) type InvariantsCheck<MtApp> =
+    Rc<dyn Fn(&MtApp, &Addr) -> Result<(), InvariantViolation>>;
+
+/// Invariant of the contract, declared with `#[sv::invariant]`, which doesn't hold.
+///
+/// ```rust
+/// use cw_storage_plus::Item;
+/// use sylvia::cw_std::{ensure, Response, StdError, StdResult};
+/// use sylvia::multitest::{App, InvariantViolation, MtError};
+/// use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+///
+/// pub struct Counter {
+///     count: Item<u64>,
+/// }
+///
+/// #[sylvia::contract]
+/// impl Counter {
+///     pub const fn new() -> Self {
+///         Self { count: Item::new("count") }
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+///         self.count.save(ctx.deps.storage, &0)?;
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     fn increment(&self, ctx: ExecCtx, by: u64) -> StdResult<Response> {
+///         self.count.update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + by) })?;
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::invariant]
+///     fn below_ten(&self, ctx: QueryCtx) -> StdResult<()> {
+///         let count = self.count.load(ctx.deps.storage)?;
+///         ensure!(count < 10, StdError::generic_err(format!("count is {count}")));
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() {
+/// use sylvia::cw_multi_test::IntoBech32;
+/// use sv::mt::{CodeId, CounterProxy};
+///
+/// let app = App::default();
+/// let owner = "owner".into_bech32();
+/// let contract = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+///
+/// contract.increment(5).call(&owner).unwrap();
+///
+/// let err = contract.increment(5).try_call(&owner).unwrap_err();
+/// let MtError::InvariantViolated(InvariantViolation { invariant, error }) = err else {
+///     panic!("Expected invariant violation, received {err:?}");
+/// };
+/// assert_eq!(invariant, "below_ten");
+/// assert_eq!(error, "Generic error: count is 10");
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvariantViolation {
+    pub invariant: &'static str,
+    pub error: String,
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invariant `{}` violated: {}", self.invariant, self.error)
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+impl<MtApp> App<MtApp> {
+    /// Registers the check of the contract invariants.
+    ///
+    /// Used by the generated `CodeId::store_code` of the contracts declaring the
+    /// `#[sv::invariant]` methods. The check is expected to skip the contracts of other codes.
+    pub fn add_invariants(
+        &self,
+        check: impl Fn(&MtApp, &Addr) -> Result<(), InvariantViolation> + 'static,
+    ) {
+        self.invariants.borrow_mut().push(Rc::new(check));
+    }
+
+    /// Checks the invariants of the `contract` after it successfully processed the `msg`.
+    ///
+    /// Panics with the name of the violated invariant and the message.
+    #[track_caller]
+    pub fn check_invariants(&self, contract: &Addr, msg: &dyn Debug) {
+        if let Err(InvariantViolation { invariant, error }) = self.try_check_invariants(contract) {
+            panic!(
+                "Invariant `{invariant}` of the contract {contract} violated after {msg:?}: {error}"
+            );
+        }
+    }
+
+    /// Checks the invariants of the `contract`, returning the first one which doesn't hold.
+    pub fn try_check_invariants(&self, contract: &Addr) -> Result<(), InvariantViolation> {
+        let checks = self.invariants.borrow().clone();
+        let app = self.app();
+        checks.iter().try_for_each(|check| check(&app, contract))
+    }
+}
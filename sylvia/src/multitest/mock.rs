@@ -0,0 +1,368 @@
+//! Mock contracts answering the messages of the interfaces with the registered handlers.
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use cosmwasm_std::{
+    from_json, Addr, Api, Binary, Coin, Empty, MessageInfo, Response, StdResult, Storage,
+};
+use cw_multi_test::{Bank, Distribution, Executor, Gov, Ibc, Module, Staking, Stargate, Wasm};
+use derivative::Derivative;
+use serde::de::DeserializeOwned;
+
+use super::{App, FailedContract};
+use crate::types::{CustomMsg, CustomQuery};
+
+type ExecHandler<Msg, C> = Arc<dyn Fn(&Msg, &MessageInfo) -> StdResult<Response<C>> + Send + Sync>;
+type QueryHandler<Msg> = Arc<dyn Fn(&Msg) -> StdResult<Binary> + Send + Sync>;
+type SudoHandler<Msg, C> = Arc<dyn Fn(&Msg) -> StdResult<Response<C>> + Send + Sync>;
+
+/// Execute message received by the [MockContract].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedMsg<Msg> {
+    pub contract: Addr,
+    pub sender: Addr,
+    pub funds: Vec<Coin>,
+    pub msg: Msg,
+}
+
+/// Sudo message received by the [MockContract].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedSudoMsg<Msg> {
+    pub contract: Addr,
+    pub msg: Msg,
+}
+
+struct MockState<ExecMsg, QueryMsg, SudoMsg, C> {
+    exec_handlers: HashMap<&'static str, ExecHandler<ExecMsg, C>>,
+    query_handlers: HashMap<&'static str, QueryHandler<QueryMsg>>,
+    sudo_handlers: HashMap<&'static str, SudoHandler<SudoMsg, C>>,
+    executed: Vec<ReceivedMsg<ExecMsg>>,
+    sudo: Vec<ReceivedSudoMsg<SudoMsg>>,
+}
+
+/// Contract answering the messages of an interface with the registered handlers.
+///
+/// Used by the `Mock` type generated by the `interface` macro in the `sv::mt` module, which
+/// registers the handlers of the particular messages by their names.
+/// The mock is stored in the [App] with [MockContract::store_code]. Its clones share the
+/// handlers and the received messages, so the handlers can be changed after the mock is stored.
+/// All the instances of the stored mock share them as well. The received messages record the
+/// address of the instance which received them.
+///
+/// Execute and sudo messages without a handler succeed with an empty response. Queries
+/// without a handler fail.
+///
+/// ```rust
+/// use sylvia::cw_std::{Response, StdError, StdResult};
+/// use sylvia::multitest::{App, ReceivedMsg};
+/// use sylvia::types::{ExecCtx, QueryCtx};
+///
+/// #[sylvia::interface]
+/// pub trait Counter {
+///     type Error: From<StdError>;
+///
+///     #[sv::msg(query)]
+///     fn count(&self, ctx: QueryCtx) -> StdResult<u64>;
+///
+///     #[sv::msg(exec)]
+///     fn increment(&self, ctx: ExecCtx, by: u64) -> StdResult<Response>;
+/// }
+///
+/// # fn main() {
+/// use sylvia::cw_multi_test::{Executor, IntoBech32};
+/// use sv::mt::CounterMock;
+///
+/// let app = App::default();
+/// let owner = "owner".into_bech32();
+/// let mock = CounterMock::new();
+/// let counter = mock.store_code(&app).instantiate(&owner, "Counter");
+///
+/// mock.with_count(5);
+/// let count: u64 = app
+///     .querier()
+///     .query_wasm_smart(&counter, &sv::QueryMsg::count())
+///     .unwrap();
+/// assert_eq!(count, 5);
+///
+/// app.app_mut()
+///     .execute_contract(owner.clone(), counter.clone(), &sv::ExecMsg::increment(3), &[])
+///     .unwrap();
+/// assert_eq!(
+///     mock.executed(),
+///     [ReceivedMsg {
+///         contract: counter,
+///         sender: owner,
+///         funds: vec![],
+///         msg: sv::ExecMsg::increment(3),
+///     }]
+/// );
+/// # }
+/// ```
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct MockContract<ExecMsg, QueryMsg, SudoMsg, C = Empty> {
+    state: Arc<Mutex<MockState<ExecMsg, QueryMsg, SudoMsg, C>>>,
+    exec_name: fn(&ExecMsg) -> &'static str,
+    query_name: fn(&QueryMsg) -> &'static str,
+    sudo_name: fn(&SudoMsg) -> &'static str,
+}
+
+impl<ExecMsg, QueryMsg, SudoMsg, C> MockContract<ExecMsg, QueryMsg, SudoMsg, C> {
+    /// Creates the mock reading the names of the messages with the given functions.
+    pub fn new(
+        exec_name: fn(&ExecMsg) -> &'static str,
+        query_name: fn(&QueryMsg) -> &'static str,
+        sudo_name: fn(&SudoMsg) -> &'static str,
+    ) -> Self {
+        let state = MockState {
+            exec_handlers: HashMap::new(),
+            query_handlers: HashMap::new(),
+            sudo_handlers: HashMap::new(),
+            executed: vec![],
+            sudo: vec![],
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+            exec_name,
+            query_name,
+            sudo_name,
+        }
+    }
+
+    /// Registers the handler of the execute message named `name`.
+    pub fn on_exec(
+        &self,
+        name: &'static str,
+        handler: impl Fn(&ExecMsg, &MessageInfo) -> StdResult<Response<C>> + Send + Sync + 'static,
+    ) -> &Self {
+        self.state().exec_handlers.insert(name, Arc::new(handler));
+        self
+    }
+
+    /// Registers the handler of the query message named `name`.
+    pub fn on_query(
+        &self,
+        name: &'static str,
+        handler: impl Fn(&QueryMsg) -> StdResult<Binary> + Send + Sync + 'static,
+    ) -> &Self {
+        self.state().query_handlers.insert(name, Arc::new(handler));
+        self
+    }
+
+    /// Registers the handler of the sudo message named `name`.
+    pub fn on_sudo(
+        &self,
+        name: &'static str,
+        handler: impl Fn(&SudoMsg) -> StdResult<Response<C>> + Send + Sync + 'static,
+    ) -> &Self {
+        self.state().sudo_handlers.insert(name, Arc::new(handler));
+        self
+    }
+
+    /// Returns the execute messages received by the mock, including the failed ones.
+    pub fn executed(&self) -> Vec<ReceivedMsg<ExecMsg>>
+    where
+        ExecMsg: Clone,
+    {
+        self.state().executed.clone()
+    }
+
+    /// Returns the sudo messages received by the mock, including the failed ones.
+    pub fn sudo_received(&self) -> Vec<ReceivedSudoMsg<SudoMsg>>
+    where
+        SudoMsg: Clone,
+    {
+        self.state().sudo.clone()
+    }
+
+    /// Stores the mock in the [App].
+    pub fn store_code<'app, MtApp, Q>(&self, app: &'app App<MtApp>) -> MockCodeId<'app, MtApp, C>
+    where
+        Self: cw_multi_test::Contract<C, Q> + 'static,
+        MtApp: StoreCode<C, Q>,
+        C: CustomMsg + 'static,
+        Q: CustomQuery + 'static,
+    {
+        let code_id = app.app_mut().store_code(Box::new(self.clone()));
+        MockCodeId {
+            code_id,
+            app,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MockState<ExecMsg, QueryMsg, SudoMsg, C>> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<ExecMsg, QueryMsg, SudoMsg, C, Q> cw_multi_test::Contract<C, Q>
+    for MockContract<ExecMsg, QueryMsg, SudoMsg, C>
+where
+    ExecMsg: DeserializeOwned + Clone,
+    QueryMsg: DeserializeOwned,
+    SudoMsg: DeserializeOwned + Clone,
+    C: CustomMsg + 'static,
+    Q: CustomQuery + 'static,
+{
+    fn execute(
+        &self,
+        _deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<C>> {
+        let msg: ExecMsg = from_json(msg)?;
+        let handler = {
+            let mut state = self.state();
+            state.executed.push(ReceivedMsg {
+                contract: env.contract.address.clone(),
+                sender: info.sender.clone(),
+                funds: info.funds.clone(),
+                msg: msg.clone(),
+            });
+            state.exec_handlers.get((self.exec_name)(&msg)).cloned()
+        };
+        match handler {
+            Some(handler) => handler(&msg, &info)
+                .map_err(|err| anyhow::Error::from(err).context(FailedContract::new(&env))),
+            None => Ok(Response::new()),
+        }
+    }
+
+    fn instantiate(
+        &self,
+        _deps: cosmwasm_std::DepsMut<Q>,
+        _env: cosmwasm_std::Env,
+        _info: MessageInfo,
+        _msg: Vec<u8>,
+    ) -> anyhow::Result<Response<C>> {
+        Ok(Response::new())
+    }
+
+    fn query(
+        &self,
+        _deps: cosmwasm_std::Deps<Q>,
+        _env: cosmwasm_std::Env,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Binary> {
+        let msg: QueryMsg = from_json(msg)?;
+        let name = (self.query_name)(&msg);
+        let handler = self.state().query_handlers.get(name).cloned();
+        match handler {
+            Some(handler) => Ok(handler(&msg)?),
+            None => anyhow::bail!("No response registered for the `{name}` query"),
+        }
+    }
+
+    fn sudo(
+        &self,
+        _deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response<C>> {
+        let msg: SudoMsg = from_json(msg)?;
+        let handler = {
+            let mut state = self.state();
+            state.sudo.push(ReceivedSudoMsg {
+                contract: env.contract.address.clone(),
+                msg: msg.clone(),
+            });
+            state.sudo_handlers.get((self.sudo_name)(&msg)).cloned()
+        };
+        match handler {
+            Some(handler) => handler(&msg)
+                .map_err(|err| anyhow::Error::from(err).context(FailedContract::new(&env))),
+            None => Ok(Response::new()),
+        }
+    }
+
+    fn reply(
+        &self,
+        _deps: cosmwasm_std::DepsMut<Q>,
+        _env: cosmwasm_std::Env,
+        _msg: cosmwasm_std::Reply,
+    ) -> anyhow::Result<Response<C>> {
+        anyhow::bail!("reply not implemented for mock contract")
+    }
+
+    fn migrate(
+        &self,
+        _deps: cosmwasm_std::DepsMut<Q>,
+        _env: cosmwasm_std::Env,
+        _msg: Vec<u8>,
+    ) -> anyhow::Result<Response<C>> {
+        anyhow::bail!("migrate not implemented for mock contract")
+    }
+}
+
+/// Storing the codes in the underlying `cw_multi_test::App`.
+///
+/// Used by [MockContract::store_code].
+pub trait StoreCode<ExecC, QueryC> {
+    fn store_code(&mut self, code: Box<dyn cw_multi_test::Contract<ExecC, QueryC>>) -> u64;
+}
+
+impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    StoreCode<CustomT::ExecT, CustomT::QueryT>
+    for cw_multi_test::App<
+        BankT,
+        ApiT,
+        StorageT,
+        CustomT,
+        WasmT,
+        StakingT,
+        DistrT,
+        IbcT,
+        GovT,
+        StargateT,
+    >
+where
+    CustomT::ExecT: CustomMsg + 'static,
+    CustomT::QueryT: CustomQuery + 'static,
+    WasmT: Wasm<CustomT::ExecT, CustomT::QueryT>,
+    BankT: Bank,
+    ApiT: Api,
+    StorageT: Storage,
+    CustomT: Module,
+    StakingT: Staking,
+    DistrT: Distribution,
+    IbcT: Ibc,
+    GovT: Gov,
+    StargateT: Stargate,
+{
+    fn store_code(
+        &mut self,
+        code: Box<dyn cw_multi_test::Contract<CustomT::ExecT, CustomT::QueryT>>,
+    ) -> u64 {
+        cw_multi_test::App::store_code(self, code)
+    }
+}
+
+/// Code of the [MockContract] stored in the [App].
+pub struct MockCodeId<'app, MtApp, ExecC> {
+    code_id: u64,
+    app: &'app App<MtApp>,
+    _phantom: PhantomData<ExecC>,
+}
+
+impl<'app, MtApp, ExecC> MockCodeId<'app, MtApp, ExecC>
+where
+    MtApp: Executor<ExecC>,
+    ExecC: cosmwasm_std::CustomMsg + 'static,
+{
+    pub fn code_id(&self) -> u64 {
+        self.code_id
+    }
+
+    /// Instantiates the mock, returning its address.
+    #[track_caller]
+    pub fn instantiate(&self, sender: &Addr, label: &str) -> Addr {
+        self.app
+            .app_mut()
+            .instantiate_contract(self.code_id, sender.clone(), &Empty {}, &[], label, None)
+            .unwrap()
+    }
+}
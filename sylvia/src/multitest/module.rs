@@ -0,0 +1,257 @@
+//! Contexts of the `cw_multi_test` custom modules generated by the [module](crate::module) macro.
+//!
+//! The handlers of the module receive the storage, api and block the module is called with,
+//! and the router to send messages and queries to the rest of the chain:
+//!
+//! ```rust,ignore
+//! #[sylvia::module]
+//! impl CounterModule {
+//!     #[sv::msg(exec)]
+//!     fn increment(&self, ctx: ModuleExecCtx) -> StdResult<AppResponse> {
+//!         self.counter.update(ctx.storage, |count| StdResult::Ok(count + 1))?;
+//!         Ok(AppResponse::default())
+//!     }
+//!
+//!     #[sv::msg(query)]
+//!     fn count(&self, ctx: ModuleQueryCtx) -> StdResult<u64> {
+//!         self.counter.load(ctx.storage)
+//!     }
+//! }
+//! ```
+
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{
+    from_json, to_json_vec, Addr, Api, Binary, BlockInfo, ContractResult, CosmosMsg, CustomMsg,
+    CustomQuery, Empty, Querier, QuerierResult, QuerierWrapper, QueryRequest, Storage, SystemError,
+    SystemResult,
+};
+use cw_multi_test::{AppResponse, CosmosRouter, SudoMsg};
+use serde::de::DeserializeOwned;
+
+/// Router of the chain the module is called from.
+///
+/// Messages and queries are passed without the custom variant, as the custom messages of the
+/// chain are not known to the module.
+pub trait ModuleRouter {
+    fn execute(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: CosmosMsg,
+    ) -> AnyResult<AppResponse>;
+
+    fn sudo(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: SudoMsg,
+    ) -> AnyResult<AppResponse>;
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        request: QueryRequest,
+    ) -> AnyResult<Binary>;
+}
+
+/// [ModuleRouter] forwarding to the `cw_multi_test` router working with the custom messages
+/// `ExecC` and `QueryC`.
+pub struct CustomRouter<'a, ExecC, QueryC> {
+    router: &'a dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+}
+
+impl<'a, ExecC, QueryC> CustomRouter<'a, ExecC, QueryC> {
+    pub fn new(router: &'a dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>) -> Self {
+        Self { router }
+    }
+}
+
+impl<ExecC, QueryC> ModuleRouter for CustomRouter<'_, ExecC, QueryC>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    fn execute(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: CosmosMsg,
+    ) -> AnyResult<AppResponse> {
+        let msg: CosmosMsg<ExecC> = from_json(to_json_vec(&msg)?)?;
+        self.router.execute(api, storage, block, sender, msg)
+    }
+
+    fn sudo(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        msg: SudoMsg,
+    ) -> AnyResult<AppResponse> {
+        self.router.sudo(api, storage, block, msg)
+    }
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        request: QueryRequest,
+    ) -> AnyResult<Binary> {
+        let request: QueryRequest<QueryC> = from_json(to_json_vec(&request)?)?;
+        self.router.query(api, storage, block, request)
+    }
+}
+
+/// [Querier] sending the queries through the [ModuleRouter].
+pub struct ModuleQuerier<'a> {
+    api: &'a dyn Api,
+    storage: &'a dyn Storage,
+    router: &'a dyn ModuleRouter,
+    block: &'a BlockInfo,
+}
+
+impl Querier for ModuleQuerier<'_> {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        let request: QueryRequest = match from_json(bin_request) {
+            Ok(request) => request,
+            Err(err) => {
+                return SystemResult::Err(SystemError::InvalidRequest {
+                    error: err.to_string(),
+                    request: bin_request.into(),
+                })
+            }
+        };
+        let result = self
+            .router
+            .query(self.api, self.storage, self.block, request)
+            .map_err(|err| err.to_string());
+        SystemResult::Ok(ContractResult::from(result))
+    }
+}
+
+/// Representation of the context the module receives with the `exec` message.
+pub struct ModuleExecCtx<'a> {
+    pub api: &'a dyn Api,
+    pub storage: &'a mut dyn Storage,
+    pub router: &'a dyn ModuleRouter,
+    pub block: &'a BlockInfo,
+    pub sender: Addr,
+}
+
+impl<'a> ModuleExecCtx<'a> {
+    pub fn new(
+        api: &'a dyn Api,
+        storage: &'a mut dyn Storage,
+        router: &'a dyn ModuleRouter,
+        block: &'a BlockInfo,
+        sender: Addr,
+    ) -> Self {
+        Self {
+            api,
+            storage,
+            router,
+            block,
+            sender,
+        }
+    }
+
+    /// Executes the message on the chain on behalf of the `sender`.
+    pub fn execute(&mut self, sender: Addr, msg: impl Into<CosmosMsg>) -> AnyResult<AppResponse> {
+        self.router
+            .execute(self.api, self.storage, self.block, sender, msg.into())
+    }
+
+    /// Calls the privileged action of the other module.
+    pub fn sudo(&mut self, msg: impl Into<SudoMsg>) -> AnyResult<AppResponse> {
+        self.router
+            .sudo(self.api, self.storage, self.block, msg.into())
+    }
+
+    /// Returns the [Querier] of the chain, to be wrapped in the [QuerierWrapper].
+    pub fn querier(&self) -> ModuleQuerier<'_> {
+        ModuleQuerier {
+            api: self.api,
+            storage: &*self.storage,
+            router: self.router,
+            block: self.block,
+        }
+    }
+}
+
+/// Representation of the context the module receives with the `sudo` message.
+pub struct ModuleSudoCtx<'a> {
+    pub api: &'a dyn Api,
+    pub storage: &'a mut dyn Storage,
+    pub router: &'a dyn ModuleRouter,
+    pub block: &'a BlockInfo,
+}
+
+impl<'a> ModuleSudoCtx<'a> {
+    pub fn new(
+        api: &'a dyn Api,
+        storage: &'a mut dyn Storage,
+        router: &'a dyn ModuleRouter,
+        block: &'a BlockInfo,
+    ) -> Self {
+        Self {
+            api,
+            storage,
+            router,
+            block,
+        }
+    }
+
+    /// Executes the message on the chain on behalf of the `sender`.
+    pub fn execute(&mut self, sender: Addr, msg: impl Into<CosmosMsg>) -> AnyResult<AppResponse> {
+        self.router
+            .execute(self.api, self.storage, self.block, sender, msg.into())
+    }
+
+    /// Calls the privileged action of the other module.
+    pub fn sudo(&mut self, msg: impl Into<SudoMsg>) -> AnyResult<AppResponse> {
+        self.router
+            .sudo(self.api, self.storage, self.block, msg.into())
+    }
+
+    /// Returns the [Querier] of the chain, to be wrapped in the [QuerierWrapper].
+    pub fn querier(&self) -> ModuleQuerier<'_> {
+        ModuleQuerier {
+            api: self.api,
+            storage: &*self.storage,
+            router: self.router,
+            block: self.block,
+        }
+    }
+}
+
+/// Representation of the context the module receives with the `query` message.
+pub struct ModuleQueryCtx<'a> {
+    pub api: &'a dyn Api,
+    pub storage: &'a dyn Storage,
+    pub querier: QuerierWrapper<'a, Empty>,
+    pub block: &'a BlockInfo,
+}
+
+impl<'a> ModuleQueryCtx<'a> {
+    pub fn new(
+        api: &'a dyn Api,
+        storage: &'a dyn Storage,
+        querier: &'a dyn Querier,
+        block: &'a BlockInfo,
+    ) -> Self {
+        Self {
+            api,
+            storage,
+            querier: QuerierWrapper::new(querier),
+            block,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Snapshots of the state of the chain, used to roll back the changes made by the tests.
+
+use cosmwasm_std::{Api, BlockInfo, Order, Storage};
+use cw_multi_test::{Bank, Distribution, Gov, Ibc, Module, Staking, Stargate, Wasm};
+
+use super::App;
+use crate::types::{CustomMsg, CustomQuery};
+
+impl<MtApp: AppState> App<MtApp> {
+    /// Saves the current state of the chain, which can be brought back with [App::restore].
+    pub fn snapshot(&self) -> AppSnapshot {
+        self.app.borrow().snapshot()
+    }
+
+    /// Brings back the state of the chain saved with [App::snapshot].
+    /// The same snapshot can be restored multiple times.
+    pub fn restore(&self, snapshot: &AppSnapshot) {
+        self.app.borrow_mut().restore(snapshot)
+    }
+
+    /// Calls the `action` and reverts all the changes it made to the state of the chain.
+    ///
+    /// The state is reverted also if the `action` panics. Only the state covered by the
+    /// [AppSnapshot] is reverted.
+    pub fn dry_run<T>(&self, action: impl FnOnce(&Self) -> T) -> T {
+        struct Revert<'app, MtApp: AppState> {
+            app: &'app App<MtApp>,
+            snapshot: AppSnapshot,
+        }
+
+        impl<MtApp: AppState> Drop for Revert<'_, MtApp> {
+            fn drop(&mut self) {
+                self.app.restore(&self.snapshot);
+            }
+        }
+
+        let _revert = Revert {
+            app: self,
+            snapshot: self.snapshot(),
+        };
+        action(self)
+    }
+}
+
+/// State of the chain saved with [App::snapshot].
+///
+/// Covers:
+/// - the storage of the `cw_multi_test::App`, which holds the bank balances, the staking and
+///   distribution state, the instantiated contracts along with their storage and the state of
+///   the custom modules kept in the storage passed to them,
+/// - the block info.
+///
+/// Doesn't cover:
+/// - the codes stored on the chain, which stay available after [App::restore],
+/// - the state the modules keep outside of the storage, e.g. in their own fields,
+/// - the invariants registered in the [App] and the messages recorded for the coverage report.
+///
+/// ```rust
+/// use cw_storage_plus::Item;
+/// use sylvia::cw_std::{Response, StdResult};
+/// use sylvia::multitest::App;
+/// use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+///
+/// pub struct Counter {
+///     count: Item<u64>,
+/// }
+///
+/// #[sylvia::contract]
+/// impl Counter {
+///     pub const fn new() -> Self {
+///         Self { count: Item::new("count") }
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+///         self.count.save(ctx.deps.storage, &0)?;
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         self.count.update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(query)]
+///     fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+///         self.count.load(ctx.deps.storage)
+///     }
+/// }
+///
+/// # fn main() {
+/// use sylvia::cw_multi_test::IntoBech32;
+/// use sv::mt::{CodeId, CounterProxy};
+///
+/// let app = App::default();
+/// let owner = "owner".into_bech32();
+/// let contract = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+///
+/// let snapshot = app.snapshot();
+/// contract.increment().call(&owner).unwrap();
+/// assert_eq!(contract.count().unwrap(), 1);
+///
+/// app.restore(&snapshot);
+/// assert_eq!(contract.count().unwrap(), 0);
+///
+/// contract.increment().dry_run(&owner).unwrap();
+/// assert_eq!(contract.count().unwrap(), 0);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AppSnapshot {
+    storage: Vec<(Vec<u8>, Vec<u8>)>,
+    block: BlockInfo,
+}
+
+/// Saving and restoring the state of the underlying `cw_multi_test::App`.
+///
+/// Used by [App::snapshot], [App::restore] and the `dry_run` methods of the proxies.
+pub trait AppState {
+    fn snapshot(&self) -> AppSnapshot;
+    fn restore(&mut self, snapshot: &AppSnapshot);
+}
+
+impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT> AppState
+    for cw_multi_test::App<
+        BankT,
+        ApiT,
+        StorageT,
+        CustomT,
+        WasmT,
+        StakingT,
+        DistrT,
+        IbcT,
+        GovT,
+        StargateT,
+    >
+where
+    CustomT::ExecT: CustomMsg + 'static,
+    CustomT::QueryT: CustomQuery + 'static,
+    WasmT: Wasm<CustomT::ExecT, CustomT::QueryT>,
+    BankT: Bank,
+    ApiT: Api,
+    StorageT: Storage,
+    CustomT: Module,
+    StakingT: Staking,
+    DistrT: Distribution,
+    IbcT: Ibc,
+    GovT: Gov,
+    StargateT: Stargate,
+{
+    fn snapshot(&self) -> AppSnapshot {
+        AppSnapshot {
+            storage: self.storage().range(None, None, Order::Ascending).collect(),
+            block: self.block_info(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: &AppSnapshot) {
+        let storage = self.storage_mut();
+        let keys: Vec<_> = storage.range_keys(None, None, Order::Ascending).collect();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in &snapshot.storage {
+            storage.set(key, value);
+        }
+        self.set_block(snapshot.block.clone());
+    }
+}
@@ -0,0 +1,239 @@
+//! Atomic transactions of multiple messages sent to the chain.
+
+use std::convert::Infallible;
+use std::fmt::{Debug, Display};
+
+use cosmwasm_std::{to_json_binary, Addr, BankMsg, Coin, CosmosMsg, StdResult, WasmMsg};
+use cw_multi_test::Executor;
+use serde::Serialize;
+
+use super::{App, AppState, ExecProxy, FailedContract, MigrateProxy, MtError};
+use crate::types::NamespacedMsg;
+
+impl<MtApp> App<MtApp> {
+    /// Creates the [Transaction] sending multiple messages atomically.
+    pub fn transaction<ExecC>(&self) -> Transaction<'_, MtApp, ExecC>
+    where
+        ExecC: cosmwasm_std::CustomMsg + 'static,
+        MtApp: Executor<ExecC>,
+    {
+        Transaction::new(self)
+    }
+}
+
+/// Message of the generated `InstantiateProxy` sent as a part of the [Transaction].
+pub trait TransactionMsg<ExecC> {
+    fn into_msg(self) -> StdResult<CosmosMsg<ExecC>>;
+}
+
+/// Messages sent to the chain atomically, created with [App::transaction].
+///
+/// The messages are executed in order. If any of the messages fails or violates the invariants
+/// of the contracts which processed it, the changes of all the messages are reverted.
+///
+/// Unlike `cw_multi_test::App::execute_multi`, which takes a single sender, the messages can be
+/// sent by different senders. They are therefore executed one by one and the changes are
+/// reverted by restoring the [AppSnapshot] taken before the first message. Only the `Storage`
+/// and the block info are rolled back. State kept outside of the `Storage`, such as the internals
+/// of a custom module, is not.
+///
+/// ```rust
+/// use cw_storage_plus::Item;
+/// use sylvia::cw_std::{coins, Response, StdResult};
+/// use sylvia::multitest::App;
+/// use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+///
+/// pub struct Counter {
+///     count: Item<u64>,
+/// }
+///
+/// #[sylvia::contract]
+/// impl Counter {
+///     pub const fn new() -> Self {
+///         Self { count: Item::new("count") }
+///     }
+///
+///     #[sv::msg(instantiate)]
+///     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+///         self.count.save(ctx.deps.storage, &0)?;
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         self.count.update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(query)]
+///     fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+///         self.count.load(ctx.deps.storage)
+///     }
+/// }
+///
+/// # fn main() {
+/// use sylvia::cw_multi_test::IntoBech32;
+/// use sv::mt::{CodeId, CounterProxy};
+///
+/// let app = App::default();
+/// let owner = "owner".into_bech32();
+/// let user = "user".into_bech32();
+/// let contract = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+///
+/// let responses = app
+///     .transaction()
+///     .execute(&owner, contract.increment())
+///     .execute(&user, contract.increment())
+///     .call()
+///     .unwrap();
+/// assert_eq!(responses.len(), 2);
+/// assert_eq!(contract.count().unwrap(), 2);
+///
+/// // The owner has no funds, so the whole transaction is reverted.
+/// app.transaction()
+///     .execute(&owner, contract.increment())
+///     .send_tokens(&owner, &user, &coins(10, "atom"))
+///     .call()
+///     .unwrap_err();
+/// assert_eq!(contract.count().unwrap(), 2);
+/// # }
+/// ```
+#[must_use]
+pub struct Transaction<'app, MtApp, ExecC> {
+    app: &'app App<MtApp>,
+    msgs: Vec<(Addr, StdResult<CosmosMsg<ExecC>>)>,
+}
+
+impl<'app, MtApp, ExecC> Transaction<'app, MtApp, ExecC>
+where
+    ExecC: cosmwasm_std::CustomMsg + 'static,
+    MtApp: Executor<ExecC>,
+{
+    pub fn new(app: &'app App<MtApp>) -> Self {
+        Self { app, msgs: vec![] }
+    }
+
+    /// Adds the execute message of the [ExecProxy].
+    pub fn execute<Error, Msg>(
+        mut self,
+        sender: &Addr,
+        proxy: ExecProxy<'_, '_, Error, Msg, MtApp, ExecC>,
+    ) -> Self
+    where
+        Msg: Serialize + Debug,
+        Error: Debug + Display + Send + Sync + 'static,
+    {
+        let msg = to_json_binary(&NamespacedMsg::new(proxy.namespace, &proxy.msg)).map(|msg| {
+            WasmMsg::Execute {
+                contract_addr: proxy.contract_addr.to_string(),
+                msg,
+                funds: proxy.funds.to_vec(),
+            }
+            .into()
+        });
+        self.msgs.push((sender.clone(), msg));
+        self
+    }
+
+    /// Adds the instantiate message of the generated `InstantiateProxy`.
+    pub fn instantiate(mut self, sender: &Addr, proxy: impl TransactionMsg<ExecC>) -> Self {
+        self.msgs.push((sender.clone(), proxy.into_msg()));
+        self
+    }
+
+    /// Adds the message migrating the contract of the [MigrateProxy] to the `new_code_id`.
+    pub fn migrate<Error, Msg>(
+        mut self,
+        sender: &Addr,
+        proxy: MigrateProxy<'_, '_, Error, Msg, MtApp, ExecC>,
+        new_code_id: u64,
+    ) -> Self
+    where
+        Msg: Serialize + Debug,
+        Error: Debug + Display + Send + Sync + 'static,
+    {
+        let msg = to_json_binary(&proxy.msg).map(|msg| {
+            WasmMsg::Migrate {
+                contract_addr: proxy.contract_addr.to_string(),
+                new_code_id,
+                msg,
+            }
+            .into()
+        });
+        self.msgs.push((sender.clone(), msg));
+        self
+    }
+
+    /// Adds the bank message sending the `amount` to the `recipient`.
+    pub fn send_tokens(mut self, sender: &Addr, recipient: &Addr, amount: &[Coin]) -> Self {
+        let msg = BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: amount.to_vec(),
+        };
+        self.msgs.push((sender.clone(), Ok(msg.into())));
+        self
+    }
+
+    /// Adds any other message.
+    pub fn add_message(mut self, sender: &Addr, msg: impl Into<CosmosMsg<ExecC>>) -> Self {
+        self.msgs.push((sender.clone(), Ok(msg.into())));
+        self
+    }
+
+    /// Sends the messages, returning the [AppResponse](cw_multi_test::AppResponse) of every
+    /// message in order. Reverts all the messages on the first failure.
+    ///
+    /// No contract is called directly by the transaction, so the errors returned by the contracts
+    /// are reported as [MtError::ForeignContract] along with the address of the failing contract.
+    pub fn call(self) -> Result<Vec<cw_multi_test::AppResponse>, MtError<Infallible>>
+    where
+        MtApp: AppState,
+    {
+        let app = self.app;
+        let snapshot = app.snapshot();
+        let result = self.send();
+        if result.is_err() {
+            app.restore(&snapshot);
+        }
+        result
+    }
+
+    fn send(self) -> Result<Vec<cw_multi_test::AppResponse>, MtError<Infallible>> {
+        self.msgs
+            .into_iter()
+            .map(|(sender, msg)| {
+                let msg = msg.map_err(|err| MtError::System(err.into()))?;
+                let resp =
+                    self.app
+                        .app_mut()
+                        .execute(sender, msg)
+                        .map_err(|error| match error.downcast_ref::<FailedContract>() {
+                            Some(FailedContract { contract }) => MtError::ForeignContract {
+                                contract: Some(contract.clone()),
+                                error,
+                            },
+                            None => MtError::from_anyhow(error, None),
+                        })?;
+                processed_contracts(&resp).iter().try_for_each(|contract| {
+                    self.app
+                        .try_check_invariants(contract)
+                        .map_err(MtError::InvariantViolated)
+                })?;
+                Ok(resp)
+            })
+            .collect()
+    }
+}
+
+/// Lists the contracts which processed the message, in the order of the events they emitted.
+fn processed_contracts(resp: &cw_multi_test::AppResponse) -> Vec<Addr> {
+    let mut contracts: Vec<Addr> = vec![];
+    for attr in resp.events.iter().flat_map(|event| &event.attributes) {
+        if attr.key == "_contract_address"
+            && contracts.iter().all(|addr| addr.as_str() != attr.value)
+        {
+            contracts.push(Addr::unchecked(&attr.value));
+        }
+    }
+    contracts
+}
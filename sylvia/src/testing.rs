@@ -0,0 +1,142 @@
+//! Utilities for unit testing the contracts with the `cosmwasm_std::testing` mocks.
+//!
+//! The `contract` and `interface` macros generate the `sv::QueryHandlers` type, registering
+//! typed handlers of their query messages. The handlers are assigned to the contract addresses
+//! with [WasmQueryHandlers] and installed in the [MockQuerier], so the queries sent through
+//! the [BoundQuerier](crate::types::BoundQuerier) are answered without the multitest.
+//!
+//! The `QueryHandlers` type is not generated for the generic contracts and for the interfaces
+//! with associated types other than `Error`. The interface handlers match the plain messages
+//! only, so the queries of an interface registered with a `namespace` are not answered.
+//!
+//! ```rust,ignore
+//! let mut deps = mock_dependencies();
+//! WasmQueryHandlers::new()
+//!     .with_contract(
+//!         "cw4",
+//!         cw4::sv::QueryHandlers::new()
+//!             .with_total_weight(TotalWeightResponse { weight: 10 })
+//!             .on_member(|addr, _| Ok(MemberResponse { weight: (addr == "alice").then_some(5) })),
+//!     )
+//!     .install(&mut deps.querier);
+//! ```
+
+use std::collections::HashMap;
+
+use cosmwasm_std::testing::MockQuerier;
+use cosmwasm_std::{
+    from_json, Binary, ContractResult, CustomQuery, QuerierResult, StdError, StdResult,
+    SystemError, SystemResult, WasmQuery,
+};
+use serde::de::DeserializeOwned;
+
+type QueryHandler<Msg> = Box<dyn Fn(&Msg) -> StdResult<Binary>>;
+
+/// Handlers of the query messages of type `Msg`, keyed by the message names.
+///
+/// Usually created through the `sv::QueryHandlers` type generated by the `contract` and
+/// `interface` macros.
+pub struct QueryHandlers<Msg> {
+    msg_name: fn(&Msg) -> &'static str,
+    handlers: HashMap<&'static str, QueryHandler<Msg>>,
+}
+
+impl<Msg> QueryHandlers<Msg> {
+    /// Creates the handlers reading the names of the messages with the given function.
+    pub fn new(msg_name: fn(&Msg) -> &'static str) -> Self {
+        Self {
+            msg_name,
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Registers the handler of the query message named `name`.
+    pub fn on(
+        mut self,
+        name: &'static str,
+        handler: impl Fn(&Msg) -> StdResult<Binary> + 'static,
+    ) -> Self {
+        self.handlers.insert(name, Box::new(handler));
+        self
+    }
+}
+
+/// Handler of the smart queries sent to a mocked contract.
+pub trait SmartQueryHandler {
+    /// Answers the query, or returns `None` if the handler doesn't support it.
+    fn handle(&self, msg: &[u8]) -> Option<StdResult<Binary>>;
+}
+
+impl<Msg: DeserializeOwned> SmartQueryHandler for QueryHandlers<Msg> {
+    fn handle(&self, msg: &[u8]) -> Option<StdResult<Binary>> {
+        let msg: Msg = from_json(msg).ok()?;
+        let handler = self.handlers.get((self.msg_name)(&msg))?;
+        Some(handler(&msg))
+    }
+}
+
+/// Smart query handlers of the mocked contracts.
+///
+/// Queries sent to the addresses without the handlers fail with [SystemError::NoSuchContract].
+/// Queries not supported by any of the handlers of the contract fail with the contract error.
+#[derive(Default)]
+pub struct WasmQueryHandlers {
+    contracts: HashMap<String, Vec<Box<dyn SmartQueryHandler>>>,
+}
+
+impl WasmQueryHandlers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the handler of the queries sent to the `contract`.
+    ///
+    /// Every contract can have multiple handlers, f.e. one per implemented interface.
+    /// They are tried in order of registration.
+    pub fn with_contract(
+        mut self,
+        contract: impl Into<String>,
+        handler: impl SmartQueryHandler + 'static,
+    ) -> Self {
+        self.contracts
+            .entry(contract.into())
+            .or_default()
+            .push(Box::new(handler));
+        self
+    }
+
+    /// Answers the wasm query.
+    pub fn handle(&self, query: &WasmQuery) -> QuerierResult {
+        let WasmQuery::Smart { contract_addr, msg } = query else {
+            return SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "Only smart queries are supported by the query handlers".to_owned(),
+            });
+        };
+        let Some(handlers) = self.contracts.get(contract_addr) else {
+            return SystemResult::Err(SystemError::NoSuchContract {
+                addr: contract_addr.clone(),
+            });
+        };
+        let result = handlers
+            .iter()
+            .find_map(|handler| handler.handle(msg))
+            .unwrap_or_else(|| {
+                Err(StdError::generic_err(format!(
+                    "No handler registered for the query {}",
+                    String::from_utf8_lossy(msg)
+                )))
+            });
+        match result {
+            Ok(response) => SystemResult::Ok(ContractResult::Ok(response)),
+            Err(err) => SystemResult::Ok(ContractResult::Err(err.to_string())),
+        }
+    }
+
+    /// Installs the handlers as the wasm query handler of the [MockQuerier].
+    pub fn install<C>(self, querier: &mut MockQuerier<C>)
+    where
+        C: CustomQuery + DeserializeOwned,
+    {
+        querier.update_wasm(move |query| self.handle(query));
+    }
+}
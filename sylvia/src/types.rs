@@ -1,9 +1,17 @@
 //! Module providing utilities to build and use sylvia contracts.
-use cosmwasm_std::{Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, WasmMsg};
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Binary, Coin, ContractResult, CosmosMsg, Deps, DepsMut,
+    Empty, Env, Event, MessageInfo, QueryRequest, ReplyOn, StdError, StdResult, SubMsg,
+    SystemResult, WasmMsg, WasmQuery,
+};
 use derivative::Derivative;
+use schemars::schema::RootSchema;
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+
+pub use sylvia_derive::TypedEvent;
 
 /// Wrapper around [QuerierWrapper](cosmwasm_std::QuerierWrapper) for more user friendly query experience.
 /// Most of the implementation should be provided via traits.
@@ -150,6 +158,93 @@ impl<'a, C: cosmwasm_std::CustomQuery, Contract: ?Sized> BoundQuerier<'a, C, Con
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Sends the smart query to the contract, keeping the failures apart in the [QueryError].
+    ///
+    /// Used by the `Querier` traits generated for the contracts and interfaces marked with
+    /// `#[sv::typed_query_errors]`.
+    pub fn query_typed<T: DeserializeOwned>(&self, msg: &impl Serialize) -> Result<T, QueryError> {
+        let request = QueryRequest::<C>::Wasm(WasmQuery::Smart {
+            contract_addr: self.contract.to_string(),
+            msg: to_json_binary(msg).map_err(|err| QueryError::System(err.to_string()))?,
+        });
+        let request = to_json_vec(&request).map_err(|err| QueryError::System(err.to_string()))?;
+
+        match cosmwasm_std::Querier::raw_query(&**self.querier, &request) {
+            SystemResult::Err(err) => Err(QueryError::System(err.to_string())),
+            SystemResult::Ok(ContractResult::Err(err)) => Err(QueryError::Contract(err)),
+            SystemResult::Ok(ContractResult::Ok(response)) => {
+                from_json(response).map_err(QueryError::Deserialization)
+            }
+        }
+    }
+}
+
+/// Error of the query sent with the `Querier` generated for the contracts and interfaces
+/// marked with `#[sv::typed_query_errors]`.
+#[derive(Debug)]
+pub enum QueryError {
+    /// The querier failed to handle the query, f.e. the contract doesn't exist.
+    System(String),
+    /// The queried contract returned an error with this message.
+    Contract(String),
+    /// The response of the contract couldn't be deserialized into the expected type.
+    Deserialization(StdError),
+}
+
+impl QueryError {
+    /// Decodes the message of the [QueryError::Contract] into the error type of the queried
+    /// contract. Returns `None` for other errors or if the message couldn't be decoded.
+    ///
+    /// ```rust
+    /// use sylvia::types::QueryError;
+    ///
+    /// let err = QueryError::Contract("42".to_owned());
+    /// assert_eq!(err.decode_contract_error::<u32>(), Some(42));
+    /// ```
+    pub fn decode_contract_error<E: DecodeError>(&self) -> Option<E> {
+        match self {
+            QueryError::Contract(msg) => E::decode(msg),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryError::System(msg) => write!(f, "Querier system error: {msg}"),
+            QueryError::Contract(msg) => write!(f, "Querier contract error: {msg}"),
+            QueryError::Deserialization(err) => write!(f, "Query response error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// Converts into the [StdError] returned by [QuerierWrapper](cosmwasm_std::QuerierWrapper)
+/// for the same failure.
+impl From<QueryError> for StdError {
+    fn from(err: QueryError) -> Self {
+        match err {
+            QueryError::Deserialization(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// Decoding of the error message returned by the queried contract, see
+/// [QueryError::decode_contract_error].
+///
+/// Implemented for every type implementing [FromStr](std::str::FromStr).
+pub trait DecodeError: Sized {
+    fn decode(msg: &str) -> Option<Self>;
+}
+
+impl<T: std::str::FromStr> DecodeError for T {
+    fn decode(msg: &str) -> Option<Self> {
+        msg.parse().ok()
+    }
 }
 
 impl<'a, C: cosmwasm_std::CustomQuery, Contract> From<&'a BoundQuerier<'a, C, Contract>>
@@ -260,10 +355,30 @@ pub struct ReadyExecutorBuilderState;
 ///     Ok(Response::new().add_message(msg))
 /// }
 /// ```
+///
+/// The built message can also be sent as a [SubMsg] expecting a reply:
+///
+/// ```rust
+/// # use cosmwasm_std::{Addr, Binary, ReplyOn, Response, StdError, StdResult};
+/// # use sylvia::types::{ExecutorBuilder, ReadyExecutorBuilderState};
+/// #
+/// const DECREASE_REPLY_ID: u64 = 1;
+///
+/// fn execute_method(msg: ExecutorBuilder<ReadyExecutorBuilderState>) -> Response {
+///     let sub_msg = msg
+///         .with_gas_limit(100_000)
+///         .with_payload(Binary::from(b"decrease"))
+///         .reply_on_success(DECREASE_REPLY_ID);
+///     assert_eq!(sub_msg.reply_on, ReplyOn::Success);
+///     Response::new().add_submessage(sub_msg)
+/// }
+/// ```
 pub struct ExecutorBuilder<State: ?Sized> {
     contract: String,
     funds: Vec<Coin>,
     msg: Binary,
+    gas_limit: Option<u64>,
+    payload: Binary,
     _state: std::marker::PhantomData<State>,
 }
 
@@ -275,6 +390,8 @@ impl<Contract: ?Sized> ExecutorBuilder<(EmptyExecutorBuilderState, Contract)> {
             contract: contract.to_string(),
             funds: vec![],
             msg: Binary::default(),
+            gas_limit: None,
+            payload: Binary::default(),
             _state: std::marker::PhantomData,
         }
     }
@@ -303,10 +420,29 @@ impl ExecutorBuilder<ReadyExecutorBuilderState> {
             contract,
             funds,
             msg,
+            gas_limit: None,
+            payload: Binary::default(),
             _state: std::marker::PhantomData,
         }
     }
 
+    /// Sets the gas limit of the [SubMsg] built with one of the `reply_*` methods.
+    pub fn with_gas_limit(self, gas_limit: u64) -> Self {
+        Self {
+            gas_limit: Some(gas_limit),
+            ..self
+        }
+    }
+
+    /// Sets the payload passed back in the [Reply](cosmwasm_std::Reply) to the [SubMsg]
+    /// built with one of the `reply_*` methods.
+    pub fn with_payload(self, payload: impl Into<Binary>) -> Self {
+        Self {
+            payload: payload.into(),
+            ..self
+        }
+    }
+
     pub fn build(self) -> WasmMsg {
         WasmMsg::Execute {
             contract_addr: self.contract,
@@ -314,6 +450,33 @@ impl ExecutorBuilder<ReadyExecutorBuilderState> {
             funds: self.funds,
         }
     }
+
+    /// Builds the [SubMsg] replying with the `id` if the execution succeeds.
+    pub fn reply_on_success<C>(self, id: u64) -> SubMsg<C> {
+        self.build_sub_msg(id, ReplyOn::Success)
+    }
+
+    /// Builds the [SubMsg] replying with the `id` if the execution fails.
+    pub fn reply_on_error<C>(self, id: u64) -> SubMsg<C> {
+        self.build_sub_msg(id, ReplyOn::Error)
+    }
+
+    /// Builds the [SubMsg] replying with the `id` regardless of the execution result.
+    pub fn reply_always<C>(self, id: u64) -> SubMsg<C> {
+        self.build_sub_msg(id, ReplyOn::Always)
+    }
+
+    fn build_sub_msg<C>(mut self, id: u64, reply_on: ReplyOn) -> SubMsg<C> {
+        let gas_limit = self.gas_limit;
+        let payload = std::mem::take(&mut self.payload);
+        SubMsg {
+            id,
+            payload,
+            msg: CosmosMsg::Wasm(self.build()),
+            gas_limit,
+            reply_on,
+        }
+    }
 }
 
 /// Represents a contract on the chain and acts as a gateway to communicate with it.
@@ -441,21 +604,188 @@ impl<'a, Contract: ?Sized> AsRef<cosmwasm_std::Addr> for Remote<'a, Contract> {
     }
 }
 
-/// Represantation of `reply` context received in entry point as
+/// Represents a code of the contract stored on the chain. Builds the messages instantiating
+/// the new instances of the contract or migrating the existing ones to it, using the
+/// `InstantiateMsg` and `MigrateMsg` generated for the contract.
+///
+/// ```rust
+/// pub mod another_contract {
+///     # use cosmwasm_std::{Response, StdResult};
+///     # use sylvia::contract;
+///     # use sylvia::types::InstantiateCtx;
+///     pub struct AnotherContract {}
+///
+///     #[contract]
+///     impl AnotherContract {
+///         pub fn new() -> Self { Self {} }
+///
+///         #[sv::msg(instantiate)]
+///         fn instantiate(&self, ctx: InstantiateCtx, admin: String) -> StdResult<Response> {
+///             Ok(Response::new())
+///         }
+///     }
+/// }
+/// # use cosmwasm_std::{coin, Response, StdResult};
+/// # use sylvia::types::RemoteCode;
+/// # use another_contract::AnotherContract;
+/// # use another_contract::sv::InstantiateMsg;
+///
+/// fn spawn_contract(code_id: u64, admin: String) -> StdResult<Response> {
+///     let msg = RemoteCode::<AnotherContract>::new(code_id)
+///         .instantiate(&InstantiateMsg::new(admin.clone()))?
+///         .with_funds(vec![coin(100, "atom")])
+///         .with_label("Another contract")
+///         .with_admin(admin.as_str())
+///         .build();
+///     Ok(Response::new().add_message(msg))
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Derivative)]
+#[derivative(Clone, Debug, PartialEq)]
+pub struct RemoteCode<Contract: ?Sized> {
+    code_id: u64,
+    #[serde(skip)]
+    _phantom: std::marker::PhantomData<Contract>,
+}
+
+impl<Contract: ?Sized> RemoteCode<Contract> {
+    /// Creates a new instance of [RemoteCode] from the code id.
+    pub fn new(code_id: u64) -> Self {
+        Self {
+            code_id,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the underlying code id.
+    pub fn code_id(&self) -> u64 {
+        self.code_id
+    }
+}
+
+impl<Contract: ContractApi + ?Sized> RemoteCode<Contract> {
+    /// Creates the [InstantiateBuilder] of the new contract instance.
+    pub fn instantiate(&self, msg: &Contract::Instantiate) -> StdResult<InstantiateBuilder>
+    where
+        Contract::Instantiate: Serialize,
+    {
+        Ok(InstantiateBuilder::new(self.code_id, to_json_binary(msg)?))
+    }
+
+    /// Builds the message migrating the `contract` to this code.
+    pub fn migrate(
+        &self,
+        contract: &cosmwasm_std::Addr,
+        msg: &Contract::Migrate,
+    ) -> StdResult<WasmMsg>
+    where
+        Contract::Migrate: Serialize,
+    {
+        Ok(WasmMsg::Migrate {
+            contract_addr: contract.to_string(),
+            new_code_id: self.code_id,
+            msg: to_json_binary(msg)?,
+        })
+    }
+}
+
+/// Builder of the message instantiating a contract, created with [RemoteCode::instantiate].
+///
+/// Like in the multitest `InstantiateProxy`, the label defaults to `"Contract"` and the contract
+/// has no admin unless set.
+pub struct InstantiateBuilder {
+    code_id: u64,
+    msg: Binary,
+    funds: Vec<Coin>,
+    label: String,
+    admin: Option<String>,
+}
+
+impl InstantiateBuilder {
+    pub fn new(code_id: u64, msg: Binary) -> Self {
+        Self {
+            code_id,
+            msg,
+            funds: vec![],
+            label: "Contract".to_owned(),
+            admin: None,
+        }
+    }
+
+    /// Adds the funds to the instantiate message.
+    pub fn with_funds(self, funds: Vec<Coin>) -> Self {
+        Self { funds, ..self }
+    }
+
+    /// Sets the label of the new contract.
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..self
+        }
+    }
+
+    /// Sets the admin allowed to migrate the new contract.
+    pub fn with_admin<'a>(self, admin: impl Into<Option<&'a str>>) -> Self {
+        let admin = admin.into().map(str::to_owned);
+        Self { admin, ..self }
+    }
+
+    /// Builds the [WasmMsg::Instantiate].
+    pub fn build(self) -> WasmMsg {
+        WasmMsg::Instantiate {
+            admin: self.admin,
+            code_id: self.code_id,
+            msg: self.msg,
+            funds: self.funds,
+            label: self.label,
+        }
+    }
+
+    /// Builds the [WasmMsg::Instantiate2] along with the address of the new contract,
+    /// predicted from the `creator` of the message, the `checksum` of the code and the `salt`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "cosmwasm_1_2")))]
+    #[cfg(feature = "cosmwasm_1_2")]
+    pub fn build2(
+        self,
+        api: &dyn cosmwasm_std::Api,
+        creator: &cosmwasm_std::Addr,
+        checksum: &cosmwasm_std::Checksum,
+        salt: impl Into<Binary>,
+    ) -> StdResult<(WasmMsg, cosmwasm_std::Addr)> {
+        let salt = salt.into();
+        let creator = api.addr_canonicalize(creator.as_str())?;
+        let address = cosmwasm_std::instantiate2_address(checksum.as_slice(), &creator, &salt)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let address = api.addr_humanize(&address)?;
+
+        let msg = WasmMsg::Instantiate2 {
+            admin: self.admin,
+            code_id: self.code_id,
+            label: self.label,
+            msg: self.msg,
+            funds: self.funds,
+            salt,
+        };
+        Ok((msg, address))
+    }
+}
+
+/// Representation of `reply` context received in entry point as
 /// (DepsMut, Env) tuple.
 pub struct ReplyCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: DepsMut<'a, C>,
     pub env: Env,
 }
 
-/// Represantation of `reply` context received in entry point as
+/// Representation of `reply` context received in entry point as
 /// (DepsMut, Env) tuple.
 pub struct MigrateCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: DepsMut<'a, C>,
     pub env: Env,
 }
 
-/// Represantation of `reply` context received in entry point as
+/// Representation of `reply` context received in entry point as
 /// (DepsMut, Env, MessageInfo) tuple.
 pub struct ExecCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: DepsMut<'a, C>,
@@ -463,7 +793,7 @@ pub struct ExecCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub info: MessageInfo,
 }
 
-/// Represantation of `instantiate` context received in entry point as
+/// Representation of `instantiate` context received in entry point as
 /// (DepsMut, Env, MessageInfo) tuple.
 pub struct InstantiateCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: DepsMut<'a, C>,
@@ -471,14 +801,14 @@ pub struct InstantiateCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub info: MessageInfo,
 }
 
-/// Represantation of `query` context received in entry point as
+/// Representation of `query` context received in entry point as
 /// (Deps, Env) tuple.
 pub struct QueryCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: Deps<'a, C>,
     pub env: Env,
 }
 
-/// Represantation of `sudo` context received in entry point as
+/// Representation of `sudo` context received in entry point as
 /// (DepsMut, Env) tuple.
 pub struct SudoCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: DepsMut<'a, C>,
@@ -552,6 +882,35 @@ impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for SudoCtx<'
     }
 }
 
+/// Defines the contexts of the IBC entry points, received as (DepsMut, Env) tuple.
+macro_rules! ibc_ctx {
+    ($($ctx:ident => $entry_point:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Representation of `", $entry_point, "` context received in entry point as")]
+            /// (DepsMut, Env) tuple.
+            pub struct $ctx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+                pub deps: DepsMut<'a, C>,
+                pub env: Env,
+            }
+
+            impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for $ctx<'a, C> {
+                fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+                    Self { deps, env }
+                }
+            }
+        )*
+    };
+}
+
+ibc_ctx! {
+    IbcChannelOpenCtx => "ibc_channel_open",
+    IbcChannelConnectCtx => "ibc_channel_connect",
+    IbcChannelCloseCtx => "ibc_channel_close",
+    IbcPacketReceiveCtx => "ibc_packet_receive",
+    IbcPacketAckCtx => "ibc_packet_ack",
+    IbcPacketTimeoutCtx => "ibc_packet_timeout",
+}
+
 /// Set of trait bounds for custom messages.
 pub trait CustomMsg: cosmwasm_std::CustomMsg + DeserializeOwned {}
 
@@ -574,6 +933,78 @@ pub trait InterfaceApi {
     type Querier<'querier, Contract>;
 }
 
+/// Identifies the interface in the [InterfaceNamespace] lookup.
+///
+/// Implemented by the `sv::InterfaceMarker` generated by the [interface](crate::interface) macro.
+pub trait InterfaceId {
+    const ID: &'static str;
+}
+
+/// Namespace under which the contract exposes messages of an interface.
+///
+/// Implemented for every contract generated with the [contract](crate::contract) macro,
+/// with `Interface` being the `sv::InterfaceMarker` generated by the
+/// [interface](crate::interface) macro. Messages of interfaces declared with
+/// `#[sv::messages(interface, namespace = "...")]` are nested under the namespace key,
+/// e.g. `{"cw4": {"update_admin": {...}}}`. Other interfaces have no namespace.
+///
+/// Required by the interface `Querier` and `Executor` implemented for the [BoundQuerier] and
+/// the [ExecutorBuilder] of the contract.
+pub trait InterfaceNamespace<Interface> {
+    const NAMESPACE: Option<&'static str>;
+}
+
+impl<Interface, Contract> InterfaceNamespace<Interface> for Contract
+where
+    Interface: InterfaceId,
+    Contract: ContractApi + ?Sized,
+{
+    const NAMESPACE: Option<&'static str> = namespace_of(Contract::NAMESPACES, Interface::ID);
+}
+
+const fn namespace_of(
+    namespaces: &[(&'static str, &'static str)],
+    id: &str,
+) -> Option<&'static str> {
+    let mut index = 0;
+    while index < namespaces.len() {
+        let (interface, namespace) = namespaces[index];
+        if konst::eq_str(interface, id) {
+            return Some(namespace);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Interface message nested under the optional namespace.
+///
+/// Serializes to `{"<namespace>": <msg>}`, or to the plain message if there is no namespace.
+#[derive(Debug)]
+pub struct NamespacedMsg<'a, Msg> {
+    namespace: Option<&'a str>,
+    msg: &'a Msg,
+}
+
+impl<'a, Msg> NamespacedMsg<'a, Msg> {
+    pub fn new(namespace: Option<&'a str>, msg: &'a Msg) -> Self {
+        Self { namespace, msg }
+    }
+}
+
+impl<Msg: Serialize> Serialize for NamespacedMsg<'_, Msg> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.namespace {
+            Some(namespace) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(namespace, self.msg)?;
+                map.end()
+            }
+            None => self.msg.serialize(serializer),
+        }
+    }
+}
+
 /// Api trait for easier access to generated types and messages.
 pub trait ContractApi {
     type Instantiate;
@@ -588,4 +1019,65 @@ pub trait ContractApi {
     type Remote<'remote>;
     type CustomMsg: CustomMsg;
     type CustomQuery: CustomQuery;
+
+    /// Namespaces of the interfaces declared with `#[sv::messages(interface, namespace = "...")]`,
+    /// keyed by the [InterfaceId] of the interface.
+    const NAMESPACES: &'static [(&'static str, &'static str)] = &[];
+}
+
+/// Event with a stable type and typed attributes.
+///
+/// Usually implemented with the `TypedEvent` derive, which turns every field of the struct
+/// into an attribute of the same name. String-like values, f.e. `String`, `Addr` or `Uint128`,
+/// are stored as they are and the other values as JSON. `None` values are skipped.
+///
+/// ```rust
+/// use sylvia::cw_std::{Addr, Uint128};
+/// use sylvia::types::TypedEvent;
+///
+/// #[derive(TypedEvent, Debug, PartialEq)]
+/// #[event(name = "transfer")]
+/// pub struct TransferEvent {
+///     pub sender: Addr,
+///     #[event(name = "recipient")]
+///     pub receiver: Addr,
+///     pub amount: Uint128,
+///     pub memo: Option<String>,
+/// }
+///
+/// let transfer = TransferEvent {
+///     sender: Addr::unchecked("alice"),
+///     receiver: Addr::unchecked("bob"),
+///     amount: Uint128::new(100),
+///     memo: None,
+/// };
+/// let event = transfer.to_event().unwrap();
+///
+/// assert_eq!(event.ty, "transfer");
+/// assert_eq!(event.attributes[1].key, "recipient");
+/// assert_eq!(event.attributes[2].value, "100");
+/// assert_eq!(TransferEvent::from_event(&event).unwrap(), transfer);
+/// ```
+pub trait TypedEvent: Sized {
+    /// Type of the event. Once emitted by a contract it is prefixed with `wasm-` by the chain.
+    const EVENT_TYPE: &'static str;
+
+    /// Keys of the attributes paired with whether the attribute is always present.
+    const ATTRIBUTES: &'static [(&'static str, bool)];
+
+    /// Builds the event to be added to the [Response](cosmwasm_std::Response).
+    fn to_event(&self) -> StdResult<Event>;
+
+    /// Parses the event back, ignoring attributes not defined on the event.
+    fn from_event(event: &Event) -> StdResult<Self>;
+
+    /// Checks if the event is of this type, either as emitted or as prefixed by the chain.
+    fn matches(event: &Event) -> bool {
+        event.ty == Self::EVENT_TYPE || event.ty.strip_prefix("wasm-") == Some(Self::EVENT_TYPE)
+    }
+
+    /// Schema of the event attributes, exported with [write_events_schema](crate::utils::write_events_schema).
+    fn schema() -> RootSchema {
+        crate::utils::event_schema(Self::EVENT_TYPE, Self::ATTRIBUTES)
+    }
 }
@@ -1,3 +1,124 @@
+use cosmwasm_std::{from_json, to_json_string, Event, StdError, StdResult};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Schema of the message `Msg` nested under the `namespace` key.
+///
+/// Used by the glue messages to describe interfaces set with
+/// `#[sv::messages(interface, namespace = "...")]`.
+pub fn namespaced_schema<Msg: JsonSchema>(gen: &mut SchemaGenerator, namespace: &str) -> Schema {
+    let mut schema = SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        ..Default::default()
+    };
+    let object = schema.object();
+    object
+        .properties
+        .insert(namespace.to_owned(), gen.subschema_for::<Msg>());
+    object.required.insert(namespace.to_owned());
+    object.additional_properties = Some(Box::new(false.into()));
+    Schema::Object(schema)
+}
+
+/// Value of the attribute of the [TypedEvent](crate::types::TypedEvent).
+///
+/// JSON strings are stored without quotes, `null` values are not stored at all.
+pub fn event_attribute_value<T: Serialize>(value: &T) -> StdResult<Option<String>> {
+    let value = to_json_string(value)?;
+    match value.as_str() {
+        "null" => Ok(None),
+        _ => Ok(Some(from_json::<String>(&value).unwrap_or(value))),
+    }
+}
+
+/// Parses the attribute of the [TypedEvent](crate::types::TypedEvent) stored with
+/// [event_attribute_value].
+pub fn parse_event_attribute<T: DeserializeOwned>(event: &Event, key: &str) -> StdResult<T> {
+    let Some(attr) = event.attributes.iter().find(|attr| attr.key == key) else {
+        return from_json("null").map_err(|_| {
+            StdError::generic_err(format!(
+                "Missing attribute `{key}` of the `{}` event",
+                event.ty
+            ))
+        });
+    };
+
+    from_json(to_json_string(&attr.value)?)
+        .or_else(|_| from_json(&attr.value))
+        .map_err(|err| {
+            StdError::parse_err(
+                std::any::type_name::<T>(),
+                format!("attribute `{key}` of the `{}` event: {err}", event.ty),
+            )
+        })
+}
+
+/// Schema of the [TypedEvent](crate::types::TypedEvent) with string attributes.
+pub fn event_schema(event_type: &str, attributes: &[(&str, bool)]) -> RootSchema {
+    let mut schema = SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        metadata: Some(Box::new(Metadata {
+            title: Some(event_type.to_owned()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+    let object = schema.object();
+    for (key, required) in attributes {
+        object.properties.insert(
+            (*key).to_owned(),
+            SchemaObject {
+                instance_type: Some(InstanceType::String.into()),
+                ..Default::default()
+            }
+            .into(),
+        );
+        if *required {
+            object.required.insert((*key).to_owned());
+        }
+    }
+
+    RootSchema {
+        meta_schema: Some("http://json-schema.org/draft-07/schema#".to_owned()),
+        schema,
+        definitions: Default::default(),
+    }
+}
+
+/// Writes schemas of the events to the `schema/events` directory, next to the schema written
+/// by the `write_api!` macro.
+///
+/// ```rust,no_run
+/// # use sylvia::types::TypedEvent;
+/// #
+/// # #[derive(TypedEvent)]
+/// # pub struct TransferEvent {
+/// #     pub amount: u128,
+/// # }
+/// #
+/// sylvia::utils::write_events_schema(&[TransferEvent::schema()]);
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write_events_schema(schemas: &[RootSchema]) {
+    let mut out_dir = std::env::current_dir().unwrap();
+    out_dir.push("schema");
+    out_dir.push("events");
+    std::fs::create_dir_all(&out_dir).unwrap();
+
+    for schema in schemas {
+        let title = schema
+            .schema
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.title.as_deref())
+            .unwrap_or("event");
+        cosmwasm_schema::export_schema_with_title(schema, &out_dir, title);
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum State {
     // Ongoing arrays can be compared to other arrays.
@@ -20,15 +141,34 @@ enum State {
 ///     };
 /// ```
 pub const fn assert_no_intersection<const N: usize>(msgs: [&[&str]; N]) {
-    let mut states = init_states(&msgs);
+    check_intersection(&msgs, None)
+}
+
+/// # Examples
+///
+/// Compile time intersection assert naming the overlapping message and the `names` of the
+/// message sets it was found in.
+/// Requires sorted arrays to work.
+/// ```
+///     const _: () = {
+///         let msgs: [&[&str]; 2] = [&["msg_a", "msg_b"], &["msg_c", "msg_d"]];
+///         sylvia::utils::assert_no_intersection_named(msgs, ["Interface", "Contract"]);
+///     };
+/// ```
+pub const fn assert_no_intersection_named<const N: usize>(msgs: [&[&str]; N], names: [&str; N]) {
+    check_intersection(&msgs, Some(&names))
+}
+
+const fn check_intersection<const N: usize>(msgs: &[&[&str]; N], names: Option<&[&str; N]>) {
+    let mut states = init_states(msgs);
 
     while !should_end(&states) {
         // Get index of array with alphabetically smallest value.
         // This will always be one which state is Ongoing.
-        let index = get_next_alphabetical_index(&msgs, &states);
+        let index = get_next_alphabetical_index(msgs, &states);
 
         // Compare all elements at current indexes
-        verify_no_collissions(&msgs, &states, &index);
+        verify_no_collissions(msgs, names, &states, &index);
 
         // Increment index of alphabetically first element
         states[index] = match states[index] {
@@ -87,6 +227,7 @@ const fn get_next_alphabetical_index<const N: usize>(
 // and can just compare values in alphabetical ordering.
 const fn verify_no_collissions<const N: usize>(
     msgs: &[&[&str]; N],
+    names: Option<&[&str; N]>,
     states: &[State; N],
     index: &usize,
 ) {
@@ -100,7 +241,18 @@ const fn verify_no_collissions<const N: usize>(
             State::Ongoing(outer_i) | State::Finished(outer_i) => {
                 if let State::Ongoing(inner_i) = states[*index] {
                     if konst::eq_str(msgs[i][outer_i], msgs[*index][inner_i]) {
-                        panic!("Message overlaps between interface and contract impl!");
+                        match names {
+                            Some(names) => const_panic::concat_panic!(
+                                "Message `",
+                                display: msgs[i][outer_i],
+                                "` overlaps between `",
+                                display: names[i],
+                                "` and `",
+                                display: names[*index],
+                                "`! Consider nesting one of the interfaces with `#[sv::messages(interface, namespace = \"...\")]`."
+                            ),
+                            None => panic!("Message overlaps between interface and contract impl!"),
+                        }
                     }
                 }
             }
@@ -173,8 +325,8 @@ mod tests {
             State::Ongoing(0),
         ];
 
-        super::verify_no_collissions(&msgs, &states, &1);
-        super::verify_no_collissions(&msgs, &states, &3);
+        super::verify_no_collissions(&msgs, None, &states, &1);
+        super::verify_no_collissions(&msgs, None, &states, &3);
 
         let states = [
             State::Empty,
@@ -183,7 +335,7 @@ mod tests {
             State::Ongoing(1),
         ];
 
-        super::verify_no_collissions(&msgs, &states, &1);
+        super::verify_no_collissions(&msgs, None, &states, &1);
     }
 
     #[test]
@@ -197,7 +349,7 @@ mod tests {
             State::Ongoing(1),
         ];
 
-        super::verify_no_collissions(&msgs, &states, &3);
+        super::verify_no_collissions(&msgs, None, &states, &3);
     }
 
     #[test]
@@ -227,6 +379,20 @@ mod tests {
         assert_no_intersection(msgs);
     }
 
+    #[test]
+    #[should_panic(expected = "Message `msg_i` overlaps between `Fourth` and `First`!")]
+    fn named_intersection() {
+        let msgs: [&[&str]; 5] = [
+            &["msg_b", "msg_c", "msg_i"],
+            &["msg_d", "msg_e", "msg_f"],
+            &["msg_a"],
+            &["msg_g", "msg_h", "msg_i", "msg_j"],
+            &[],
+        ];
+
+        assert_no_intersection_named(msgs, ["First", "Second", "Third", "Fourth", "Fifth"]);
+    }
+
     #[test]
     fn single_interface_with_no_contract_msgs() {
         let msgs: [&[&str]; 2] = [&["msg_a", "msg_b"], &[]];
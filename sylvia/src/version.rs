@@ -0,0 +1,66 @@
+//! Module providing `cw2` contract versioning for contracts using the `sv::version` attribute.
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, CustomQuery, Deps, StdError, StdResult, Storage};
+use semver::Version;
+
+/// Query generated for contracts with the `sv::version` attribute.
+/// Returns the [ContractVersion](cw2::ContractVersion) stored by the contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ContractInfoQueryMsg {
+    #[returns(cw2::ContractVersion)]
+    ContractInfo {},
+}
+
+impl ContractInfoQueryMsg {
+    pub fn dispatch<C: CustomQuery>(self, deps: Deps<C>) -> StdResult<Binary> {
+        match self {
+            Self::ContractInfo {} => to_json_binary(&cw2::get_contract_version(deps.storage)?),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ContractInfoQueryMsg {
+    fn arbitrary(_u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::ContractInfo {})
+    }
+}
+
+/// Names of the messages handled by [ContractInfoQueryMsg].
+pub const fn query_messages() -> [&'static str; 1] {
+    ["contract_info"]
+}
+
+/// Validates that the contract stored in `storage` can be migrated to the contract `name`
+/// in version `new_version`.
+///
+/// Unlike [cw2::ensure_from_older_version] it doesn't update the stored contract info.
+pub fn ensure_from_older_version(
+    storage: &dyn Storage,
+    name: &str,
+    new_version: &str,
+) -> StdResult<()> {
+    let version: Version = new_version.parse().map_err(from_semver)?;
+    let stored = cw2::get_contract_version(storage)?;
+    let storage_version: Version = stored.version.parse().map_err(from_semver)?;
+
+    if name != stored.contract {
+        let msg = format!("Cannot migrate from {} to {}", stored.contract, name);
+        return Err(StdError::generic_err(msg));
+    }
+
+    if storage_version > version {
+        let msg = format!(
+            "Cannot migrate from newer version ({}) to older ({})",
+            stored.version, new_version
+        );
+        return Err(StdError::generic_err(msg));
+    }
+
+    Ok(())
+}
+
+fn from_semver(err: semver::Error) -> StdError {
+    StdError::generic_err(format!("Semver: {err}"))
+}
@@ -0,0 +1,225 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
+use sylvia::cw_std::{Addr, Attribute, CustomMsg};
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct MyMsg;
+
+impl CustomMsg for MyMsg {}
+
+pub mod ownable {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+    use sylvia::types::ExecCtx;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Ownable {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn update_owner(&self, ctx: ExecCtx, owner: String) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod token {
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx, SudoCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct Token;
+
+    #[entry_points]
+    #[contract]
+    #[sv::actions]
+    #[sv::messages(crate::ownable)]
+    impl Token {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn transfer(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new().add_attribute("amount", "10"))
+        }
+
+        #[sv::msg(exec, name = "burn_from")]
+        fn burn(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn msg_name(&self, _ctx: QueryCtx) -> StdResult<String> {
+            Ok("token".to_owned())
+        }
+
+        #[sv::msg(sudo)]
+        fn freeze(&self, _ctx: SudoCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+
+    impl super::ownable::Ownable for Token {
+        type Error = StdError;
+
+        fn update_owner(&self, _ctx: ExecCtx, _owner: String) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+pub mod custom_token {
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx};
+    use sylvia::{contract, entry_points};
+
+    use super::MyMsg;
+
+    pub struct CustomToken;
+
+    #[entry_points]
+    #[contract]
+    #[sv::actions]
+    #[sv::custom(msg=MyMsg)]
+    impl CustomToken {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response<MyMsg>> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn mint(&self, _ctx: ExecCtx) -> StdResult<Response<MyMsg>> {
+            Ok(Response::new())
+        }
+    }
+}
+
+pub mod plain {
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct Plain;
+
+    #[entry_points]
+    #[contract]
+    impl Plain {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn transfer(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn msg_names() {
+    use crate::ownable::sv::OwnableExecMsg;
+    use crate::token::sv::{ExecMsg, QueryMsg};
+
+    assert_eq!(ExecMsg::transfer().sv_msg_name(), "transfer");
+    assert_eq!(QueryMsg::msg_name().sv_msg_name(), "msg_name");
+    assert_eq!(ExecMsg::burn().sv_msg_name(), "burn_from");
+    assert_eq!(
+        OwnableExecMsg::update_owner("owner".to_owned()).sv_msg_name(),
+        "update_owner"
+    );
+}
+
+#[test]
+fn action_attributes() {
+    use crate::ownable::sv::OwnableExecMsg;
+    use crate::token::entry_points::{execute, instantiate, migrate, sudo};
+    use crate::token::sv::{
+        ContractExecMsg, ContractSudoMsg, ExecMsg, InstantiateMsg, MigrateMsg, SudoMsg,
+    };
+
+    let mut deps = mock_dependencies();
+    let info = message_info(&Addr::unchecked("owner"), &[]);
+
+    let resp = instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+    assert_eq!(resp.attributes, [Attribute::new("action", "instantiate")]);
+
+    let msg = ContractExecMsg::Token(ExecMsg::transfer());
+    let resp = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    assert_eq!(
+        resp.attributes,
+        [
+            Attribute::new("amount", "10"),
+            Attribute::new("action", "transfer")
+        ]
+    );
+
+    let msg = ContractExecMsg::Token(ExecMsg::burn());
+    let resp = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    assert_eq!(resp.attributes, [Attribute::new("action", "burn_from")]);
+
+    let msg = ContractExecMsg::Ownable(OwnableExecMsg::update_owner("new_owner".to_owned()));
+    let resp = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(
+        resp.attributes,
+        [
+            Attribute::new("action", "update_owner"),
+            Attribute::new("interface", "Ownable")
+        ]
+    );
+
+    let msg = ContractSudoMsg::Token(SudoMsg::freeze());
+    let resp = sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    assert_eq!(resp.attributes, [Attribute::new("action", "freeze")]);
+
+    let resp = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(resp.attributes, [Attribute::new("action", "migrate")]);
+}
+
+#[test]
+fn custom_response_action_attributes() {
+    use crate::custom_token::entry_points::{execute, instantiate};
+    use crate::custom_token::sv::{ContractExecMsg, ExecMsg, InstantiateMsg};
+
+    let mut deps = mock_dependencies();
+    let info = message_info(&Addr::unchecked("owner"), &[]);
+
+    let resp = instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+    assert_eq!(resp.attributes, [Attribute::new("action", "instantiate")]);
+
+    let msg = ContractExecMsg::CustomToken(ExecMsg::mint());
+    let resp = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(resp.attributes, [Attribute::new("action", "mint")]);
+}
+
+#[test]
+fn no_action_attributes_by_default() {
+    use crate::plain::entry_points::execute;
+    use crate::plain::sv::{ContractExecMsg, ExecMsg};
+
+    let mut deps = mock_dependencies();
+    let info = message_info(&Addr::unchecked("owner"), &[]);
+
+    let msg = ContractExecMsg::Plain(ExecMsg::transfer());
+    let resp = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert!(resp.attributes.is_empty());
+}
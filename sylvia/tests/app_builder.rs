@@ -0,0 +1,202 @@
+#![cfg(feature = "mt")]
+
+use anyhow::Result as AnyResult;
+use cosmwasm_schema::cw_serde;
+use cw_multi_test::{AppResponse, CosmosRouter, GovAcceptingModule, Module, StargateAccepting};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use sylvia::cw_std::{
+    to_json_binary, Addr, Api, Binary, BlockInfo, CustomMsg, CustomQuery, Empty, Querier, StdError,
+    Storage, Timestamp,
+};
+use sylvia::multitest::{BasicAppBuilder, MtError};
+
+use crate::counter::sv::mt::{CodeId, CounterContractProxy};
+
+#[cw_serde]
+pub enum CounterMsg {
+    Increment {},
+}
+
+impl CustomMsg for CounterMsg {}
+
+#[cw_serde]
+pub enum CounterQuery {
+    Count {},
+}
+
+impl CustomQuery for CounterQuery {}
+
+pub struct CounterModule {
+    pub counter: Item<u64>,
+}
+
+impl Default for CounterModule {
+    fn default() -> Self {
+        Self {
+            counter: Item::new("counter"),
+        }
+    }
+}
+
+impl Module for CounterModule {
+    type ExecT = CounterMsg;
+    type QueryT = CounterQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            CounterMsg::Increment {} => {
+                self.counter
+                    .update(storage, |value| Ok::<_, StdError>(value + 1))?;
+                Ok(AppResponse::default())
+            }
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        Ok(AppResponse::default())
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request {
+            CounterQuery::Count {} => Ok(to_json_binary(&self.counter.load(storage)?)?),
+        }
+    }
+}
+
+pub mod counter {
+    use sylvia::cw_std::{
+        CosmosMsg, GovMsg, QueryRequest, Response, StdResult, Timestamp, VoteOption,
+    };
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    use super::{CounterMsg, CounterQuery};
+
+    pub struct CounterContract;
+
+    #[entry_points]
+    #[contract]
+    #[sv::custom(msg=CounterMsg, query=CounterQuery)]
+    impl CounterContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(
+            &self,
+            _ctx: InstantiateCtx<CounterQuery>,
+        ) -> StdResult<Response<CounterMsg>> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, _ctx: ExecCtx<CounterQuery>) -> StdResult<Response<CounterMsg>> {
+            Ok(Response::new().add_message(CosmosMsg::Custom(CounterMsg::Increment {})))
+        }
+
+        #[sv::msg(exec)]
+        fn vote(
+            &self,
+            _ctx: ExecCtx<CounterQuery>,
+            proposal_id: u64,
+        ) -> StdResult<Response<CounterMsg>> {
+            Ok(Response::new().add_message(GovMsg::Vote {
+                proposal_id,
+                option: VoteOption::Yes,
+            }))
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx<CounterQuery>) -> StdResult<u64> {
+            ctx.deps
+                .querier
+                .query(&QueryRequest::Custom(CounterQuery::Count {}))
+        }
+
+        #[sv::msg(query)]
+        fn block_time(&self, ctx: QueryCtx<CounterQuery>) -> StdResult<Timestamp> {
+            Ok(ctx.env.block.time)
+        }
+    }
+}
+
+#[test]
+fn build_app_with_custom_modules() {
+    let block = BlockInfo {
+        height: 100,
+        time: Timestamp::from_seconds(1_000),
+        chain_id: "juno-1".to_owned(),
+    };
+    let app = BasicAppBuilder::<CounterMsg, CounterQuery>::new_custom()
+        .with_custom(CounterModule::default())
+        .with_gov(GovAcceptingModule::new())
+        .with_stargate(StargateAccepting)
+        .with_bech32_prefix("juno")
+        .with_block(block.clone())
+        .build(|router, _, storage| router.custom.counter.save(storage, &5).unwrap());
+
+    assert_eq!(app.block_info(), block);
+
+    let owner = app.app().api().addr_make("owner");
+    assert!(owner.as_str().starts_with("juno1"));
+
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id.instantiate().call(&owner).unwrap();
+    assert!(contract.contract_addr.as_str().starts_with("juno1"));
+
+    assert_eq!(contract.count().unwrap(), 5);
+    contract.increment().call(&owner).unwrap();
+    assert_eq!(contract.count().unwrap(), 6);
+
+    contract.vote(1).call(&owner).unwrap();
+    assert_eq!(contract.block_time().unwrap(), block.time);
+}
+
+#[test]
+fn default_modules_fail_on_custom_and_gov_messages() {
+    let app = BasicAppBuilder::<CounterMsg, CounterQuery>::new_custom().build(|_, _, _| {});
+    let owner = app.app().api().addr_make("owner");
+
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    let err = contract.increment().try_call(&owner).unwrap_err();
+    assert!(matches!(err, MtError::System(_)));
+    let err = contract.vote(1).try_call(&owner).unwrap_err();
+    assert!(matches!(err, MtError::System(_)));
+}
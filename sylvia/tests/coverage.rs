@@ -0,0 +1,182 @@
+#![cfg(feature = "mt")]
+
+use cw_multi_test::IntoBech32;
+use sylvia::multitest::coverage::{ContractCoverage, EntryPoint, MissedMessage};
+use sylvia::multitest::App;
+
+use crate::counter::sv::mt::CounterProxy;
+use crate::relay::sv::mt::{CodeId, RelayContractProxy};
+
+pub mod counter {
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+    use sylvia::types::{ExecCtx, QueryCtx};
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64>;
+    }
+}
+
+pub mod relay {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{Addr, Response, StdError, StdResult, SubMsg};
+    use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx, Remote, ReplyCtx, SudoCtx};
+    use sylvia::{contract, entry_points};
+
+    use crate::counter::sv::Executor as _;
+    use crate::counter::Counter;
+
+    pub struct RelayContract {
+        pub(crate) count: Item<u64>,
+    }
+
+    #[entry_points]
+    #[contract]
+    #[sv::messages(crate::counter)]
+    impl RelayContract {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &0)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn forward(&self, _ctx: ExecCtx, target: Addr) -> StdResult<Response> {
+            let msg = Remote::<dyn Counter<Error = StdError>>::new(target)
+                .executor()
+                .increment()?
+                .build();
+            Ok(Response::new()
+                .add_submessage(SubMsg::reply_on_success(msg, sv::FORWARDED_REPLY_ID)))
+        }
+
+        #[sv::msg(exec)]
+        fn reset(&self, ctx: ExecCtx) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &0)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn total(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+
+        #[sv::msg(sudo)]
+        fn set(&self, ctx: SudoCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(reply, handlers=[forwarded], reply_on=success)]
+        fn forwarded(&self, _ctx: ReplyCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(reply, handlers=[forwarded], reply_on=error)]
+        fn forward_failed(&self, _ctx: ReplyCtx, error: String) -> StdResult<Response> {
+            Err(StdError::generic_err(error))
+        }
+    }
+
+    impl Counter for RelayContract {
+        type Error = StdError;
+
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+            self.count
+                .update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+            Ok(Response::new())
+        }
+
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+    }
+}
+
+fn missed(entry_point: EntryPoint, source: &'static str, message: &'static str) -> MissedMessage {
+    MissedMessage {
+        entry_point,
+        source,
+        message,
+    }
+}
+
+#[test]
+fn coverage_report() {
+    let owner = "owner".into_bech32();
+    let app = App::default().with_coverage();
+    let code_id = CodeId::store_code(&app);
+    let relay = code_id.instantiate().call(&owner).unwrap();
+    let counter = code_id.instantiate().call(&owner).unwrap();
+
+    relay
+        .forward(counter.contract_addr.clone())
+        .call(&owner)
+        .unwrap();
+    assert_eq!(counter.count().unwrap(), 1);
+
+    let report = app.coverage_report().unwrap();
+    assert!(!report.is_complete());
+    assert_eq!(
+        report.contracts,
+        [ContractCoverage {
+            contract: "RelayContract",
+            declared: 9,
+            received: 4,
+            missed: vec![
+                missed(EntryPoint::Exec, "RelayContract", "reset"),
+                missed(EntryPoint::Query, "RelayContract", "total"),
+                missed(EntryPoint::Sudo, "RelayContract", "set"),
+                missed(EntryPoint::Migrate, "RelayContract", "migrate"),
+                missed(EntryPoint::Reply, "RelayContract", "forward_failed"),
+            ],
+        }]
+    );
+    assert_eq!(
+        report.to_string(),
+        "RelayContract: 4 of 9 messages received\n  \
+         exec RelayContract::reset\n  \
+         query RelayContract::total\n  \
+         sudo RelayContract::set\n  \
+         migrate RelayContract::migrate\n  \
+         reply RelayContract::forward_failed\n"
+    );
+    assert!(report
+        .to_json()
+        .unwrap()
+        .contains(r#"{"entry_point":"sudo","source":"RelayContract","message":"set"}"#));
+
+    relay.reset().call(&owner).unwrap();
+    assert_eq!(relay.total().unwrap(), 0);
+    let report = app.coverage_report().unwrap();
+    assert_eq!(report.contracts[0].received, 6);
+}
+
+#[test]
+fn coverage_disabled() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let relay = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+    relay.reset().call(&owner).unwrap();
+    assert_eq!(app.coverage_report(), None);
+}
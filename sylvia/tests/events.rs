@@ -0,0 +1,160 @@
+use sylvia::cw_std::{Addr, Event, Uint128};
+use sylvia::types::TypedEvent;
+
+#[derive(TypedEvent, Debug, Clone, PartialEq)]
+#[event(name = "transfer")]
+pub struct TransferEvent {
+    pub sender: Addr,
+    #[event(name = "recipient")]
+    pub receiver: Addr,
+    pub amount: Uint128,
+    pub memo: Option<String>,
+}
+
+#[derive(TypedEvent, Debug, PartialEq)]
+pub struct PausedEvent;
+
+pub mod token {
+    use sylvia::cw_std::{Addr, Response, StdResult, Uint128};
+    use sylvia::types::{ExecCtx, InstantiateCtx, TypedEvent};
+    use sylvia::{contract, entry_points};
+
+    use super::{PausedEvent, TransferEvent};
+
+    pub struct Token;
+
+    #[entry_points]
+    #[contract]
+    impl Token {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn transfer(
+            &self,
+            ctx: ExecCtx,
+            receiver: String,
+            amount: Uint128,
+            memo: Option<String>,
+        ) -> StdResult<Response> {
+            let event = TransferEvent {
+                sender: ctx.info.sender,
+                receiver: Addr::unchecked(receiver),
+                amount,
+                memo,
+            };
+            Ok(Response::new().add_event(event.to_event()?))
+        }
+
+        #[sv::msg(exec)]
+        fn pause(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new().add_event(PausedEvent.to_event()?))
+        }
+    }
+}
+
+#[test]
+fn event_conversion() {
+    let transfer = TransferEvent {
+        sender: Addr::unchecked("alice"),
+        receiver: Addr::unchecked("bob"),
+        amount: Uint128::new(100),
+        memo: Some("rent".to_owned()),
+    };
+
+    let event = transfer.to_event().unwrap();
+    assert_eq!(
+        event,
+        Event::new("transfer")
+            .add_attribute("sender", "alice")
+            .add_attribute("recipient", "bob")
+            .add_attribute("amount", "100")
+            .add_attribute("memo", "rent")
+    );
+    assert_eq!(TransferEvent::from_event(&event).unwrap(), transfer);
+
+    let transfer = TransferEvent {
+        memo: None,
+        ..transfer
+    };
+    let event = transfer.to_event().unwrap();
+    assert_eq!(event.attributes.len(), 3);
+    assert_eq!(TransferEvent::from_event(&event).unwrap(), transfer);
+
+    assert_eq!(PausedEvent::EVENT_TYPE, "paused_event");
+    let event = PausedEvent.to_event().unwrap();
+    assert_eq!(PausedEvent::from_event(&event).unwrap(), PausedEvent);
+}
+
+#[test]
+fn event_parsing_errors() {
+    let err = TransferEvent::from_event(&Event::new("mint")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Generic error: Expected the `transfer` event, received `mint`"
+    );
+
+    let event = Event::new("wasm-transfer")
+        .add_attribute("_contract_address", "contract")
+        .add_attribute("sender", "alice");
+    let err = TransferEvent::from_event(&event).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Generic error: Missing attribute `recipient` of the `wasm-transfer` event"
+    );
+
+    let event = event
+        .add_attribute("recipient", "bob")
+        .add_attribute("amount", "a lot");
+    TransferEvent::from_event(&event).unwrap_err();
+}
+
+#[test]
+fn event_schema() {
+    let schema = sylvia::cw_std::to_json_string(&TransferEvent::schema()).unwrap();
+    assert!(schema.contains(r#""title":"transfer""#));
+    assert!(schema.contains(r#""required":["amount","recipient","sender"]"#));
+    assert!(schema.contains(r#""memo":{"type":"string"}"#));
+}
+
+#[cfg(feature = "mt")]
+#[test]
+fn events_of_response() {
+    use crate::token::sv::mt::{CodeId, TokenProxy};
+    use cw_multi_test::IntoBech32;
+    use sylvia::multitest::{App, TypedEvents};
+
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let owner = "owner".into_bech32();
+    let receiver = "receiver".into_bech32();
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    let resp = contract
+        .transfer(receiver.to_string(), Uint128::new(10), None)
+        .call(&owner)
+        .unwrap();
+    let transfer = TransferEvent {
+        sender: owner.clone(),
+        receiver,
+        amount: Uint128::new(10),
+        memo: None,
+    };
+    assert_eq!(
+        resp.events_of::<TransferEvent>().unwrap(),
+        std::slice::from_ref(&transfer)
+    );
+    assert!(resp.has_typed_event(&transfer));
+    assert!(resp.events_of::<PausedEvent>().unwrap().is_empty());
+
+    let resp = contract.pause().call(&owner).unwrap();
+    assert!(resp.has_typed_event(&PausedEvent));
+    assert!(!resp.has_typed_event(&transfer));
+}
@@ -115,11 +115,16 @@ impl CounterContract {
 #[cfg(test)]
 mod tests {
     use cw_multi_test::IntoBech32;
-    use sylvia::cw_std::Addr;
+    use sylvia::cw_std::{
+        to_json_binary, Addr, CosmosMsg, Empty, ReplyOn, StdError, SubMsg, WasmMsg,
+    };
     use sylvia::multitest::App;
+    use sylvia::types::Remote;
 
     use crate::counter::sv::mt::CounterProxy;
+    use crate::counter::Counter;
     use crate::sv::mt::{CodeId, CounterContractProxy};
+    use crate::{ContractExecutor, CounterContract, InterfaceExecutor};
 
     #[test]
     fn call_querier() {
@@ -160,4 +165,44 @@ mod tests {
         let resp = first_contract.count().unwrap();
         assert_eq!(resp.count, 99);
     }
+
+    #[test]
+    fn executor_sub_msgs() {
+        let remote_addr = Addr::unchecked("remote");
+
+        let remote = Remote::<CounterContract>::new(remote_addr.clone());
+        let sub_msg: SubMsg<Empty> = remote
+            .executor()
+            .increase()
+            .unwrap()
+            .with_gas_limit(100_000)
+            .with_payload(b"increase".as_slice())
+            .reply_on_success(1);
+        assert_eq!(sub_msg.id, 1);
+        assert_eq!(sub_msg.reply_on, ReplyOn::Success);
+        assert_eq!(sub_msg.gas_limit, Some(100_000));
+        assert_eq!(sub_msg.payload.as_slice(), b"increase");
+        assert_eq!(
+            sub_msg.msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: remote_addr.to_string(),
+                msg: to_json_binary(&crate::sv::ExecMsg::increase()).unwrap(),
+                funds: vec![],
+            })
+        );
+
+        let remote = Remote::<dyn Counter<Error = StdError>>::new(remote_addr.clone());
+        let sub_msg: SubMsg<Empty> = remote.executor().decrease().unwrap().reply_on_error(2);
+        assert_eq!(sub_msg.id, 2);
+        assert_eq!(sub_msg.reply_on, ReplyOn::Error);
+        assert_eq!(sub_msg.gas_limit, None);
+        assert!(sub_msg.payload.is_empty());
+
+        let sub_msg: SubMsg<Empty> = remote.executor().decrease().unwrap().reply_always(3);
+        assert_eq!(sub_msg.reply_on, ReplyOn::Always);
+        assert_eq!(
+            sub_msg.msg,
+            CosmosMsg::Wasm(remote.executor().decrease().unwrap().build())
+        );
+    }
 }
@@ -0,0 +1,125 @@
+#![cfg(feature = "cosmwasm_1_2")]
+
+use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
+use sylvia::cw_std::{coin, coins, from_json, Addr, Uint128};
+use sylvia::cw_utils::PaymentError;
+
+use crate::funds_contract::entry_points::{execute, instantiate};
+use crate::funds_contract::sv::{ContractExecMsg, ExecMsg, InstantiateMsg};
+use crate::funds_contract::ContractError;
+
+mod funds_contract {
+    use sylvia::cw_std::{to_json_binary, Response, StdError, Uint128};
+    use sylvia::cw_utils::PaymentError;
+    use sylvia::types::{ExecCtx, InstantiateCtx};
+    use sylvia::{contract, entry_points};
+
+    #[derive(Debug, PartialEq, thiserror::Error)]
+    pub enum ContractError {
+        #[error("{0}")]
+        Std(#[from] StdError),
+
+        #[error("{0}")]
+        Payment(#[from] PaymentError),
+    }
+
+    pub struct FundsContract;
+
+    #[entry_points]
+    #[contract]
+    #[sv::error(ContractError)]
+    impl FundsContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate, nonpayable)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> Result<Response, ContractError> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec, nonpayable)]
+        fn free(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec, payable)]
+        fn donate(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+            let resp = Response::new().set_data(to_json_binary(&ctx.info.funds.len())?);
+            Ok(resp)
+        }
+
+        #[sv::msg(exec, must_pay = "ucosm")]
+        fn deposit(
+            &self,
+            _ctx: ExecCtx,
+            memo: String,
+            #[sv::funds] amount: Uint128,
+        ) -> Result<Response, ContractError> {
+            let resp = Response::new().set_data(to_json_binary(&(memo, amount))?);
+            Ok(resp)
+        }
+    }
+}
+
+#[test]
+fn nonpayable_instantiate() {
+    let mut deps = mock_dependencies();
+    let sender = Addr::unchecked("sender");
+
+    let info = message_info(&sender, &[]);
+    instantiate(deps.as_mut(), mock_env(), info, InstantiateMsg {}).unwrap();
+
+    let info = message_info(&sender, &coins(10, "ucosm"));
+    let err = instantiate(deps.as_mut(), mock_env(), info, InstantiateMsg {}).unwrap_err();
+    assert_eq!(err, ContractError::Payment(PaymentError::NonPayable {}));
+}
+
+#[test]
+fn nonpayable_exec() {
+    let mut deps = mock_dependencies();
+    let sender = Addr::unchecked("sender");
+    let msg = ContractExecMsg::FundsContract(ExecMsg::free());
+
+    let info = message_info(&sender, &[]);
+    execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap();
+
+    let info = message_info(&sender, &coins(10, "ucosm"));
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::Payment(PaymentError::NonPayable {}));
+}
+
+#[test]
+fn payable_exec() {
+    let mut deps = mock_dependencies();
+    let sender = Addr::unchecked("sender");
+    let msg = ContractExecMsg::FundsContract(ExecMsg::donate());
+
+    let info = message_info(&sender, &[coin(10, "ucosm"), coin(20, "uatom")]);
+    let resp = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let funds_cnt: usize = from_json(resp.data.unwrap()).unwrap();
+    assert_eq!(funds_cnt, 2);
+}
+
+#[test]
+fn must_pay_exec() {
+    let mut deps = mock_dependencies();
+    let sender = Addr::unchecked("sender");
+    let msg = ContractExecMsg::FundsContract(ExecMsg::deposit("memo".to_owned()));
+
+    let info = message_info(&sender, &coins(10, "ucosm"));
+    let resp = execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap();
+    let data: (String, Uint128) = from_json(resp.data.unwrap()).unwrap();
+    assert_eq!(data, ("memo".to_owned(), Uint128::new(10)));
+
+    let info = message_info(&sender, &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Payment(PaymentError::NoFunds {}));
+
+    let info = message_info(&sender, &coins(10, "uatom"));
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Payment(PaymentError::MissingDenom("ucosm".to_owned()))
+    );
+}
@@ -0,0 +1,163 @@
+#![cfg(all(feature = "mt", feature = "arbitrary"))]
+
+use sylvia::fuzz::{check_round_trip, pool_addr, round_trip};
+use sylvia::multitest::fuzz::FuzzStep;
+use sylvia::multitest::App;
+
+use crate::trap::sv::mt::{CodeId, TrapContractProxy};
+use crate::trap::sv::{ContractExecMsg, ContractQueryMsg, ExecMsg, InstantiateMsg};
+
+pub mod trap {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{ensure, Addr, Response, StdError, StdResult, Uint128};
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct TrapContract {
+        pub(crate) admin: Item<Addr>,
+        pub(crate) count: Item<u64>,
+        pub(crate) armable: Item<bool>,
+        pub(crate) armed: Item<bool>,
+    }
+
+    #[entry_points]
+    #[contract]
+    #[sv::arbitrary]
+    impl TrapContract {
+        pub const fn new() -> Self {
+            Self {
+                admin: Item::new("admin"),
+                count: Item::new("count"),
+                armable: Item::new("armable"),
+                armed: Item::new("armed"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(
+            &self,
+            ctx: InstantiateCtx,
+            #[sv::arbitrary(with = sylvia::fuzz::addr)] admin: Addr,
+            armable: bool,
+        ) -> StdResult<Response> {
+            self.admin.save(ctx.deps.storage, &admin)?;
+            self.count.save(ctx.deps.storage, &0)?;
+            self.armable.save(ctx.deps.storage, &armable)?;
+            self.armed.save(ctx.deps.storage, &false)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn set(
+            &self,
+            ctx: ExecCtx,
+            #[sv::arbitrary(with = sylvia::fuzz::uint128)] amount: Uint128,
+        ) -> StdResult<Response> {
+            let count = (amount.u128() % 11) as u64;
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn arm(&self, ctx: ExecCtx) -> StdResult<Response> {
+            ensure!(
+                self.armable.load(ctx.deps.storage)?,
+                StdError::generic_err("Not armable")
+            );
+            self.armed.save(ctx.deps.storage, &true)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn fire(&self, ctx: ExecCtx) -> StdResult<Response> {
+            if self.armed.load(ctx.deps.storage)? {
+                self.count.save(ctx.deps.storage, &100)?;
+            }
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn admin(&self, ctx: QueryCtx) -> StdResult<Addr> {
+            self.admin.load(ctx.deps.storage)
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+
+        #[sv::invariant]
+        fn below_limit(&self, ctx: QueryCtx) -> StdResult<()> {
+            let count = self.count.load(ctx.deps.storage)?;
+            ensure!(
+                count <= 10,
+                StdError::generic_err(format!("count {count} exceeds limit 10"))
+            );
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn fuzzing_shrinks_failing_sequence() {
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let senders = [pool_addr(0), pool_addr(1)];
+
+    let failure = code_id.fuzz(&senders).with_runs(64).run().unwrap_err();
+    assert!(failure.error.contains("Invariant `below_limit` violated"));
+    assert!(failure.error.contains("count 100 exceeds limit 10"));
+
+    let msgs: Vec<_> = failure
+        .steps
+        .iter()
+        .map(|step| match step {
+            FuzzStep::Execute { sender, msg } => {
+                assert!(senders.contains(sender));
+                msg.clone()
+            }
+            FuzzStep::Query { msg } => panic!("Unexpected query {msg:?} in the shrunk sequence"),
+        })
+        .collect();
+    assert_eq!(msgs, [ExecMsg::Arm {}, ExecMsg::Fire {}]);
+
+    let reproduced = code_id
+        .fuzz(&senders)
+        .with_seed(failure.seed)
+        .with_runs(1)
+        .run()
+        .unwrap_err();
+    assert_eq!(reproduced, failure);
+}
+
+#[test]
+fn fuzzing_with_instantiate_msg() {
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let owner = pool_addr(0);
+    let contract = code_id
+        .instantiate(owner.clone(), true)
+        .call(&owner)
+        .unwrap();
+    contract.set(5u128.into()).call(&owner).unwrap();
+
+    code_id
+        .fuzz(&[owner.clone(), pool_addr(1)])
+        .with_instantiate(InstantiateMsg::new(owner, false))
+        .with_runs(16)
+        .check();
+
+    assert_eq!(contract.count().unwrap(), 5);
+}
+
+#[test]
+fn generated_messages_round_trip() {
+    let data: Vec<u8> = (0..=255).rev().collect();
+    for offset in 0..data.len() {
+        round_trip::<InstantiateMsg>(&data[offset..]);
+        round_trip::<ContractExecMsg>(&data[offset..]);
+        round_trip::<ContractQueryMsg>(&data[offset..]);
+    }
+
+    check_round_trip(&InstantiateMsg::new(pool_addr(3), true)).unwrap();
+}
@@ -0,0 +1,180 @@
+use cw_storage_plus::Item;
+use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
+use sylvia::cw_std::Addr;
+
+use crate::counter::sv::CounterExecMsg;
+use crate::guarded::entry_points::{execute, instantiate, migrate};
+use crate::guarded::sv::{ContractExecMsg, ExecMsg, InstantiateMsg, MigrateMsg};
+use crate::guarded::ContractError;
+
+pub mod counter {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+    use sylvia::types::ExecCtx;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        fn is_not_frozen(&self, ctx: &ExecCtx) -> Result<(), Self::Error>;
+
+        #[sv::msg(exec)]
+        #[sv::guard(Self::is_not_frozen)]
+        fn increment(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod guarded {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{Addr, Response, StdError};
+    use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx};
+    use sylvia::{contract, entry_points};
+
+    #[derive(Debug, PartialEq, thiserror::Error)]
+    pub enum ContractError {
+        #[error("{0}")]
+        Std(#[from] StdError),
+
+        #[error("Unauthorized")]
+        Unauthorized,
+
+        #[error("Contract is frozen")]
+        Frozen,
+    }
+
+    pub struct GuardedContract {
+        pub(crate) admin: Item<Addr>,
+        pub(crate) frozen: Item<bool>,
+        pub(crate) counter: Item<u32>,
+    }
+
+    pub fn not_frozen(contract: &GuardedContract, ctx: &ExecCtx) -> Result<(), ContractError> {
+        if contract.frozen.load(ctx.deps.storage)? {
+            return Err(ContractError::Frozen);
+        }
+        Ok(())
+    }
+
+    #[entry_points]
+    #[contract]
+    #[sv::error(ContractError)]
+    #[sv::messages(crate::counter: guard(Self::is_admin))]
+    impl GuardedContract {
+        pub const fn new() -> Self {
+            Self {
+                admin: Item::new("admin"),
+                frozen: Item::new("frozen"),
+                counter: Item::new("counter"),
+            }
+        }
+
+        fn is_admin(&self, ctx: &ExecCtx) -> Result<(), ContractError> {
+            if self.admin.load(ctx.deps.storage)? != ctx.info.sender {
+                return Err(ContractError::Unauthorized);
+            }
+            Ok(())
+        }
+
+        fn can_migrate(&self, ctx: &MigrateCtx) -> Result<(), ContractError> {
+            if self.frozen.load(ctx.deps.storage)? {
+                return Err(ContractError::Frozen);
+            }
+            Ok(())
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx) -> Result<Response, ContractError> {
+            self.admin.save(ctx.deps.storage, &ctx.info.sender)?;
+            self.frozen.save(ctx.deps.storage, &false)?;
+            self.counter.save(ctx.deps.storage, &0)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        #[sv::guard(Self::is_admin)]
+        fn freeze(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+            self.frozen.save(ctx.deps.storage, &true)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        #[sv::guard(not_frozen)]
+        fn reset(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+            self.counter.save(ctx.deps.storage, &0)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        #[sv::guard(Self::can_migrate)]
+        fn migrate(&self, _ctx: MigrateCtx) -> Result<Response, ContractError> {
+            Ok(Response::new())
+        }
+    }
+
+    impl super::counter::Counter for GuardedContract {
+        type Error = ContractError;
+
+        fn is_not_frozen(&self, ctx: &ExecCtx) -> Result<(), Self::Error> {
+            not_frozen(self, ctx)
+        }
+
+        fn increment(&self, ctx: ExecCtx) -> Result<Response, Self::Error> {
+            self.counter
+                .update(ctx.deps.storage, |counter| Ok::<_, StdError>(counter + 1))?;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn method_guards() {
+    let mut deps = mock_dependencies();
+    let admin = Addr::unchecked("admin");
+    let stranger = Addr::unchecked("stranger");
+
+    let info = message_info(&admin, &[]);
+    instantiate(deps.as_mut(), mock_env(), info, InstantiateMsg {}).unwrap();
+
+    let freeze = ContractExecMsg::GuardedContract(ExecMsg::freeze());
+    let reset = ContractExecMsg::GuardedContract(ExecMsg::reset());
+
+    let info = message_info(&stranger, &[]);
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), freeze.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized);
+    execute(deps.as_mut(), mock_env(), info.clone(), reset.clone()).unwrap();
+
+    let admin_info = message_info(&admin, &[]);
+    execute(deps.as_mut(), mock_env(), admin_info, freeze).unwrap();
+
+    let err = execute(deps.as_mut(), mock_env(), info, reset).unwrap_err();
+    assert_eq!(err, ContractError::Frozen);
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(err, ContractError::Frozen);
+}
+
+#[test]
+fn interface_guards() {
+    let mut deps = mock_dependencies();
+    let admin = Addr::unchecked("admin");
+    let stranger = Addr::unchecked("stranger");
+
+    let info = message_info(&admin, &[]);
+    instantiate(deps.as_mut(), mock_env(), info, InstantiateMsg {}).unwrap();
+
+    let increment = ContractExecMsg::Counter(CounterExecMsg::increment());
+
+    let info = message_info(&stranger, &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, increment.clone()).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized);
+
+    let info = message_info(&admin, &[]);
+    execute(deps.as_mut(), mock_env(), info.clone(), increment.clone()).unwrap();
+    let counter = Item::<u32>::new("counter").load(&deps.storage).unwrap();
+    assert_eq!(counter, 1);
+
+    Item::new("frozen").save(&mut deps.storage, &true).unwrap();
+    let err = execute(deps.as_mut(), mock_env(), info, increment).unwrap_err();
+    assert_eq!(err, ContractError::Frozen);
+}
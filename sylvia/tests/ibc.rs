@@ -0,0 +1,167 @@
+#![cfg(feature = "stargate")]
+
+use sylvia::cw_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+    mock_ibc_channel_open_init, mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout,
+};
+use sylvia::cw_std::{from_json, IbcAcknowledgement, IbcOrder};
+
+mod ibc_contract {
+    use sylvia::cw_std::{
+        to_json_binary, Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg,
+        IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketAckMsg,
+        IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Response, StdResult,
+    };
+    use sylvia::types::{
+        IbcChannelCloseCtx, IbcChannelConnectCtx, IbcChannelOpenCtx, IbcPacketAckCtx,
+        IbcPacketReceiveCtx, IbcPacketTimeoutCtx, InstantiateCtx, QueryCtx,
+    };
+    use sylvia::{contract, entry_points};
+
+    pub struct IbcEnabledContract;
+
+    #[entry_points]
+    #[contract]
+    impl IbcEnabledContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn last_packet(&self, ctx: QueryCtx) -> StdResult<Option<Vec<u8>>> {
+            Ok(ctx.deps.storage.get(b"last_packet"))
+        }
+
+        #[sv::msg(ibc_channel_open)]
+        fn channel_open(
+            &self,
+            _ctx: IbcChannelOpenCtx,
+            msg: IbcChannelOpenMsg,
+        ) -> StdResult<IbcChannelOpenResponse> {
+            Ok(Some(Ibc3ChannelOpenResponse {
+                version: msg.channel().version.clone(),
+            }))
+        }
+
+        #[sv::msg(ibc_channel_connect)]
+        fn channel_connect(
+            &self,
+            _ctx: IbcChannelConnectCtx,
+            msg: IbcChannelConnectMsg,
+        ) -> StdResult<IbcBasicResponse> {
+            Ok(
+                IbcBasicResponse::new()
+                    .add_attribute("connect", &msg.channel().endpoint.channel_id),
+            )
+        }
+
+        #[sv::msg(ibc_channel_close)]
+        fn channel_close(
+            &self,
+            _ctx: IbcChannelCloseCtx,
+            msg: IbcChannelCloseMsg,
+        ) -> StdResult<IbcBasicResponse> {
+            Ok(IbcBasicResponse::new().add_attribute("close", &msg.channel().endpoint.channel_id))
+        }
+
+        #[sv::msg(ibc_packet_receive)]
+        fn packet_receive(
+            &self,
+            ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> StdResult<IbcReceiveResponse> {
+            ctx.deps
+                .storage
+                .set(b"last_packet", msg.packet.data.as_slice());
+            Ok(IbcReceiveResponse::new(msg.packet.data))
+        }
+
+        #[sv::msg(ibc_packet_ack)]
+        fn packet_ack(
+            &self,
+            _ctx: IbcPacketAckCtx,
+            msg: IbcPacketAckMsg,
+        ) -> StdResult<IbcBasicResponse> {
+            Ok(IbcBasicResponse::new().add_attribute("ack", msg.acknowledgement.data.to_base64()))
+        }
+
+        #[sv::msg(ibc_packet_timeout)]
+        fn packet_timeout(
+            &self,
+            _ctx: IbcPacketTimeoutCtx,
+            msg: IbcPacketTimeoutMsg,
+        ) -> StdResult<IbcBasicResponse> {
+            Ok(IbcBasicResponse::new()
+                .add_attribute("timeout", to_json_binary(&msg.packet.sequence)?.to_base64()))
+        }
+    }
+}
+
+#[test]
+fn ibc_entry_points_generation() {
+    use ibc_contract::entry_points;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, "ics-test");
+    let resp = entry_points::ibc_channel_open(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.unwrap().version, "ics-test");
+
+    let msg = mock_ibc_channel_connect_ack("channel-0", IbcOrder::Unordered, "ics-test");
+    let resp = entry_points::ibc_channel_connect(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.attributes[0].value, "channel-0");
+
+    let msg = mock_ibc_channel_close_init("channel-0", IbcOrder::Unordered, "ics-test");
+    let resp = entry_points::ibc_channel_close(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.attributes[0].value, "channel-0");
+
+    let msg = mock_ibc_packet_recv("channel-0", &"packet").unwrap();
+    let resp = entry_points::ibc_packet_receive(deps.as_mut(), env.clone(), msg).unwrap();
+    let data: String = from_json(resp.acknowledgement.unwrap()).unwrap();
+    assert_eq!(data, "packet");
+
+    let ack = IbcAcknowledgement::encode_json(&"ack").unwrap();
+    let msg = mock_ibc_packet_ack("channel-0", &"packet", ack.clone()).unwrap();
+    let resp = entry_points::ibc_packet_ack(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.attributes[0].value, ack.data.to_base64());
+
+    let msg = mock_ibc_packet_timeout("channel-0", &"packet").unwrap();
+    let resp = entry_points::ibc_packet_timeout(deps.as_mut(), env, msg).unwrap();
+    assert_eq!(resp.attributes[0].key, "timeout");
+}
+
+#[cfg(feature = "mt")]
+#[test]
+fn mt_ibc_proxy() {
+    use cw_multi_test::IntoAddr;
+    use ibc_contract::sv::mt::{CodeId, IbcEnabledContractProxy};
+    use sylvia::cw_std::to_json_vec;
+    use sylvia::multitest::App;
+
+    let app = App::default();
+    let owner = "owner".into_addr();
+    let contract = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+    let msg = mock_ibc_channel_open_init("channel-0", IbcOrder::Ordered, "ics-test");
+    let resp = contract.ibc_channel_open(msg).unwrap();
+    assert_eq!(resp.unwrap().version, "ics-test");
+
+    let msg = mock_ibc_packet_recv("channel-0", &"packet").unwrap();
+    let resp = contract.ibc_packet_receive(msg).unwrap();
+    let data: String = from_json(resp.acknowledgement.unwrap()).unwrap();
+    assert_eq!(data, "packet");
+    assert_eq!(
+        contract.last_packet().unwrap(),
+        Some(to_json_vec(&"packet").unwrap())
+    );
+
+    let msg = mock_ibc_packet_timeout("channel-0", &"packet").unwrap();
+    let resp = contract.ibc_packet_timeout(msg).unwrap();
+    assert_eq!(resp.attributes[0].key, "timeout");
+}
@@ -0,0 +1,177 @@
+#![cfg(feature = "mt")]
+
+use cw_multi_test::IntoBech32;
+use sylvia::cw_std::{coins, Response, StdError};
+use sylvia::multitest::{App, ReceivedMsg, ReceivedSudoMsg};
+
+use crate::consumer::sv::mt::{CodeId, ConsumerContractProxy};
+use crate::counter::sv::mt::CounterMock;
+use crate::counter::sv::{ExecMsg, SudoMsg};
+
+pub mod counter {
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+    use sylvia::types::{ExecCtx, QueryCtx, SudoCtx};
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx, offset: u64) -> StdResult<u64>;
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx, by: u64) -> StdResult<Response>;
+
+        #[sv::msg(sudo)]
+        fn reset(&self, ctx: SudoCtx, value: u64) -> StdResult<Response>;
+    }
+}
+
+pub mod consumer {
+    use sylvia::contract;
+    use sylvia::cw_std::{Addr, Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx, Remote};
+
+    use crate::counter::sv::{Executor as _, Querier as _};
+    use crate::counter::Counter;
+
+    pub struct ConsumerContract;
+
+    #[contract]
+    impl ConsumerContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn bump(&self, ctx: ExecCtx, counter: Addr, by: u64) -> StdResult<Response> {
+            let msg = Remote::<dyn Counter<Error = StdError>>::new(counter)
+                .executor()
+                .with_funds(ctx.info.funds)
+                .increment(by)?
+                .build();
+            Ok(Response::new().add_message(msg))
+        }
+
+        #[sv::msg(query)]
+        fn doubled(&self, ctx: QueryCtx, counter: Addr) -> StdResult<u64> {
+            let remote = Remote::<dyn Counter<Error = StdError>>::new(counter);
+            Ok(remote.querier(&ctx.deps.querier).count(1)? * 2)
+        }
+    }
+}
+
+#[test]
+fn canned_query_responses() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let mock = CounterMock::new();
+    let counter = mock.store_code(&app).instantiate(&owner, "Counter");
+    let consumer = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+    let err = consumer.doubled(counter.clone()).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("No response registered for the `count` query"));
+
+    mock.with_count(5);
+    assert_eq!(consumer.doubled(counter.clone()).unwrap(), 10);
+
+    mock.on_count(|offset| Ok(20 + offset));
+    assert_eq!(consumer.doubled(counter.clone()).unwrap(), 42);
+
+    mock.on_count(|_| Err(StdError::generic_err("Counter closed")));
+    let err = consumer.doubled(counter).unwrap_err();
+    assert!(err.to_string().contains("Counter closed"));
+}
+
+#[test]
+fn recorded_messages() {
+    let owner = "owner".into_bech32();
+    let app = App::new(cw_multi_test::App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &owner, coins(100, "atom"))
+            .unwrap();
+    }));
+    let mock = CounterMock::default();
+    let counter = mock.store_code(&app).instantiate(&owner, "Counter");
+    let consumer = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+    consumer
+        .bump(counter.clone(), 3)
+        .with_funds(&coins(10, "atom"))
+        .call(&owner)
+        .unwrap();
+    assert_eq!(
+        mock.executed(),
+        [ReceivedMsg {
+            contract: counter.clone(),
+            sender: consumer.contract_addr.clone(),
+            funds: coins(10, "atom"),
+            msg: ExecMsg::increment(3),
+        }]
+    );
+
+    mock.on_increment(|_, by| match by {
+        0 => Err(StdError::generic_err("Nothing to add")),
+        _ => Ok(Response::new().add_attribute("by", by.to_string())),
+    });
+    let resp = consumer.bump(counter.clone(), 2).call(&owner).unwrap();
+    assert!(resp.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "by" && attr.value == "2")));
+    consumer.bump(counter.clone(), 0).call(&owner).unwrap_err();
+    assert_eq!(mock.executed().len(), 3);
+
+    app.app_mut()
+        .wasm_sudo(counter.clone(), &SudoMsg::reset(7))
+        .unwrap();
+    assert_eq!(
+        mock.sudo_received(),
+        [ReceivedSudoMsg {
+            contract: counter,
+            msg: SudoMsg::reset(7),
+        }]
+    );
+}
+
+#[test]
+fn instances_are_recorded_separately() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let mock = CounterMock::new();
+    let code_id = mock.store_code(&app);
+    let first = code_id.instantiate(&owner, "First");
+    let second = code_id.instantiate(&owner, "Second");
+    let consumer = CodeId::store_code(&app).instantiate().call(&owner).unwrap();
+
+    mock.on_increment(|_, _| Err(StdError::generic_err("Counter closed")));
+    consumer.bump(first.clone(), 1).call(&owner).unwrap_err();
+    let err = consumer
+        .bump(second.clone(), 2)
+        .try_call(&owner)
+        .unwrap_err();
+    assert_eq!(err.contract(), Some(&second));
+
+    let contracts: Vec<_> = mock
+        .executed()
+        .into_iter()
+        .map(|received| (received.contract, received.msg))
+        .collect();
+    assert_eq!(
+        contracts,
+        [
+            (first, ExecMsg::increment(1)),
+            (second, ExecMsg::increment(2))
+        ]
+    );
+}
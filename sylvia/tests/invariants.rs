@@ -0,0 +1,166 @@
+#![cfg(feature = "mt")]
+
+use cw_multi_test::IntoBech32;
+use sylvia::multitest::{App, InvariantViolation, MtError};
+
+use crate::counter::sv::mt::{CodeId, CounterContractProxy};
+
+pub mod counter {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{ensure, Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx, SudoCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct CounterContract {
+        pub(crate) count: Item<u64>,
+        pub(crate) limit: Item<u64>,
+    }
+
+    #[entry_points]
+    #[contract]
+    impl CounterContract {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+                limit: Item::new("limit"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx, count: u64, limit: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            self.limit.save(ctx.deps.storage, &limit)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx, by: u64) -> StdResult<Response> {
+            self.count
+                .update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + by) })?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(sudo)]
+        fn set(&self, ctx: SudoCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, ctx: MigrateCtx, limit: u64) -> StdResult<Response> {
+            self.limit.save(ctx.deps.storage, &limit)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+
+        #[sv::invariant]
+        fn below_limit(&self, ctx: QueryCtx) -> StdResult<()> {
+            let count = self.count.load(ctx.deps.storage)?;
+            let limit = self.limit.load(ctx.deps.storage)?;
+            ensure!(
+                count <= limit,
+                StdError::generic_err(format!("count {count} exceeds limit {limit}"))
+            );
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn invariants_hold() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id
+        .instantiate(0, 10)
+        .with_admin(owner.as_str())
+        .call(&owner)
+        .unwrap();
+
+    contract.increment(4).call(&owner).unwrap();
+    contract.set(10).unwrap();
+    contract
+        .migrate(20)
+        .call(&owner, code_id.code_id())
+        .unwrap();
+    contract.increment(10).call(&owner).unwrap();
+    assert_eq!(contract.count().unwrap(), 20);
+}
+
+#[test]
+#[should_panic(
+    expected = "violated after Increment { by: 11 }: Generic error: count 11 exceeds limit 10"
+)]
+fn exec_violation() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let contract = CodeId::store_code(&app)
+        .instantiate(0, 10)
+        .call(&owner)
+        .unwrap();
+
+    let _ = contract.increment(11).call(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Invariant `below_limit`")]
+fn sudo_violation() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let contract = CodeId::store_code(&app)
+        .instantiate(0, 10)
+        .call(&owner)
+        .unwrap();
+
+    let _ = contract.set(11);
+}
+
+#[test]
+#[should_panic(expected = "violated after InstantiateMsg { count: 20, limit: 10 }")]
+fn instantiate_violation() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+
+    let _ = CodeId::store_code(&app).instantiate(20, 10).call(&owner);
+}
+
+#[test]
+fn try_call_returns_violation() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+
+    let err = code_id.instantiate(20, 10).try_call(&owner).unwrap_err();
+    let violation = InvariantViolation {
+        invariant: "below_limit",
+        error: "Generic error: count 20 exceeds limit 10".to_owned(),
+    };
+    assert!(matches!(err, MtError::InvariantViolated(ref v) if *v == violation));
+
+    let contract = code_id
+        .instantiate(0, 10)
+        .with_admin(owner.as_str())
+        .call(&owner)
+        .unwrap();
+
+    let err = contract.increment(11).try_call(&owner).unwrap_err();
+    let violation = InvariantViolation {
+        invariant: "below_limit",
+        error: "Generic error: count 11 exceeds limit 10".to_owned(),
+    };
+    assert_eq!(
+        err.to_string(),
+        "Invariant `below_limit` violated: Generic error: count 11 exceeds limit 10"
+    );
+    assert!(matches!(err, MtError::InvariantViolated(ref v) if *v == violation));
+
+    let err = contract
+        .migrate(5)
+        .try_call(&owner, code_id.code_id())
+        .unwrap_err();
+    assert!(matches!(err, MtError::InvariantViolated(_)));
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "mt")]
+
+use sylvia::cw_std::testing::MockApi;
+use sylvia::cw_std::{coin, to_json_string, Uint128};
+use sylvia::multitest::BasicAppBuilder;
+
+use crate::counter::sv::mt::{CodeId, CounterContractProxy};
+use crate::counter_module::sv::{ExecMsg, Querier, QueryMsg, SudoMsg};
+use crate::counter_module::CounterModule;
+
+pub mod counter_module {
+    use cw_multi_test::AppResponse;
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{coins, Addr, BankMsg, Coin, StdError, StdResult};
+    use sylvia::multitest::module::{ModuleExecCtx, ModuleQueryCtx, ModuleSudoCtx};
+
+    pub struct CounterModule {
+        pub counter: Item<u64>,
+        pub treasury: Item<Addr>,
+    }
+
+    impl Default for CounterModule {
+        fn default() -> Self {
+            Self {
+                counter: Item::new("counter"),
+                treasury: Item::new("treasury"),
+            }
+        }
+    }
+
+    #[sylvia::module]
+    impl CounterModule {
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ModuleExecCtx) -> StdResult<AppResponse> {
+            self.counter
+                .update(ctx.storage, |count| Ok::<_, StdError>(count + 1))?;
+            Ok(AppResponse::default())
+        }
+
+        #[sv::msg(exec)]
+        fn reward(&self, mut ctx: ModuleExecCtx, amount: u128) -> anyhow::Result<AppResponse> {
+            let treasury = self.treasury.load(ctx.storage)?;
+            let msg = BankMsg::Send {
+                to_address: ctx.sender.to_string(),
+                amount: coins(amount, "ucosm"),
+            };
+            ctx.execute(treasury, msg)
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: ModuleQueryCtx) -> StdResult<u64> {
+            self.counter.load(ctx.storage)
+        }
+
+        #[sv::msg(query)]
+        fn treasury_balance(&self, ctx: ModuleQueryCtx) -> StdResult<Coin> {
+            let treasury = self.treasury.load(ctx.storage)?;
+            ctx.querier.query_balance(treasury, "ucosm")
+        }
+
+        #[sv::msg(sudo)]
+        fn reset(&self, ctx: ModuleSudoCtx, value: u64) -> StdResult<AppResponse> {
+            self.counter.save(ctx.storage, &value)?;
+            Ok(AppResponse::default())
+        }
+    }
+}
+
+pub mod counter {
+    use sylvia::cw_std::{CosmosMsg, QueryRequest, Response, StdResult, Uint128};
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    use crate::counter_module::sv::{ExecMsg as CounterMsg, QueryMsg as CounterQuery};
+
+    pub struct CounterContract;
+
+    #[entry_points]
+    #[contract]
+    #[sv::custom(msg=CounterMsg, query=CounterQuery)]
+    impl CounterContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(
+            &self,
+            _ctx: InstantiateCtx<CounterQuery>,
+        ) -> StdResult<Response<CounterMsg>> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, _ctx: ExecCtx<CounterQuery>) -> StdResult<Response<CounterMsg>> {
+            Ok(Response::new().add_message(CosmosMsg::Custom(CounterMsg::increment())))
+        }
+
+        #[sv::msg(exec)]
+        fn claim(
+            &self,
+            _ctx: ExecCtx<CounterQuery>,
+            amount: Uint128,
+        ) -> StdResult<Response<CounterMsg>> {
+            Ok(Response::new().add_message(CosmosMsg::Custom(CounterMsg::reward(amount.u128()))))
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx<CounterQuery>) -> StdResult<u64> {
+            ctx.deps
+                .querier
+                .query(&QueryRequest::Custom(CounterQuery::count()))
+        }
+    }
+}
+
+#[test]
+fn dispatch_module_messages() {
+    let treasury = MockApi::default().addr_make("treasury");
+    let app = BasicAppBuilder::<ExecMsg, QueryMsg>::new_custom()
+        .with_custom(CounterModule::default())
+        .build(|router, _, storage| {
+            router.custom.counter.save(storage, &0).unwrap();
+            router.custom.treasury.save(storage, &treasury).unwrap();
+            router
+                .bank
+                .init_balance(storage, &treasury, vec![coin(100, "ucosm")])
+                .unwrap();
+        });
+    let owner = app.app().api().addr_make("owner");
+
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    contract.increment().call(&owner).unwrap();
+    contract.increment().call(&owner).unwrap();
+    assert_eq!(app.count().unwrap(), 2);
+    assert_eq!(contract.count().unwrap(), 2);
+
+    contract.claim(Uint128::new(30)).call(&owner).unwrap();
+    assert_eq!(app.treasury_balance().unwrap(), coin(70, "ucosm"));
+    assert_eq!(
+        app.querier()
+            .query_balance(&contract.contract_addr, "ucosm")
+            .unwrap(),
+        coin(30, "ucosm")
+    );
+
+    contract
+        .claim(Uint128::new(100))
+        .try_call(&owner)
+        .unwrap_err();
+    assert_eq!(app.treasury_balance().unwrap(), coin(70, "ucosm"));
+}
+
+#[test]
+fn sudo_module() {
+    let app = BasicAppBuilder::<ExecMsg, QueryMsg>::new_custom()
+        .with_custom(CounterModule::default())
+        .build(|router, _, storage| router.custom.counter.save(storage, &5).unwrap());
+    let block = app.block_info();
+
+    app.app_mut()
+        .init_modules(|router, api, storage| {
+            let router = &*router;
+            cw_multi_test::Module::sudo(
+                &router.custom,
+                api,
+                storage,
+                router,
+                &block,
+                SudoMsg::reset(1),
+            )
+        })
+        .unwrap();
+    assert_eq!(app.count().unwrap(), 1);
+
+    let msg = SudoMsg::reset(3);
+    assert_eq!(msg.sv_msg_name(), "reset");
+    assert_eq!(to_json_string(&msg).unwrap(), r#"{"reset":{"value":3}}"#);
+}
@@ -0,0 +1,250 @@
+#![cfg(feature = "mt")]
+
+use cw_multi_test::IntoBech32;
+use sylvia::cw_std::StdError;
+use sylvia::multitest::{App, MtError};
+
+use crate::forwarder::sv::mt::{CodeId as ForwarderCodeId, ForwarderContractProxy};
+use crate::forwarder::ForwarderError;
+use crate::target::sv::mt::CodeId as TargetCodeId;
+use crate::target::TargetError;
+
+pub mod target {
+    use sylvia::contract;
+    use sylvia::cw_std::{coins, Addr, BankMsg, Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx};
+
+    #[derive(Debug, PartialEq, thiserror::Error)]
+    pub enum TargetError {
+        #[error("{0}")]
+        Std(#[from] StdError),
+        #[error("Rejected")]
+        Rejected,
+    }
+
+    pub struct TargetContract;
+
+    #[contract]
+    #[sv::error(TargetError)]
+    impl TargetContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn fail(&self, _ctx: ExecCtx) -> Result<Response, TargetError> {
+            Err(TargetError::Rejected)
+        }
+
+        #[sv::msg(exec)]
+        fn pay(&self, _ctx: ExecCtx, receiver: Addr) -> Result<Response, TargetError> {
+            let msg = BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(100, "atom"),
+            };
+            Ok(Response::new().add_message(msg))
+        }
+    }
+}
+
+pub mod forwarder {
+    use sylvia::contract;
+    use sylvia::cw_std::{coins, Addr, BankMsg, Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, Remote};
+
+    use self::sv::Executor as ForwarderExecutor;
+    use crate::target::sv::Executor;
+    use crate::target::TargetContract;
+
+    #[derive(Debug, PartialEq, thiserror::Error)]
+    pub enum ForwarderError {
+        #[error("{0}")]
+        Std(#[from] StdError),
+        #[error("Unauthorized")]
+        Unauthorized,
+    }
+
+    pub struct ForwarderContract;
+
+    #[contract]
+    #[sv::error(ForwarderError)]
+    impl ForwarderContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn forward(&self, _ctx: ExecCtx, target: Addr) -> Result<Response, ForwarderError> {
+            let msg = Remote::<TargetContract>::new(target)
+                .executor()
+                .fail()?
+                .build();
+            Ok(Response::new().add_message(msg))
+        }
+
+        #[sv::msg(exec)]
+        fn forward_pay(
+            &self,
+            _ctx: ExecCtx,
+            target: Addr,
+            receiver: Addr,
+        ) -> Result<Response, ForwarderError> {
+            let msg = Remote::<TargetContract>::new(target)
+                .executor()
+                .pay(receiver)?
+                .build();
+            Ok(Response::new().add_message(msg))
+        }
+
+        #[sv::msg(exec)]
+        fn reject_self(&self, ctx: ExecCtx) -> Result<Response, ForwarderError> {
+            let msg = Remote::<ForwarderContract>::new(ctx.env.contract.address)
+                .executor()
+                .reject()?
+                .build();
+            Ok(Response::new().add_message(msg))
+        }
+
+        #[sv::msg(exec)]
+        fn pay(&self, _ctx: ExecCtx, receiver: Addr) -> Result<Response, ForwarderError> {
+            let msg = BankMsg::Send {
+                to_address: receiver.to_string(),
+                amount: coins(100, "atom"),
+            };
+            Ok(Response::new().add_message(msg))
+        }
+
+        #[sv::msg(exec)]
+        fn reject(&self, _ctx: ExecCtx) -> Result<Response, ForwarderError> {
+            Err(ForwarderError::Unauthorized)
+        }
+    }
+}
+
+#[test]
+fn contract_error() {
+    let app = App::default();
+    let owner = "owner".into_bech32();
+    let forwarder = ForwarderCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+
+    let err = forwarder.reject().try_call(&owner).unwrap_err();
+    assert!(matches!(
+        err,
+        MtError::Contract(ForwarderError::Unauthorized)
+    ));
+    assert_eq!(
+        err.contract_error::<ForwarderError>(),
+        Some(&ForwarderError::Unauthorized)
+    );
+    assert_eq!(err.contract(), None);
+    assert_eq!(err.to_string(), "Unauthorized");
+    assert_eq!(err.into_contract_error(), ForwarderError::Unauthorized);
+
+    let err = forwarder.reject().call(&owner).unwrap_err();
+    assert_eq!(err, ForwarderError::Unauthorized);
+}
+
+#[test]
+fn foreign_contract_error() {
+    let app = App::default();
+    let owner = "owner".into_bech32();
+    let target = TargetCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+    let forwarder = ForwarderCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+
+    let err = forwarder
+        .forward(target.contract_addr.clone())
+        .try_call(&owner)
+        .unwrap_err();
+    assert!(matches!(err, MtError::ForeignContract { .. }));
+    assert_eq!(err.contract(), Some(&target.contract_addr));
+    assert_eq!(
+        err.contract_error::<TargetError>(),
+        Some(&TargetError::Rejected)
+    );
+    assert_eq!(err.contract_error::<ForwarderError>(), None);
+    assert_eq!(
+        err.to_string(),
+        format!("Error in contract {}: Rejected", target.contract_addr)
+    );
+}
+
+#[test]
+fn self_call_error() {
+    let app = App::default();
+    let owner = "owner".into_bech32();
+    let forwarder = ForwarderCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+
+    let err = forwarder.reject_self().try_call(&owner).unwrap_err();
+    assert!(matches!(
+        err,
+        MtError::Contract(ForwarderError::Unauthorized)
+    ));
+    assert_eq!(err.contract(), None);
+}
+
+#[test]
+fn system_error() {
+    let app = App::default();
+    let owner = "owner".into_bech32();
+    let forwarder = ForwarderCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+
+    let err = forwarder
+        .pay("receiver".into_bech32())
+        .try_call(&owner)
+        .unwrap_err();
+    assert!(matches!(err, MtError::System(_)));
+    assert_eq!(err.contract_error::<ForwarderError>(), None);
+    assert_eq!(err.contract_error::<StdError>(), None);
+}
+
+#[test]
+fn foreign_system_error() {
+    let app = App::default();
+    let owner = "owner".into_bech32();
+    let target = TargetCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+    let forwarder = ForwarderCodeId::store_code(&app)
+        .instantiate()
+        .call(&owner)
+        .unwrap();
+
+    let err = forwarder
+        .forward_pay(target.contract_addr.clone(), "receiver".into_bech32())
+        .try_call(&owner)
+        .unwrap_err();
+    // The bank failure carries no `FailedContract` context, so the failing contract is unknown.
+    assert!(matches!(
+        err,
+        MtError::ForeignContract { contract: None, .. }
+    ));
+    assert_eq!(err.contract(), None);
+    assert_eq!(err.contract_error::<TargetError>(), None);
+    assert_eq!(err.contract_error::<ForwarderError>(), None);
+}
@@ -0,0 +1,291 @@
+use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
+use sylvia::cw_std::{from_json, to_json_string, Addr, StdError, WasmMsg};
+use sylvia::types::Remote;
+
+use crate::cw4::sv::{Cw4ExecMsg, Cw4QueryMsg};
+use crate::namespaced::sv::{ContractExecMsg, ContractQueryMsg, InstantiateMsg};
+use crate::namespaced::NamespacedContract;
+use crate::ownable::sv::{OwnableExecMsg, OwnableQueryMsg};
+
+pub mod cw4 {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+    use sylvia::types::{ExecCtx, QueryCtx};
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Cw4 {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn update_admin(&self, ctx: ExecCtx, admin: String) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn admin(&self, ctx: QueryCtx) -> Result<String, Self::Error>;
+    }
+}
+
+pub mod ownable {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+    use sylvia::types::{ExecCtx, QueryCtx};
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Ownable {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn update_admin(&self, ctx: ExecCtx, admin: String) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn admin(&self, ctx: QueryCtx) -> Result<String, Self::Error>;
+    }
+}
+
+pub mod namespaced {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct NamespacedContract {
+        pub(crate) group_admin: Item<String>,
+        pub(crate) owner: Item<String>,
+    }
+
+    #[entry_points]
+    #[contract]
+    #[sv::messages(crate::cw4 as Group, namespace = "cw4")]
+    #[sv::messages(crate::ownable)]
+    impl NamespacedContract {
+        pub const fn new() -> Self {
+            Self {
+                group_admin: Item::new("group_admin"),
+                owner: Item::new("owner"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+            let sender = ctx.info.sender.to_string();
+            self.group_admin.save(ctx.deps.storage, &sender)?;
+            self.owner.save(ctx.deps.storage, &sender)?;
+            Ok(Response::new())
+        }
+    }
+
+    impl super::cw4::Cw4 for NamespacedContract {
+        type Error = StdError;
+
+        fn update_admin(&self, ctx: ExecCtx, admin: String) -> StdResult<Response> {
+            self.group_admin.save(ctx.deps.storage, &admin)?;
+            Ok(Response::new())
+        }
+
+        fn admin(&self, ctx: QueryCtx) -> StdResult<String> {
+            self.group_admin.load(ctx.deps.storage)
+        }
+    }
+
+    impl super::ownable::Ownable for NamespacedContract {
+        type Error = StdError;
+
+        fn update_admin(&self, ctx: ExecCtx, admin: String) -> StdResult<Response> {
+            self.owner.save(ctx.deps.storage, &admin)?;
+            Ok(Response::new())
+        }
+
+        fn admin(&self, ctx: QueryCtx) -> StdResult<String> {
+            self.owner.load(ctx.deps.storage)
+        }
+    }
+}
+
+pub mod unlisted {
+    use sylvia::contract;
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+
+    pub struct UnlistedContract;
+
+    #[contract]
+    impl UnlistedContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+
+    impl super::cw4::Cw4 for UnlistedContract {
+        type Error = StdError;
+
+        fn update_admin(&self, _ctx: ExecCtx, _admin: String) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        fn admin(&self, _ctx: QueryCtx) -> StdResult<String> {
+            Ok(String::new())
+        }
+    }
+}
+
+#[test]
+fn namespaced_messages_serialization() {
+    let msg = ContractExecMsg::Group(Cw4ExecMsg::update_admin("admin".to_owned()));
+    assert_eq!(
+        to_json_string(&msg).unwrap(),
+        r#"{"cw4":{"update_admin":{"admin":"admin"}}}"#
+    );
+
+    let msg = ContractExecMsg::Ownable(OwnableExecMsg::update_admin("admin".to_owned()));
+    assert_eq!(
+        to_json_string(&msg).unwrap(),
+        r#"{"update_admin":{"admin":"admin"}}"#
+    );
+
+    let msg = ContractQueryMsg::Group(Cw4QueryMsg::admin());
+    assert_eq!(to_json_string(&msg).unwrap(), r#"{"cw4":{"admin":{}}}"#);
+}
+
+#[test]
+fn namespaced_messages_deserialization() {
+    let msg: ContractExecMsg = from_json(r#"{"cw4":{"update_admin":{"admin":"admin"}}}"#).unwrap();
+    assert_eq!(
+        msg,
+        ContractExecMsg::Group(Cw4ExecMsg::update_admin("admin".to_owned()))
+    );
+
+    let msg: ContractExecMsg = from_json(r#"{"update_admin":{"admin":"admin"}}"#).unwrap();
+    assert_eq!(
+        msg,
+        ContractExecMsg::Ownable(OwnableExecMsg::update_admin("admin".to_owned()))
+    );
+
+    let msg: ContractQueryMsg = from_json(r#"{"admin":{}}"#).unwrap();
+    assert_eq!(msg, ContractQueryMsg::Ownable(OwnableQueryMsg::admin()));
+
+    from_json::<ContractExecMsg>(r#"{"cw4":{"unknown":{}}}"#).unwrap_err();
+}
+
+#[test]
+fn unknown_message_error() {
+    let err = from_json::<ContractExecMsg>(r#"{"update_admn":{"admin":"admin"}}"#).unwrap_err();
+    let StdError::ParseErr { msg, .. } = err else {
+        panic!("Expected parse error, received {err:?}");
+    };
+    assert_eq!(
+        msg,
+        "Unknown message `update_admn`. Closest known message: `update_admin`. Checked: Group, Ownable, NamespacedContract"
+    );
+
+    let err = from_json::<ContractQueryMsg>(r#"{"cw5":{"admin":{}}}"#).unwrap_err();
+    let StdError::ParseErr { msg, .. } = err else {
+        panic!("Expected parse error, received {err:?}");
+    };
+    assert_eq!(
+        msg,
+        "Unknown message `cw5`. Closest known message: `cw4`. Checked: Group, Ownable, NamespacedContract"
+    );
+}
+
+#[test]
+fn namespaced_messages_schema() {
+    let schema = sylvia::schemars::schema_for!(ContractExecMsg);
+    let schema = to_json_string(&schema).unwrap();
+    assert!(schema.contains(r#""required":["cw4"]"#));
+}
+
+#[test]
+fn namespaced_messages_dispatch() {
+    use crate::namespaced::entry_points::{execute, instantiate, query};
+
+    let mut deps = mock_dependencies();
+    let info = message_info(&Addr::unchecked("creator"), &[]);
+    instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+    let msg = from_json(r#"{"cw4":{"update_admin":{"admin":"group_admin"}}}"#).unwrap();
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let msg = from_json(r#"{"update_admin":{"admin":"owner"}}"#).unwrap();
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let resp = query(
+        deps.as_ref(),
+        mock_env(),
+        from_json(r#"{"cw4":{"admin":{}}}"#).unwrap(),
+    );
+    assert_eq!(from_json::<String>(resp.unwrap()).unwrap(), "group_admin");
+    let resp = query(
+        deps.as_ref(),
+        mock_env(),
+        from_json(r#"{"admin":{}}"#).unwrap(),
+    );
+    assert_eq!(from_json::<String>(resp.unwrap()).unwrap(), "owner");
+}
+
+#[test]
+fn namespaced_executor() {
+    let remote = Remote::<'_, NamespacedContract>::new(Addr::unchecked("contract"));
+
+    let msg = crate::cw4::sv::Executor::update_admin(remote.executor(), "admin".to_owned())
+        .unwrap()
+        .build();
+    let WasmMsg::Execute { msg, .. } = msg else {
+        panic!("Expected execute message");
+    };
+    assert_eq!(
+        msg.to_vec(),
+        br#"{"cw4":{"update_admin":{"admin":"admin"}}}"#
+    );
+
+    let msg = crate::ownable::sv::Executor::update_admin(remote.executor(), "admin".to_owned())
+        .unwrap()
+        .build();
+    let WasmMsg::Execute { msg, .. } = msg else {
+        panic!("Expected execute message");
+    };
+    assert_eq!(msg.to_vec(), br#"{"update_admin":{"admin":"admin"}}"#);
+}
+
+#[test]
+fn unlisted_interface_executor() {
+    let remote = Remote::<'_, crate::unlisted::UnlistedContract>::new(Addr::unchecked("contract"));
+
+    let msg = crate::cw4::sv::Executor::update_admin(remote.executor(), "admin".to_owned())
+        .unwrap()
+        .build();
+    let WasmMsg::Execute { msg, .. } = msg else {
+        panic!("Expected execute message");
+    };
+    assert_eq!(msg.to_vec(), br#"{"update_admin":{"admin":"admin"}}"#);
+}
+
+#[cfg(feature = "mt")]
+#[test]
+fn namespaced_mt_proxies() {
+    use crate::cw4::sv::mt::Cw4Proxy;
+    use crate::namespaced::sv::mt::CodeId;
+    use crate::ownable::sv::mt::OwnableProxy;
+    use cw_multi_test::IntoBech32;
+    use sylvia::multitest::App;
+
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let owner = "owner".into_bech32();
+
+    let contract = code_id.instantiate().call(&owner).unwrap();
+
+    Cw4Proxy::update_admin(&contract, "group_admin".to_owned())
+        .call(&owner)
+        .unwrap();
+    OwnableProxy::update_admin(&contract, "owner".to_owned())
+        .call(&owner)
+        .unwrap();
+
+    assert_eq!(Cw4Proxy::admin(&contract).unwrap(), "group_admin");
+    assert_eq!(OwnableProxy::admin(&contract).unwrap(), "owner");
+}
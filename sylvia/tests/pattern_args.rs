@@ -0,0 +1,199 @@
+use cw_storage_plus::Item;
+use sylvia::cw_schema::cw_serde;
+use sylvia::cw_std::{from_json, Addr};
+
+use crate::pattern_contract::sv::{ContractExecMsg, ExecMsg, InstantiateMsg};
+use crate::transfer::sv::{TransferExecMsg, TransferQueryMsg};
+
+#[cw_serde]
+pub struct Metadata {
+    pub owner: Addr,
+    pub memo: String,
+}
+
+pub mod transfer {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+    use sylvia::types::{ExecCtx, QueryCtx};
+
+    use super::Metadata;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Transfer {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn transfer(
+            &self,
+            ctx: ExecCtx,
+            #[sv::msg(name = metadata)] Metadata { owner, memo }: Metadata,
+        ) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn sum(
+            &self,
+            _ctx: QueryCtx,
+            #[sv::msg(name = pair)] (a, b): (u32, u32),
+        ) -> Result<u32, Self::Error> {
+            Ok(a + b)
+        }
+    }
+}
+
+pub mod pattern_contract {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{Addr, Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx};
+    use sylvia::{contract, entry_points};
+
+    use super::Metadata;
+
+    pub struct PatternContract {
+        pub(crate) owner: Item<Addr>,
+        pub(crate) memo: Item<String>,
+    }
+
+    #[entry_points]
+    #[contract]
+    #[sv::messages(crate::transfer)]
+    impl PatternContract {
+        pub const fn new() -> Self {
+            Self {
+                owner: Item::new("owner"),
+                memo: Item::new("memo"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(
+            &self,
+            ctx: InstantiateCtx,
+            #[sv::msg(name = metadata)] Metadata { owner, memo }: Metadata,
+        ) -> StdResult<Response> {
+            self.owner.save(ctx.deps.storage, &owner)?;
+            self.memo.save(ctx.deps.storage, &memo)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn update_memo(
+            &self,
+            ctx: ExecCtx,
+            #[sv::msg(name = memo)] (prefix, suffix): (String, String),
+        ) -> StdResult<Response> {
+            self.memo
+                .save(ctx.deps.storage, &format!("{prefix}{suffix}"))?;
+            Ok(Response::new())
+        }
+    }
+
+    impl super::transfer::Transfer for PatternContract {
+        type Error = StdError;
+
+        fn transfer(
+            &self,
+            ctx: ExecCtx,
+            Metadata { owner, memo }: Metadata,
+        ) -> Result<Response, Self::Error> {
+            self.owner.save(ctx.deps.storage, &owner)?;
+            self.memo.save(ctx.deps.storage, &memo)?;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn pattern_args_messages() {
+    let metadata = Metadata {
+        owner: Addr::unchecked("owner"),
+        memo: "memo".to_owned(),
+    };
+
+    let msg: InstantiateMsg =
+        from_json(r#"{"metadata": {"owner": "owner", "memo": "memo"}}"#).unwrap();
+    assert_eq!(msg, InstantiateMsg::new(metadata.clone()));
+
+    let msg: ExecMsg = from_json(r#"{"update_memo": {"memo": ["a", "b"]}}"#).unwrap();
+    assert_eq!(msg, ExecMsg::update_memo(("a".to_owned(), "b".to_owned())));
+
+    let msg: TransferExecMsg =
+        from_json(r#"{"transfer": {"metadata": {"owner": "owner", "memo": "memo"}}}"#).unwrap();
+    assert_eq!(msg, TransferExecMsg::transfer(metadata));
+
+    let msg: TransferQueryMsg = from_json(r#"{"sum": {"pair": [1, 2]}}"#).unwrap();
+    assert_eq!(msg, TransferQueryMsg::sum((1, 2)));
+}
+
+#[test]
+fn pattern_args_dispatch() {
+    use crate::pattern_contract::entry_points::{execute, instantiate};
+    use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
+
+    let mut deps = mock_dependencies();
+    let sender = Addr::unchecked("sender");
+    let info = message_info(&sender, &[]);
+
+    let metadata = Metadata {
+        owner: Addr::unchecked("owner"),
+        memo: "memo".to_owned(),
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        InstantiateMsg::new(metadata),
+    )
+    .unwrap();
+
+    let msg = ContractExecMsg::PatternContract(ExecMsg::update_memo((
+        "new ".to_owned(),
+        "memo".to_owned(),
+    )));
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let memo = Item::<String>::new("memo").load(&deps.storage).unwrap();
+    assert_eq!(memo, "new memo");
+
+    let msg = ContractExecMsg::Transfer(TransferExecMsg::transfer(Metadata {
+        owner: Addr::unchecked("new_owner"),
+        memo: "transferred".to_owned(),
+    }));
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let owner = Item::<Addr>::new("owner").load(&deps.storage).unwrap();
+    assert_eq!(owner, Addr::unchecked("new_owner"));
+}
+
+#[cfg(feature = "mt")]
+#[test]
+fn pattern_args_mt_proxies() {
+    use crate::pattern_contract::sv::mt::{CodeId, PatternContractProxy};
+    use crate::transfer::sv::mt::TransferProxy;
+    use cw_multi_test::IntoBech32;
+    use sylvia::multitest::App;
+
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let owner = "owner".into_bech32();
+
+    let contract = code_id
+        .instantiate(Metadata {
+            owner: owner.clone(),
+            memo: "memo".to_owned(),
+        })
+        .call(&owner)
+        .unwrap();
+
+    contract
+        .update_memo(("a".to_owned(), "b".to_owned()))
+        .call(&owner)
+        .unwrap();
+    contract
+        .transfer(Metadata {
+            owner: owner.clone(),
+            memo: "transferred".to_owned(),
+        })
+        .call(&owner)
+        .unwrap();
+
+    assert_eq!(contract.sum((1, 2)).unwrap(), 3);
+}
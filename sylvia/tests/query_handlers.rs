@@ -0,0 +1,121 @@
+use sylvia::cw_std::testing::mock_dependencies;
+use sylvia::cw_std::{Addr, StdError, StdResult};
+use sylvia::testing::WasmQueryHandlers;
+use sylvia::types::Remote;
+
+use crate::counter::sv::Querier as _;
+use crate::counter::Counter;
+use crate::limits::sv::Querier as _;
+use crate::limits::{LimitResp, LimitsContract};
+
+pub mod counter {
+    use sylvia::cw_std::{StdError, StdResult};
+    use sylvia::interface;
+    use sylvia::types::QueryCtx;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64>;
+
+        #[sv::msg(query)]
+        fn count_of(&self, ctx: QueryCtx, owner: String, offset: u64) -> StdResult<u64>;
+    }
+}
+
+pub mod limits {
+    use cosmwasm_schema::cw_serde;
+    use sylvia::contract;
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::types::{InstantiateCtx, QueryCtx};
+
+    #[cw_serde]
+    pub struct LimitResp {
+        pub limit: u64,
+    }
+
+    pub struct LimitsContract;
+
+    #[contract]
+    impl LimitsContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn limit(&self, _ctx: QueryCtx, owner: String) -> StdResult<LimitResp> {
+            Ok(LimitResp {
+                limit: owner.len() as u64,
+            })
+        }
+    }
+}
+
+#[test]
+fn typed_query_handlers() {
+    let mut deps = mock_dependencies();
+    WasmQueryHandlers::new()
+        .with_contract(
+            "counter",
+            counter::sv::QueryHandlers::new().with_count(5).on_count_of(
+                |owner, offset| match owner.as_str() {
+                    "alice" => Ok(10 + offset),
+                    _ => Err(StdError::not_found("owner")),
+                },
+            ),
+        )
+        .with_contract(
+            "counter",
+            limits::sv::QueryHandlers::new().on_limit(|owner| {
+                Ok(LimitResp {
+                    limit: owner.len() as u64 * 100,
+                })
+            }),
+        )
+        .install(&mut deps.querier);
+    let querier = deps.as_ref().querier;
+
+    let counter = Remote::<dyn Counter<Error = StdError>>::new(Addr::unchecked("counter"));
+    let counter = counter.querier(&querier);
+    assert_eq!(counter.count().unwrap(), 5);
+    assert_eq!(counter.count_of("alice".to_owned(), 2).unwrap(), 12);
+    let err = counter.count_of("bob".to_owned(), 2).unwrap_err();
+    assert!(err.to_string().contains("owner not found"), "{err}");
+
+    let limits = Remote::<LimitsContract>::new(Addr::unchecked("counter"));
+    assert_eq!(
+        limits.querier(&querier).limit("alice".to_owned()).unwrap(),
+        LimitResp { limit: 500 }
+    );
+}
+
+#[test]
+fn missing_query_handlers() {
+    let mut deps = mock_dependencies();
+    WasmQueryHandlers::new()
+        .with_contract("counter", counter::sv::QueryHandlers::new().with_count(5))
+        .install(&mut deps.querier);
+    let querier = deps.as_ref().querier;
+
+    let counter = Remote::<dyn Counter<Error = StdError>>::new(Addr::unchecked("counter"));
+    let err: StdResult<u64> = counter.querier(&querier).count_of("alice".to_owned(), 0);
+    assert!(err
+        .unwrap_err()
+        .to_string()
+        .contains("No handler registered for the query"));
+
+    let counter = Remote::<dyn Counter<Error = StdError>>::new(Addr::unchecked("missing"));
+    let err = counter.querier(&querier).count().unwrap_err();
+    assert!(
+        err.to_string().contains("No such contract: missing"),
+        "{err}"
+    );
+}
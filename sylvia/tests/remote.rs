@@ -305,8 +305,8 @@ mod tests {
     fn setup(
         app: &App<ExampleApp>,
     ) -> (
-        Proxy<ExampleApp, ManagerContract<i32>>,
-        Proxy<ExampleApp, ManagerContract<u32>>,
+        Proxy<'_, ExampleApp, ManagerContract<i32>>,
+        Proxy<'_, ExampleApp, ManagerContract<u32>>,
     ) {
         // Manager operating on signed numbers
         let signed_counter_code_id = SignedCounterCodeId::store_code(app);
@@ -0,0 +1,127 @@
+use sylvia::cw_std::{coin, to_json_binary, Addr, WasmMsg};
+use sylvia::types::RemoteCode;
+
+use crate::counter::sv::{InstantiateMsg, MigrateMsg};
+use crate::counter::CounterContract;
+
+pub mod counter {
+    use cw_storage_plus::Item;
+    use sylvia::contract;
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::types::{InstantiateCtx, MigrateCtx};
+
+    pub struct CounterContract {
+        pub(crate) count: Item<u64>,
+    }
+
+    #[contract]
+    impl CounterContract {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, ctx: MigrateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn instantiate_msg() {
+    let code = RemoteCode::<CounterContract>::new(7);
+    assert_eq!(code.code_id(), 7);
+
+    let msg = code.instantiate(&InstantiateMsg::new(5)).unwrap().build();
+    assert_eq!(
+        msg,
+        WasmMsg::Instantiate {
+            admin: None,
+            code_id: 7,
+            msg: to_json_binary(&InstantiateMsg::new(5)).unwrap(),
+            funds: vec![],
+            label: "Contract".to_owned(),
+        }
+    );
+
+    let msg = code
+        .instantiate(&InstantiateMsg::new(5))
+        .unwrap()
+        .with_funds(vec![coin(100, "atom")])
+        .with_label("Counter")
+        .with_admin("admin")
+        .build();
+    assert_eq!(
+        msg,
+        WasmMsg::Instantiate {
+            admin: Some("admin".to_owned()),
+            code_id: 7,
+            msg: to_json_binary(&InstantiateMsg::new(5)).unwrap(),
+            funds: vec![coin(100, "atom")],
+            label: "Counter".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn migrate_msg() {
+    let contract = Addr::unchecked("contract");
+    let msg = RemoteCode::<CounterContract>::new(8)
+        .migrate(&contract, &MigrateMsg::new(10))
+        .unwrap();
+    assert_eq!(
+        msg,
+        WasmMsg::Migrate {
+            contract_addr: contract.to_string(),
+            new_code_id: 8,
+            msg: to_json_binary(&MigrateMsg::new(10)).unwrap(),
+        }
+    );
+}
+
+#[cfg(feature = "cosmwasm_1_2")]
+#[test]
+fn instantiate2_msg() {
+    use sylvia::cw_std::testing::MockApi;
+    use sylvia::cw_std::{instantiate2_address, Api, Binary, Checksum};
+
+    let api = MockApi::default();
+    let creator = api.addr_make("creator");
+    let checksum = Checksum::generate(b"counter code");
+
+    let (msg, address) = RemoteCode::<CounterContract>::new(7)
+        .instantiate(&InstantiateMsg::new(5))
+        .unwrap()
+        .with_label("Counter")
+        .build2(&api, &creator, &checksum, b"salt".as_slice())
+        .unwrap();
+
+    assert_eq!(
+        msg,
+        WasmMsg::Instantiate2 {
+            admin: None,
+            code_id: 7,
+            label: "Counter".to_owned(),
+            msg: to_json_binary(&InstantiateMsg::new(5)).unwrap(),
+            funds: vec![],
+            salt: Binary::from(b"salt".as_slice()),
+        }
+    );
+
+    let expected = instantiate2_address(
+        checksum.as_slice(),
+        &api.addr_canonicalize(creator.as_str()).unwrap(),
+        b"salt",
+    )
+    .unwrap();
+    assert_eq!(address, api.addr_humanize(&expected).unwrap());
+}
@@ -0,0 +1,139 @@
+use sylvia::cw_std::{from_json, to_json_string};
+
+use crate::legacy::sv::{ContractExecMsg, ContractQueryMsg, ExecMsg, InstantiateMsg, QueryMsg};
+use crate::ownable::sv::OwnableExecMsg;
+
+pub mod ownable {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+    use sylvia::types::ExecCtx;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Ownable {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec, name = "UpdateOwner", alias = "set_owner")]
+        fn update_owner(
+            &self,
+            ctx: ExecCtx,
+            #[sv::msg(name = "newOwner", alias = ["owner"])] new_owner: String,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod legacy {
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct LegacyContract;
+
+    #[entry_points]
+    #[contract]
+    #[sv::messages(crate::ownable)]
+    impl LegacyContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(
+            &self,
+            _ctx: InstantiateCtx,
+            #[sv::msg(name = "initialSupply")] initial_supply: u32,
+        ) -> StdResult<Response> {
+            let _ = initial_supply;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec, name = "transfer_from", alias = ["send_from"])]
+        fn transfer_legacy(
+            &self,
+            _ctx: ExecCtx,
+            #[sv::msg(name = "owner")] from: String,
+            amount: u32,
+        ) -> StdResult<Response> {
+            let _ = (from, amount);
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query, name = "balance_of")]
+        fn balance(&self, _ctx: QueryCtx, address: String) -> StdResult<u32> {
+            let _ = address;
+            Ok(0)
+        }
+    }
+
+    impl super::ownable::Ownable for LegacyContract {
+        type Error = StdError;
+
+        fn update_owner(&self, _ctx: ExecCtx, new_owner: String) -> StdResult<Response> {
+            let _ = new_owner;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn renamed_messages_names() {
+    assert_eq!(
+        legacy::sv::execute_messages(),
+        ["send_from", "transfer_from"]
+    );
+    assert_eq!(legacy::sv::query_messages(), ["balance_of"]);
+    assert_eq!(
+        ownable::sv::execute_messages(),
+        ["UpdateOwner", "set_owner"]
+    );
+}
+
+#[test]
+fn renamed_messages_serialization() {
+    let msg = ExecMsg::transfer_legacy("owner".to_owned(), 10);
+    assert_eq!(
+        to_json_string(&msg).unwrap(),
+        r#"{"transfer_from":{"owner":"owner","amount":10}}"#
+    );
+
+    let msg = QueryMsg::balance("addr".to_owned());
+    assert_eq!(
+        to_json_string(&msg).unwrap(),
+        r#"{"balance_of":{"address":"addr"}}"#
+    );
+
+    let msg = OwnableExecMsg::update_owner("owner".to_owned());
+    assert_eq!(
+        to_json_string(&msg).unwrap(),
+        r#"{"UpdateOwner":{"newOwner":"owner"}}"#
+    );
+
+    let msg = InstantiateMsg::new(100);
+    assert_eq!(to_json_string(&msg).unwrap(), r#"{"initialSupply":100}"#);
+}
+
+#[test]
+fn renamed_messages_deserialization() {
+    let transfer =
+        ContractExecMsg::LegacyContract(ExecMsg::transfer_legacy("owner".to_owned(), 10));
+    let msg: ContractExecMsg =
+        from_json(r#"{"transfer_from":{"owner":"owner","amount":10}}"#).unwrap();
+    assert_eq!(msg, transfer);
+    let msg: ContractExecMsg = from_json(r#"{"send_from":{"owner":"owner","amount":10}}"#).unwrap();
+    assert_eq!(msg, transfer);
+
+    let update_owner = ContractExecMsg::Ownable(OwnableExecMsg::update_owner("owner".to_owned()));
+    let msg: ContractExecMsg = from_json(r#"{"UpdateOwner":{"newOwner":"owner"}}"#).unwrap();
+    assert_eq!(msg, update_owner);
+    let msg: ContractExecMsg = from_json(r#"{"set_owner":{"owner":"owner"}}"#).unwrap();
+    assert_eq!(msg, update_owner);
+
+    let msg: ContractQueryMsg = from_json(r#"{"balance_of":{"address":"addr"}}"#).unwrap();
+    assert_eq!(
+        msg,
+        ContractQueryMsg::LegacyContract(QueryMsg::balance("addr".to_owned()))
+    );
+
+    from_json::<ContractExecMsg>(r#"{"transfer_legacy":{"owner":"owner","amount":10}}"#)
+        .unwrap_err();
+}
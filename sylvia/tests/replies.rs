@@ -1,7 +1,9 @@
 #[cfg(all(test, feature = "mt"))]
 use cw_multi_test::IntoBech32;
 use sylvia::cw_std::testing::{mock_dependencies, mock_env};
-use sylvia::cw_std::{from_json, Reply, SubMsgResponse, SubMsgResult};
+use sylvia::cw_std::{
+    from_json, to_json_binary, Binary, Reply, StdError, SubMsgResponse, SubMsgResult,
+};
 
 #[allow(dead_code)]
 mod noop_contract {
@@ -76,6 +78,76 @@ mod reply_contract {
     }
 }
 
+mod typed_reply_contract {
+    use sylvia::types::{ExecCtx, InstantiateCtx, ReplyCtx};
+    use sylvia::{contract, entry_points};
+
+    use sylvia::cw_std::{
+        to_json_binary, Binary, Response, StdResult, SubMsg, SubMsgResult, WasmMsg,
+    };
+
+    use super::noop_contract;
+
+    pub struct TypedReplyContract;
+
+    #[allow(dead_code)]
+    #[entry_points]
+    #[contract]
+    impl TypedReplyContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn poke(&self, _ctx: ExecCtx, noop: String) -> StdResult<Response> {
+            let msg = noop_contract::sv::ExecMsg::Noop {};
+            let msg = WasmMsg::Execute {
+                contract_addr: noop,
+                msg: to_json_binary(&msg)?,
+                funds: vec![],
+            };
+            let msg = SubMsg::reply_always(msg, sv::ALWAYS_REPLY_ID)
+                .with_payload(to_json_binary("payload")?);
+
+            let resp = Response::new().add_submessage(msg);
+            Ok(resp)
+        }
+
+        #[sv::msg(reply, handlers=[noop], reply_on=success)]
+        fn noop_success(
+            &self,
+            _ctx: ReplyCtx,
+            #[sv::data(opt)] data: Option<String>,
+            #[sv::payload] payload: String,
+        ) -> StdResult<Response> {
+            let resp = Response::new().set_data(to_json_binary(&(data, payload))?);
+            Ok(resp)
+        }
+
+        #[sv::msg(reply, handlers=[noop], reply_on=error)]
+        fn noop_failure(&self, _ctx: ReplyCtx, error: String) -> StdResult<Response> {
+            let resp = Response::new().set_data(to_json_binary(&error)?);
+            Ok(resp)
+        }
+
+        #[sv::msg(reply, reply_on=always, id = 7)]
+        fn always(
+            &self,
+            _ctx: ReplyCtx,
+            result: SubMsgResult,
+            #[sv::payload(raw)] payload: Binary,
+        ) -> StdResult<Response> {
+            let resp = Response::new().set_data(to_json_binary(&(result.is_ok(), payload))?);
+            Ok(resp)
+        }
+    }
+}
+
 #[test]
 fn entry_point_generation() {
     let msg = Reply {
@@ -120,3 +192,120 @@ fn mt_helper_generation() {
 
     assert_eq!(data, "data");
 }
+
+fn typed_reply(id: u64, result: SubMsgResult) -> Reply {
+    Reply {
+        id,
+        payload: to_json_binary("payload").unwrap(),
+        gas_used: 0,
+        result,
+    }
+}
+
+#[test]
+fn typed_reply_ids() {
+    // FNV-1a hash of the reply id name
+    assert_eq!(
+        typed_reply_contract::sv::NOOP_REPLY_ID,
+        0x3c11_10ba_d16d_326d
+    );
+    assert_eq!(typed_reply_contract::sv::ALWAYS_REPLY_ID, 7);
+}
+
+#[test]
+fn typed_reply_dispatch() {
+    use typed_reply_contract::entry_points::reply;
+    use typed_reply_contract::sv::{ALWAYS_REPLY_ID, NOOP_REPLY_ID};
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    #[allow(deprecated)]
+    let success = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: Some(to_json_binary("data").unwrap()),
+        msg_responses: vec![],
+    });
+    let resp = reply(
+        deps.as_mut(),
+        env.clone(),
+        typed_reply(NOOP_REPLY_ID, success),
+    )
+    .unwrap();
+    let data: (Option<String>, String) = from_json(resp.data.unwrap()).unwrap();
+    assert_eq!(data, (Some("data".to_owned()), "payload".to_owned()));
+
+    let failure = SubMsgResult::Err("failure".to_owned());
+    let resp = reply(
+        deps.as_mut(),
+        env.clone(),
+        typed_reply(NOOP_REPLY_ID, failure),
+    )
+    .unwrap();
+    let data: String = from_json(resp.data.unwrap()).unwrap();
+    assert_eq!(data, "failure");
+
+    let failure = SubMsgResult::Err("failure".to_owned());
+    let resp = reply(
+        deps.as_mut(),
+        env.clone(),
+        typed_reply(ALWAYS_REPLY_ID, failure),
+    )
+    .unwrap();
+    let (is_ok, payload): (bool, Binary) = from_json(resp.data.unwrap()).unwrap();
+    assert!(!is_ok);
+    assert_eq!(payload, to_json_binary("payload").unwrap());
+
+    let failure = SubMsgResult::Err("failure".to_owned());
+    let err = reply(deps.as_mut(), env, typed_reply(42, failure)).unwrap_err();
+    assert_eq!(err, StdError::generic_err("Unknown reply id: 42."));
+}
+
+#[test]
+fn typed_reply_empty_payload() {
+    use typed_reply_contract::entry_points::reply;
+    use typed_reply_contract::sv::NOOP_REPLY_ID;
+
+    let mut deps = mock_dependencies();
+
+    #[allow(deprecated)]
+    let success = SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: Some(to_json_binary("data").unwrap()),
+        msg_responses: vec![],
+    });
+    let msg = Reply {
+        payload: Binary::default(),
+        ..typed_reply(NOOP_REPLY_ID, success)
+    };
+    let err = reply(deps.as_mut(), mock_env(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::parse_err(
+            "alloc::string::String",
+            "Failed to decode payload of the reply `noop`: payload is empty."
+        )
+    );
+}
+
+#[cfg(all(test, feature = "mt"))]
+#[test]
+fn typed_reply_mt_helper_generation() {
+    use crate::typed_reply_contract::sv::mt::TypedReplyContractProxy;
+    let app = sylvia::multitest::App::default();
+    let owner = "owner".into_bech32();
+
+    let noop_contract_code = noop_contract::sv::mt::CodeId::store_code(&app);
+    let noop_contract = noop_contract_code.instantiate().call(&owner).unwrap();
+
+    let reply_contract_code = typed_reply_contract::sv::mt::CodeId::store_code(&app);
+    let reply_contract = reply_contract_code.instantiate().call(&owner).unwrap();
+
+    let resp = reply_contract
+        .poke(noop_contract.contract_addr.to_string())
+        .call(&owner)
+        .unwrap();
+
+    let (is_ok, _): (bool, Binary) = from_json(resp.data.unwrap()).unwrap();
+    assert!(is_ok);
+}
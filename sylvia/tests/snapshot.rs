@@ -0,0 +1,154 @@
+#![cfg(feature = "mt")]
+
+use cw_multi_test::{Executor, IntoBech32};
+use sylvia::cw_std::coins;
+use sylvia::multitest::App;
+
+use crate::counter::sv::mt::{CodeId, CounterContractProxy};
+
+pub mod counter {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct CounterContract {
+        pub(crate) count: Item<u64>,
+    }
+
+    #[entry_points]
+    #[contract]
+    impl CounterContract {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+            let count = self
+                .count
+                .update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+            Ok(Response::new().add_attribute("count", count.to_string()))
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, ctx: MigrateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+    }
+}
+
+#[test]
+fn snapshot_and_restore() {
+    let owner = "owner".into_bech32();
+    let app = App::new(cw_multi_test::App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &owner, coins(100, "atom"))
+            .unwrap();
+    }));
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id.instantiate(0).call(&owner).unwrap();
+
+    let snapshot = app.snapshot();
+    let height = app.block_info().height;
+
+    contract.increment().call(&owner).unwrap();
+    app.update_block(|block| block.height += 10);
+    app.app_mut()
+        .send_tokens(
+            owner.clone(),
+            contract.contract_addr.clone(),
+            &coins(40, "atom"),
+        )
+        .unwrap();
+    let second = code_id.instantiate(5).call(&owner).unwrap();
+    assert_eq!(contract.count().unwrap(), 1);
+
+    app.restore(&snapshot);
+    assert_eq!(contract.count().unwrap(), 0);
+    assert_eq!(app.block_info().height, height);
+    assert_eq!(
+        app.querier()
+            .query_balance(&owner, "atom")
+            .unwrap()
+            .amount
+            .u128(),
+        100
+    );
+    app.querier()
+        .query_wasm_contract_info(&second.contract_addr)
+        .unwrap_err();
+
+    // The same snapshot can be restored again.
+    contract.increment().call(&owner).unwrap();
+    app.restore(&snapshot);
+    assert_eq!(contract.count().unwrap(), 0);
+}
+
+#[test]
+fn dry_run() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id
+        .instantiate(0)
+        .with_admin(owner.as_str())
+        .call(&owner)
+        .unwrap();
+
+    let resp = contract.increment().dry_run(&owner).unwrap();
+    assert!(resp.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "count" && attr.value == "1")));
+    assert_eq!(contract.count().unwrap(), 0);
+
+    contract
+        .migrate(7)
+        .dry_run(&owner, code_id.code_id())
+        .unwrap();
+    assert_eq!(contract.count().unwrap(), 0);
+
+    let resp = code_id.instantiate(3).dry_run(&owner).unwrap();
+    assert!(resp.events.iter().any(|event| event.ty == "instantiate"));
+    let contract_addr = app.dry_run(|_| {
+        let contract = code_id.instantiate(3).call(&owner).unwrap();
+        assert_eq!(contract.count().unwrap(), 3);
+        contract.contract_addr
+    });
+    app.querier()
+        .query_wasm_contract_info(&contract_addr)
+        .unwrap_err();
+}
+
+#[test]
+fn dry_run_reverts_on_panic() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id.instantiate(0).call(&owner).unwrap();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        app.dry_run(|_| {
+            contract.increment().call(&owner).unwrap();
+            panic!("Scenario failed");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(contract.count().unwrap(), 0);
+}
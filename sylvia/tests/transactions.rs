@@ -0,0 +1,180 @@
+#![cfg(feature = "mt")]
+
+use cw_multi_test::IntoBech32;
+use sylvia::cw_std::{coins, StdError};
+use sylvia::multitest::{App, MtError};
+
+use crate::counter::sv::mt::{CodeId, CounterContractProxy};
+
+pub mod counter {
+    use cw_storage_plus::Item;
+    use sylvia::cw_std::{ensure, Response, StdError, StdResult};
+    use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    pub struct CounterContract {
+        pub(crate) count: Item<u64>,
+    }
+
+    #[entry_points]
+    #[contract]
+    impl CounterContract {
+        pub const fn new() -> Self {
+            Self {
+                count: Item::new("count"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, ctx: InstantiateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+            self.count
+                .update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        fn fail(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Err(StdError::generic_err("Failed"))
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, ctx: MigrateCtx, count: u64) -> StdResult<Response> {
+            self.count.save(ctx.deps.storage, &count)?;
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64> {
+            self.count.load(ctx.deps.storage)
+        }
+
+        #[sv::invariant]
+        fn below_limit(&self, ctx: QueryCtx) -> StdResult<()> {
+            let count = self.count.load(ctx.deps.storage)?;
+            ensure!(
+                count <= 100,
+                StdError::generic_err(format!("count {count} exceeds limit 100"))
+            );
+            Ok(())
+        }
+    }
+}
+
+fn app_with_balance(owner: &sylvia::cw_std::Addr) -> App<cw_multi_test::BasicApp> {
+    App::new(cw_multi_test::App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, owner, coins(100, "atom"))
+            .unwrap();
+    }))
+}
+
+#[test]
+fn transaction_success() {
+    let owner = "owner".into_bech32();
+    let other = "other".into_bech32();
+    let app = app_with_balance(&owner);
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id
+        .instantiate(0)
+        .with_admin(owner.as_str())
+        .call(&owner)
+        .unwrap();
+
+    let responses = app
+        .transaction()
+        .execute(&owner, contract.increment())
+        .execute(&other, contract.increment())
+        .send_tokens(&owner, &other, &coins(10, "atom"))
+        .instantiate(&owner, code_id.instantiate(5).with_label("Second"))
+        .execute(&owner, contract.increment())
+        .call()
+        .unwrap();
+
+    assert_eq!(responses.len(), 5);
+    assert_eq!(contract.count().unwrap(), 3);
+    assert_eq!(
+        app.querier()
+            .query_balance(&other, "atom")
+            .unwrap()
+            .amount
+            .u128(),
+        10
+    );
+    assert!(responses[3]
+        .events
+        .iter()
+        .any(|event| event.ty == "instantiate"));
+
+    app.transaction()
+        .migrate(&owner, contract.migrate(10), code_id.code_id())
+        .execute(&owner, contract.increment())
+        .call()
+        .unwrap();
+    assert_eq!(contract.count().unwrap(), 11);
+}
+
+#[test]
+fn transaction_rollback() {
+    let owner = "owner".into_bech32();
+    let other = "other".into_bech32();
+    let app = app_with_balance(&owner);
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id.instantiate(0).call(&owner).unwrap();
+
+    let err = app
+        .transaction()
+        .execute(&owner, contract.increment())
+        .send_tokens(&owner, &other, &coins(10, "atom"))
+        .execute(&other, contract.increment())
+        .execute(&other, contract.fail())
+        .call()
+        .unwrap_err();
+
+    assert_eq!(err.contract(), Some(&contract.contract_addr));
+    assert_eq!(
+        err.contract_error::<StdError>(),
+        Some(&StdError::generic_err("Failed"))
+    );
+    assert_eq!(contract.count().unwrap(), 0);
+    assert_eq!(
+        app.querier()
+            .query_balance(&owner, "atom")
+            .unwrap()
+            .amount
+            .u128(),
+        100
+    );
+}
+
+#[test]
+fn transaction_invariant_violation() {
+    let owner = "owner".into_bech32();
+    let app = App::default();
+    let code_id = CodeId::store_code(&app);
+    let contract = code_id
+        .instantiate(0)
+        .with_admin(owner.as_str())
+        .call(&owner)
+        .unwrap();
+
+    let err = app
+        .transaction()
+        .execute(&owner, contract.increment())
+        .migrate(&owner, contract.migrate(101), code_id.code_id())
+        .execute(&owner, contract.increment())
+        .call()
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        MtError::InvariantViolated(ref violation) if violation.invariant == "below_limit"
+    ));
+    assert_eq!(contract.count().unwrap(), 0);
+}
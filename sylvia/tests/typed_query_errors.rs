@@ -0,0 +1,176 @@
+use sylvia::cw_std::testing::MockQuerier;
+use sylvia::cw_std::{
+    to_json_binary, Addr, ContractResult, Empty, QuerierWrapper, StdError, SystemError,
+    SystemResult, WasmQuery,
+};
+use sylvia::types::{DecodeError, QueryError, Remote};
+
+use crate::counter::sv::Querier as _;
+use crate::counter::{Counter, CounterError};
+use crate::limited::sv::Querier as _;
+use crate::limited::LimitedContract;
+
+pub mod counter {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use sylvia::cw_std::{StdError, StdResult};
+    use sylvia::interface;
+    use sylvia::types::QueryCtx;
+
+    #[derive(Debug, PartialEq)]
+    pub enum CounterError {
+        Std(String),
+        Overflow,
+    }
+
+    impl From<StdError> for CounterError {
+        fn from(err: StdError) -> Self {
+            Self::Std(err.to_string())
+        }
+    }
+
+    impl Display for CounterError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Std(msg) => write!(f, "{msg}"),
+                Self::Overflow => write!(f, "Counter overflow"),
+            }
+        }
+    }
+
+    impl FromStr for CounterError {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "Counter overflow" => Ok(Self::Overflow),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    #[sv::typed_query_errors]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64>;
+    }
+}
+
+pub mod limited {
+    use sylvia::contract;
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::types::{InstantiateCtx, QueryCtx};
+
+    pub struct LimitedContract;
+
+    #[derive(Debug, PartialEq)]
+    pub struct LimitReached;
+
+    impl sylvia::types::DecodeError for LimitReached {
+        fn decode(msg: &str) -> Option<Self> {
+            msg.starts_with("Limit reached").then_some(Self)
+        }
+    }
+
+    #[contract]
+    #[sv::typed_query_errors]
+    impl LimitedContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn limit(&self, _ctx: QueryCtx) -> StdResult<u64> {
+            Err(StdError::generic_err("Limit reached"))
+        }
+    }
+}
+
+fn mock_querier() -> MockQuerier {
+    let mut querier = MockQuerier::new(&[]);
+    querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, .. } => match contract_addr.as_str() {
+            "counter" => SystemResult::Ok(ContractResult::Ok(to_json_binary(&5u64).unwrap())),
+            "overflowing" => SystemResult::Ok(ContractResult::Err("Counter overflow".to_owned())),
+            "limited" => {
+                SystemResult::Ok(ContractResult::Err("Limit reached at height 10".to_owned()))
+            }
+            "malformed" => SystemResult::Ok(ContractResult::Ok(to_json_binary("five").unwrap())),
+            addr => SystemResult::Err(SystemError::NoSuchContract {
+                addr: addr.to_owned(),
+            }),
+        },
+        _ => unimplemented!(),
+    });
+    querier
+}
+
+#[test]
+fn interface_query_errors() {
+    let querier = mock_querier();
+    let querier = QuerierWrapper::<Empty>::new(&querier);
+
+    let remote = Remote::<dyn Counter<Error = CounterError>>::new(Addr::unchecked("counter"));
+    assert_eq!(remote.querier(&querier).count().unwrap(), 5);
+
+    let remote = Remote::<dyn Counter<Error = CounterError>>::new(Addr::unchecked("overflowing"));
+    let err = remote.querier(&querier).count().unwrap_err();
+    assert!(matches!(&err, QueryError::Contract(msg) if msg == "Counter overflow"));
+    assert_eq!(
+        err.decode_contract_error::<CounterError>(),
+        Some(CounterError::Overflow)
+    );
+
+    let remote = Remote::<dyn Counter<Error = CounterError>>::new(Addr::unchecked("malformed"));
+    let err = remote.querier(&querier).count().unwrap_err();
+    assert!(matches!(
+        err,
+        QueryError::Deserialization(StdError::ParseErr { .. })
+    ));
+    assert_eq!(err.decode_contract_error::<CounterError>(), None);
+
+    let remote = Remote::<dyn Counter<Error = CounterError>>::new(Addr::unchecked("missing"));
+    let err = remote.querier(&querier).count().unwrap_err();
+    assert!(matches!(err, QueryError::System(_)));
+    assert_eq!(
+        err.to_string(),
+        "Querier system error: No such contract: missing"
+    );
+}
+
+#[test]
+fn contract_query_errors() {
+    let querier = mock_querier();
+    let querier = QuerierWrapper::<Empty>::new(&querier);
+
+    let remote = Remote::<LimitedContract>::new(Addr::unchecked("limited"));
+    let err = remote.querier(&querier).limit().unwrap_err();
+    assert_eq!(
+        err.decode_contract_error::<limited::LimitReached>(),
+        Some(limited::LimitReached)
+    );
+    let err = StdError::from(err);
+    assert!(
+        matches!(&err, StdError::GenericErr { msg, .. } if msg == "Querier contract error: Limit reached at height 10"),
+        "Unexpected error {err:?}"
+    );
+}
+
+#[test]
+fn decode_error_from_str() {
+    assert_eq!(
+        CounterError::decode("Counter overflow"),
+        Some(CounterError::Overflow)
+    );
+    assert_eq!(CounterError::decode("Unknown error"), None);
+}
@@ -0,0 +1,45 @@
+#![allow(unused_imports)]
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::types::{ExecCtx, InstantiateCtx};
+
+mod interface {
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::types::SudoCtx;
+
+    #[sylvia::interface]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(sudo)]
+        fn sudo_msg(&self, ctx: SudoCtx) -> Result<Response, Self::Error>;
+    }
+
+    impl Interface for crate::Contract {
+        type Error = StdError;
+
+        fn sudo_msg(&self, _ctx: SudoCtx) -> Result<Response, Self::Error> {
+            Ok(Response::new())
+        }
+    }
+}
+
+pub struct Contract;
+
+#[sylvia::contract]
+#[sv::messages(interface: guard(Self::is_admin))]
+impl Contract {
+    pub const fn new() -> Self {
+        Contract
+    }
+
+    fn is_admin(&self, _ctx: &ExecCtx) -> StdResult<()> {
+        Ok(())
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,117 @@
+#![cfg(feature = "cw2")]
+
+use cw_storage_plus::Item;
+use sylvia::cw2::{self, ContractVersion};
+use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
+use sylvia::cw_std::{from_json, Addr, StdError};
+
+use crate::versioned::entry_points::{instantiate, migrate, query};
+use crate::versioned::sv::{ContractQueryMsg, InstantiateMsg, MigrateMsg};
+
+const CONTRACT_NAME: &str = "crates.io:versioned";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub mod versioned {
+    use cw_storage_plus::Item;
+    use sylvia::cw2;
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::types::{InstantiateCtx, MigrateCtx, QueryCtx};
+    use sylvia::{contract, entry_points};
+
+    use super::CONTRACT_NAME;
+
+    pub struct VersionedContract {
+        pub(crate) migrated_from: Item<String>,
+    }
+
+    #[entry_points]
+    #[contract]
+    #[sv::version(name = CONTRACT_NAME, version = env!("CARGO_PKG_VERSION"))]
+    impl VersionedContract {
+        pub const fn new() -> Self {
+            Self {
+                migrated_from: Item::new("migrated_from"),
+            }
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn migrated_from(&self, ctx: QueryCtx) -> StdResult<String> {
+            self.migrated_from.load(ctx.deps.storage)
+        }
+
+        #[sv::msg(migrate)]
+        fn migrate(&self, ctx: MigrateCtx) -> StdResult<Response> {
+            let original = cw2::get_contract_version(ctx.deps.storage)?;
+            self.migrated_from
+                .save(ctx.deps.storage, &original.version)?;
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn instantiate_sets_contract_version() {
+    let mut deps = mock_dependencies();
+    let info = message_info(&Addr::unchecked("owner"), &[]);
+    instantiate(deps.as_mut(), mock_env(), info, InstantiateMsg {}).unwrap();
+
+    let stored = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(stored.contract, CONTRACT_NAME);
+    assert_eq!(stored.version, CONTRACT_VERSION);
+
+    let msg: ContractQueryMsg = from_json(r#"{"contract_info": {}}"#).unwrap();
+    let resp = query(deps.as_ref(), mock_env(), msg).unwrap();
+    let info: ContractVersion = from_json(resp).unwrap();
+    assert_eq!(info, stored);
+}
+
+#[test]
+fn migrate_from_older_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "0.1.0").unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let stored = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(stored.version, CONTRACT_VERSION);
+    let migrated_from = Item::<String>::new("migrated_from")
+        .load(&deps.storage)
+        .unwrap();
+    assert_eq!(migrated_from, "0.1.0");
+}
+
+#[test]
+fn migrate_refuses_downgrade() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "999.0.0").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Cannot migrate from newer version (999.0.0) to older ({CONTRACT_VERSION})"
+        ))
+    );
+
+    let stored = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(stored.version, "999.0.0");
+}
+
+#[test]
+fn migrate_refuses_other_contract() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "crates.io:other", "0.1.0").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Cannot migrate from crates.io:other to {CONTRACT_NAME}"
+        ))
+    );
+}